@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Limb base: each limb holds nine decimal digits.
+const BASE: u32 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+/// An arbitrary-precision signed integer.
+///
+/// The magnitude is stored in base `10^9` limbs, least significant first,
+/// with no leading zero limbs; zero has no limbs and is never negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt { negative: false, limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Builds a value from its parts, restoring the invariants.
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        let negative = negative && !limbs.is_empty();
+        BigInt { negative, limbs }
+    }
+
+    fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+        a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+
+    fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        let mut result = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0;
+        for (i, &x) in long.iter().enumerate() {
+            let sum = x + short.get(i).copied().unwrap_or(0) + carry;
+            carry = (sum >= BASE) as u32;
+            result.push(if carry == 1 { sum - BASE } else { sum });
+        }
+        if carry == 1 {
+            result.push(1);
+        }
+        result
+    }
+
+    /// `a - b` for magnitudes with `a >= b`.
+    fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut result = Vec::with_capacity(a.len());
+        let mut borrow = 0;
+        for (i, &x) in a.iter().enumerate() {
+            let y = b.get(i).copied().unwrap_or(0) + borrow;
+            borrow = (x < y) as u32;
+            result.push(if borrow == 1 { x + BASE - y } else { x - y });
+        }
+        result
+    }
+}
+
+impl std::ops::Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.limbs)
+    }
+}
+
+impl std::ops::Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, BigInt::add_magnitude(&self.limbs, &rhs.limbs));
+        }
+        // Opposite signs: the larger magnitude decides the sign.
+        match BigInt::cmp_magnitude(&self.limbs, &rhs.limbs) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, BigInt::sub_magnitude(&rhs.limbs, &self.limbs)),
+            _ => BigInt::from_parts(self.negative, BigInt::sub_magnitude(&self.limbs, &rhs.limbs)),
+        }
+    }
+}
+
+impl std::ops::Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &(-rhs.clone())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    /// Parses an optionally signed decimal integer.
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(format!("invalid integer: {:?}", s));
+        }
+
+        // Nine digits per limb, starting from the least significant end.
+        let bytes = digits.as_bytes();
+        let limbs = bytes
+            .rchunks(BASE_DIGITS)
+            .map(|chunk| chunk.iter().fold(0, |acc, &c| acc * 10 + (c - b'0') as u32))
+            .collect();
+        Ok(BigInt::from_parts(negative, limbs))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((&top, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", top)?;
+        // Lower limbs are zero-padded to the full nine digits.
+        for limb in rest.iter().rev() {
+            write!(f, "{:09}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let a: BigInt = parts.next().unwrap().parse().expect("Failed to parse a");
+        let b: BigInt = parts.next().unwrap().parse().expect("Failed to parse b");
+        output.push((&a + &b).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
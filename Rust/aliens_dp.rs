@@ -0,0 +1,71 @@
+use std::io::{self, BufRead};
+
+/// The Aliens trick (Lagrangian relaxation): `f(k)` for a convex `f` over
+/// integers with integer values, given only the penalized problem.
+///
+/// `solve(lambda)` must return `min_x f(x) + lambda x` together with the
+/// smallest minimizing `x`. The minimizer shrinks as `lambda` grows, so a
+/// binary search over `[lo, hi]` finds the smallest `lambda` whose reported
+/// count is at most `k`; the caller picks the range so that `solve(hi)`
+/// reports at most `k`, and `lo` at most `-(f(k + 1) - f(k))`.
+///
+/// A flat stretch of `f` makes a whole range of counts optimal for the same
+/// `lambda`, so the exact `k` may never be reported. Integer slopes make
+/// that harmless: the counts optimal at the `lambda` found run from the
+/// reported one up to the one reported at `lambda - 1`, which is past `k`,
+/// so `k` is among them and `f(k)` is the penalized value minus `lambda k`.
+/// This takes `O(log(hi - lo))` calls of `solve`.
+pub fn aliens<F: FnMut(i64) -> (i64, usize)>(k: usize, mut lo: i64, mut hi: i64, mut solve: F) -> i64 {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if solve(mid).1 <= k {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    let (value, _) = solve(lo);
+    value - lo * k as i64
+}
+
+/// The smallest total of `k` disjoint non-empty subarrays of `a`, in
+/// `O(n log(sum |a|))`. The total is convex in `k`; with a penalty on every
+/// subarray opened, a two-state scan over `(value, count)` pairs breaks
+/// ties toward fewer subarrays, as `aliens` needs.
+fn min_k_subarrays(a: &[i64], k: usize) -> i64 {
+    let max_abs = a.iter().map(|x| x.abs()).max().unwrap_or(0);
+    let total_abs: i64 = a.iter().map(|x| x.abs()).sum();
+    // Every slope of the total lies in `[-total_abs, max_abs]`.
+    aliens(k, -max_abs - 1, total_abs + 1, |lambda| {
+        // Best `(value, count)` so far with the last element outside any
+        // subarray, and inside one; tuples order ties toward fewer subarrays.
+        let mut outside = (0, 0);
+        let mut inside: Option<(i64, usize)> = None;
+        for &x in a {
+            let best = inside.map_or(outside, |s| s.min(outside));
+            let open = (best.0 + lambda, best.1 + 1);
+            let (value, count) = inside.map_or(open, |s| s.min(open));
+            outside = best;
+            inside = Some((value + x, count));
+        }
+        inside.map_or(outside, |s| s.min(outside))
+    })
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n k` with `1 <= k <= n`, then the `n` values.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let _n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let k: usize = parts.next().unwrap().parse().expect("Failed to parse k");
+    let line = lines.next().unwrap().expect("Failed to read the values");
+    let a: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a value")).collect();
+
+    // --- Solving and Output ---
+    println!("{}", min_k_subarrays(&a, k));
+}
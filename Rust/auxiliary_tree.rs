@@ -0,0 +1,286 @@
+// Auxiliary (virtual) tree construction.
+//
+// Input: `N Q`, the parents `p_1 .. p_{N-1}` of a tree rooted at 0, then `Q`
+// lines `k v_1 .. v_k`. For every query prints the number of vertices `K` of
+// the compressed tree, its vertices on one line, and `K - 1` lines `p c`
+// giving each non-root vertex with its parent in the compressed tree.
+use std::io::{self, BufRead};
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    /// Returns the entry and exit times of every node on a DFS of the tree.
+    ///
+    /// `tin[v]` is the position of `v` in preorder and `tout[v]` is one past the
+    /// position of its last descendant, so the subtree of `v` is exactly the
+    /// half-open range `tin[v]..tout[v]` of preorder positions.
+    pub fn euler_times(&self) -> (Vec<usize>, Vec<usize>) {
+        let n = self.children.len();
+        let mut tin = vec![0; n];
+        let mut tout = vec![0; n];
+        let mut timer = 0;
+        // Each stack entry is `(node, index of the next child to visit)`.
+        let mut stack = vec![(self.root, 0)];
+        tin[self.root] = timer;
+        timer += 1;
+
+        while let Some((u, next_child)) = stack.last_mut() {
+            if let Some(&c) = self.children[*u].get(*next_child) {
+                *next_child += 1;
+                tin[c] = timer;
+                timer += 1;
+                stack.push((c, 0));
+            } else {
+                tout[*u] = timer;
+                stack.pop();
+            }
+        }
+
+        (tin, tout)
+    }
+}
+
+/// LCA by binary lifting: `O(n log n)` preprocessing, `O(log n)` per query.
+///
+/// Ancestor checks use the Euler tour times instead of depths, so one DFS
+/// serves both the LCA and the preorder sorting of the auxiliary tree.
+#[derive(Debug)]
+pub struct BinaryLifting {
+    /// `up[k][v]` is the `2^k`-th ancestor of `v` (clamped at the root).
+    up: Vec<Vec<usize>>,
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+}
+
+impl BinaryLifting {
+    fn new(tree: &Tree) -> Self {
+        let n = tree.parent.len();
+        let (tin, tout) = tree.euler_times();
+
+        let mut levels = 1;
+        while (1 << levels) < n {
+            levels += 1;
+        }
+
+        let mut up = vec![tree.parent.clone()];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+
+        Self { up, tin, tout }
+    }
+
+    /// Returns whether `u` is an ancestor of `v` (every node is its own ancestor).
+    fn is_ancestor(&self, u: usize, v: usize) -> bool {
+        self.tin[u] <= self.tin[v] && self.tout[v] <= self.tout[u]
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, mut u: usize, v: usize) -> usize {
+        if self.is_ancestor(u, v) {
+            return u;
+        }
+        if self.is_ancestor(v, u) {
+            return v;
+        }
+        // Lift `u` as long as it stays strictly below the common ancestor.
+        for up in self.up.iter().rev() {
+            if !self.is_ancestor(up[u], v) {
+                u = up[u];
+            }
+        }
+        self.up[0][u]
+    }
+
+    /// Builds the auxiliary tree of `vertices`: the smallest tree that contains
+    /// them and is closed under taking LCAs, with every compressed path
+    /// replaced by a single edge. Runs in `O(k log n)` for `k` vertices.
+    ///
+    /// Returns `(mapping, edges)`, where `mapping[i]` is the original vertex of
+    /// compressed vertex `i` (sorted by preorder, so `mapping[0]` is the root)
+    /// and `edges` holds `(parent, child)` pairs of compressed indices.
+    pub fn auxiliary_tree(&self, vertices: &[usize]) -> (Vec<usize>, Vec<(usize, usize)>) {
+        if vertices.is_empty() {
+            return (Vec::new(), Vec::new());
+        }
+
+        let mut mapping = vertices.to_vec();
+        mapping.sort_by_key(|&v| self.tin[v]);
+        mapping.dedup();
+
+        // The LCAs of preorder-adjacent vertices are exactly the extra vertices needed.
+        let lcas: Vec<usize> = mapping.windows(2).map(|w| self.lca(w[0], w[1])).collect();
+        mapping.extend(lcas);
+        mapping.sort_by_key(|&v| self.tin[v]);
+        mapping.dedup();
+
+        // In the closed set, the parent of each vertex is the LCA with its preorder predecessor.
+        let index = |v: usize| mapping.binary_search_by_key(&self.tin[v], |&u| self.tin[u]).unwrap();
+        let edges = mapping
+            .windows(2)
+            .enumerate()
+            .map(|(i, w)| (index(self.lca(w[0], w[1])), i + 1))
+            .collect();
+
+        (mapping, edges)
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    // Node 0 is the root; `p_i` is given for i = 1..n.
+    let mut parent = vec![0; n];
+    if n > 1 {
+        let line = lines.next().unwrap().expect("Failed to read the parents");
+        for (i, p) in line.split_whitespace().enumerate() {
+            parent[i + 1] = p.parse().expect("Failed to parse p");
+        }
+    }
+
+    let bl = BinaryLifting::new(&Tree::new(parent, 0));
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let vertices: Vec<usize> = line
+            .split_whitespace()
+            .skip(1)
+            .map(|s| s.parse().expect("Failed to parse a vertex"))
+            .collect();
+
+        let (mapping, edges) = bl.auxiliary_tree(&vertices);
+        println!("{}", mapping.len());
+        let names: Vec<String> = mapping.iter().map(|v| v.to_string()).collect();
+        println!("{}", names.join(" "));
+        for (p, c) in edges {
+            println!("{} {}", mapping[p], mapping[c]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// The LCA by walking parents, given depths.
+    fn naive_lca(parent: &[usize], depth: &[usize], mut u: usize, mut v: usize) -> usize {
+        while depth[u] > depth[v] {
+            u = parent[u];
+        }
+        while depth[v] > depth[u] {
+            v = parent[v];
+        }
+        while u != v {
+            u = parent[u];
+            v = parent[v];
+        }
+        u
+    }
+
+    #[test]
+    fn random_subsets_are_compressed_correctly() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..500 {
+            let n = 1 + rng.below(40);
+            // Parents precede their children, so depths fill in one pass.
+            let mut parent = vec![0; n];
+            let mut depth = vec![0; n];
+            for v in 1..n {
+                parent[v] = rng.below(v);
+                depth[v] = depth[parent[v]] + 1;
+            }
+            let bl = BinaryLifting::new(&Tree::new(parent.clone(), 0));
+
+            for _ in 0..10 {
+                let vertices: Vec<usize> = (0..1 + rng.below(n)).map(|_| rng.below(n)).collect();
+                let (mapping, edges) = bl.auxiliary_tree(&vertices);
+                let mut kept = vec![false; n];
+                for &v in &mapping {
+                    assert!(!kept[v], "vertex {} appears twice", v);
+                    kept[v] = true;
+                }
+                assert!(vertices.iter().all(|&v| kept[v]));
+
+                let mut distinct = vertices.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+                assert!(mapping.len() < 2 * distinct.len());
+
+                for &u in &mapping {
+                    for &v in &mapping {
+                        assert!(kept[naive_lca(&parent, &depth, u, v)]);
+                    }
+                }
+
+                // Every non-root vertex is the child of exactly one edge, whose
+                // parent is its nearest kept proper ancestor.
+                assert_eq!(edges.len(), mapping.len() - 1);
+                let mut compressed_parent = vec![None; mapping.len()];
+                for &(p, c) in &edges {
+                    assert!(compressed_parent[c].is_none());
+                    compressed_parent[c] = Some(mapping[p]);
+                }
+                for (i, &v) in mapping.iter().enumerate() {
+                    let mut u = v;
+                    let nearest = loop {
+                        if u == parent[u] {
+                            break None;
+                        }
+                        u = parent[u];
+                        if kept[u] {
+                            break Some(u);
+                        }
+                    };
+                    assert_eq!(compressed_parent[i], nearest, "parent of {}", v);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly prime with primitive root 3.
+const MOD: u64 = 998244353;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    base %= MOD;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MOD;
+        }
+        base = base * base % MOD;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `MOD` of length
+/// `a.len()` (a power of two).
+fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (MOD - 1) / len as u64);
+        if invert {
+            w_len = pow_mod(w_len, MOD - 2);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % MOD;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % MOD;
+                *x = if u + v >= MOD { u + v - MOD } else { u + v };
+                *y = if u >= v { u - v } else { u + MOD - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, MOD - 2);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % MOD;
+        }
+    }
+}
+
+/// The product of the polynomials `a` and `b` modulo `MOD`. Small inputs use
+/// the schoolbook product, which beats the transform below a few dozen terms.
+fn convolve(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 32 {
+        let mut result = vec![0; result_len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] = (result[i + j] + x * y) % MOD;
+            }
+        }
+        return result;
+    }
+
+    let size = result_len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(size, 0);
+    fb.resize(size, 0);
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y % MOD;
+    }
+    ntt(&mut fa, true);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// `inverses[i]` is the inverse of `i` modulo `MOD` for `1 <= i < n`.
+fn inverses(n: usize) -> Vec<u64> {
+    let mut inv = vec![0, 1];
+    for i in 2..n as u64 {
+        inv.push((MOD - MOD / i) * inv[(MOD % i) as usize] % MOD);
+    }
+    inv.truncate(n);
+    inv
+}
+
+/// A square root of `a` modulo `MOD` by Tonelli-Shanks, or `None` if `a`
+/// is a quadratic non-residue.
+fn sqrt_mod(a: u64) -> Option<u64> {
+    let a = a % MOD;
+    if a < 2 {
+        return Some(a);
+    }
+    // Euler's criterion.
+    if pow_mod(a, (MOD - 1) / 2) != 1 {
+        return None;
+    }
+
+    // MOD - 1 = q * 2^s with q odd.
+    let s = (MOD - 1).trailing_zeros();
+    let q = (MOD - 1) >> s;
+    let z = (2..).find(|&z| pow_mod(z, (MOD - 1) / 2) == MOD - 1).unwrap();
+
+    let mut m = s;
+    let mut c = pow_mod(z, q);
+    let mut t = pow_mod(a, q);
+    let mut r = pow_mod(a, q.div_ceil(2));
+    // Invariant: r^2 = a t, and t has order dividing 2^(m-1).
+    while t != 1 {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = t2 * t2 % MOD;
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1));
+        m = i;
+        c = b * b % MOD;
+        t = t * c % MOD;
+        r = r * b % MOD;
+    }
+    Some(r)
+}
+
+/// Series with at most this many non-zero terms take the `O(nk)` sparse
+/// paths of `exp` and `log` instead of Newton iteration.
+const SPARSE_THRESHOLD: usize = 32;
+
+/// A formal power series with coefficients modulo `MOD`.
+///
+/// Operations that produce infinite series take the number of terms `n` to
+/// compute and return exactly `n` coefficients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fps {
+    coef: Vec<u64>,
+}
+
+impl Fps {
+    pub fn new(coef: Vec<u64>) -> Self {
+        Self { coef: coef.into_iter().map(|x| x % MOD).collect() }
+    }
+
+    pub fn coef(&self) -> &[u64] {
+        &self.coef
+    }
+
+    /// The coefficient of `x^i`, zero beyond the stored terms.
+    pub fn get(&self, i: usize) -> u64 {
+        self.coef.get(i).copied().unwrap_or(0)
+    }
+
+    /// The first `n` coefficients, padded with zeros if needed.
+    pub fn truncated(&self, n: usize) -> Fps {
+        let mut coef = self.coef[..n.min(self.coef.len())].to_vec();
+        coef.resize(n, 0);
+        Fps { coef }
+    }
+
+    /// The non-zero terms as `(exponent, coefficient)` pairs.
+    pub fn nonzero_terms(&self) -> Vec<(usize, u64)> {
+        self.coef.iter().enumerate().filter(|&(_, &c)| c != 0).map(|(i, &c)| (i, c)).collect()
+    }
+
+    pub fn derivative(&self) -> Fps {
+        let coef = self.coef.iter().enumerate().skip(1).map(|(i, &c)| c * i as u64 % MOD).collect();
+        Fps { coef }
+    }
+
+    /// The antiderivative with zero constant term.
+    pub fn integral(&self) -> Fps {
+        let inv = inverses(self.coef.len() + 1);
+        let mut coef = vec![0];
+        coef.extend(self.coef.iter().enumerate().map(|(i, &c)| c * inv[i + 1] % MOD));
+        Fps { coef }
+    }
+
+    /// The first `n` terms of `1 / f` by Newton iteration, `g <- g (2 - f g)`,
+    /// doubling the precision each round. The constant term must be non-zero.
+    pub fn inv(&self, n: usize) -> Fps {
+        assert!(self.get(0) != 0, "inverse of a series with zero constant term");
+        let mut g = vec![pow_mod(self.get(0), MOD - 2)];
+        let mut m = 1;
+        while m < n {
+            m *= 2;
+            let fg = convolve(&self.truncated(m).coef, &g);
+            let mut correction: Vec<u64> = fg[..m.min(fg.len())].iter().map(|&x| (MOD - x) % MOD).collect();
+            correction[0] = (correction[0] + 2) % MOD;
+            g = convolve(&g, &correction);
+            g.truncate(m);
+        }
+        Fps { coef: g }.truncated(n)
+    }
+
+    /// The first `n` terms of `log f = integral(f' / f)`. The constant term must be one.
+    pub fn log(&self, n: usize) -> Fps {
+        assert_eq!(self.get(0), 1, "log of a series with constant term other than one");
+        if n == 0 {
+            return Fps { coef: Vec::new() };
+        }
+        let terms = self.truncated(n).nonzero_terms();
+        if terms.len() <= SPARSE_THRESHOLD {
+            return Self::log_sparse(&terms, n);
+        }
+
+        let quotient = convolve(&self.truncated(n).derivative().coef, &self.inv(n).coef);
+        Fps { coef: quotient }.truncated(n - 1).integral()
+    }
+
+    /// `log f` from the non-zero terms of `f` in `O(nk)`, using `f g' = f'`:
+    /// `i g_i = i f_i - sum_{j >= 1} f_j (i - j) g_{i-j}`.
+    pub fn log_sparse(terms: &[(usize, u64)], n: usize) -> Fps {
+        let inv = inverses(n);
+        let mut f = vec![0; n];
+        for &(i, c) in terms {
+            if i < n {
+                f[i] = c;
+            }
+        }
+        // `d[i]` holds `i * g_i`.
+        let mut d = vec![0; n];
+        for i in 1..n {
+            let mut acc = f[i] * i as u64 % MOD;
+            for &(j, c) in terms {
+                if j == 0 || j >= i {
+                    continue;
+                }
+                acc = (acc + MOD - c * d[i - j] % MOD) % MOD;
+            }
+            d[i] = acc;
+        }
+        let coef = (0..n).map(|i| if i == 0 { 0 } else { d[i] * inv[i] % MOD }).collect();
+        Fps { coef }
+    }
+
+    /// The first `n` terms of `exp f` by Newton iteration,
+    /// `g <- g (1 - log g + f)`. The constant term must be zero.
+    pub fn exp(&self, n: usize) -> Fps {
+        assert_eq!(self.get(0), 0, "exp of a series with non-zero constant term");
+        if n == 0 {
+            return Fps { coef: Vec::new() };
+        }
+        let terms = self.truncated(n).nonzero_terms();
+        if terms.len() <= SPARSE_THRESHOLD {
+            return Self::exp_sparse(&terms, n);
+        }
+
+        let mut g = Fps { coef: vec![1] };
+        let mut m = 1;
+        while m < n {
+            m *= 2;
+            let log_g = g.log(m);
+            let mut correction: Vec<u64> = (0..m).map(|i| (self.get(i) + MOD - log_g.coef[i]) % MOD).collect();
+            correction[0] = (correction[0] + 1) % MOD;
+            let mut next = convolve(&g.coef, &correction);
+            next.truncate(m);
+            g = Fps { coef: next };
+        }
+        g.truncated(n)
+    }
+
+    /// `exp f` from the non-zero terms of `f` in `O(nk)`, using `g' = f' g`:
+    /// `i g_i = sum_j j f_j g_{i-j}`.
+    pub fn exp_sparse(terms: &[(usize, u64)], n: usize) -> Fps {
+        let inv = inverses(n);
+        let mut g = vec![0; n];
+        if n > 0 {
+            g[0] = 1;
+        }
+        for i in 1..n {
+            let mut acc = 0;
+            for &(j, c) in terms {
+                if j == 0 || j > i {
+                    continue;
+                }
+                acc = (acc + c * j as u64 % MOD * g[i - j]) % MOD;
+            }
+            g[i] = acc * inv[i] % MOD;
+        }
+        Fps { coef: g }
+    }
+
+    /// The first `n` terms of `f^k`.
+    ///
+    /// Writing `f = c x^d g` with `g(0) = 1`, this is `c^k x^{dk} exp(k log g)`.
+    /// `f^0 = 1` (even for `f = 0`), and once `dk >= n` every requested
+    /// term is zero; that is checked before multiplying so `dk` can't overflow.
+    pub fn pow(&self, k: u64, n: usize) -> Fps {
+        if k == 0 {
+            return Fps { coef: vec![1] }.truncated(n);
+        }
+        let Some(d) = self.coef.iter().position(|&c| c != 0) else {
+            return Fps { coef: vec![0; n] };
+        };
+        if d > 0 && k >= n.div_ceil(d) as u64 {
+            return Fps { coef: vec![0; n] };
+        }
+        let shift = d * k as usize;
+        let m = n - shift;
+
+        let c = self.coef[d];
+        let c_inv = pow_mod(c, MOD - 2);
+        let g = Fps { coef: self.coef[d..].iter().map(|&x| x * c_inv % MOD).collect() };
+        let k_mod = k % MOD;
+        let log_g = g.log(m);
+        let scaled = Fps { coef: log_g.coef.iter().map(|&x| x * k_mod % MOD).collect() };
+        let c_k = pow_mod(c, k);
+
+        let mut coef = vec![0; shift];
+        coef.extend(scaled.exp(m).coef.iter().map(|&x| x * c_k % MOD));
+        Fps { coef }
+    }
+
+    /// The first `n` terms of a square root of `f`, or `None` if there is none.
+    ///
+    /// The lowest-order term `c x^d` needs an even `d` and a square root of
+    /// `c`; the normalised rest is handled by Newton iteration,
+    /// `h <- (h + g / h) / 2`.
+    pub fn sqrt(&self, n: usize) -> Option<Fps> {
+        let Some(d) = self.coef.iter().position(|&c| c != 0) else {
+            return Some(Fps { coef: vec![0; n] });
+        };
+        if d % 2 == 1 {
+            return None;
+        }
+        let shift = d / 2;
+        if shift >= n {
+            return Some(Fps { coef: vec![0; n] });
+        }
+        let root = sqrt_mod(self.coef[d])?;
+        let m = n - shift;
+
+        let c_inv = pow_mod(self.coef[d], MOD - 2);
+        let g = Fps { coef: self.coef[d..].iter().map(|&x| x * c_inv % MOD).collect() };
+        let inv2 = pow_mod(2, MOD - 2);
+        let mut h = Fps { coef: vec![1] };
+        let mut size = 1;
+        while size < m {
+            size *= 2;
+            let quotient = convolve(&g.truncated(size).coef, &h.inv(size).coef);
+            let coef = (0..size).map(|i| (h.get(i) + quotient[i]) * inv2 % MOD).collect();
+            h = Fps { coef };
+        }
+
+        let mut coef = vec![0; shift];
+        coef.extend(h.truncated(m).coef.iter().map(|&x| x * root % MOD));
+        Some(Fps { coef })
+    }
+
+    /// Drops trailing zero coefficients, so the length is the degree plus one
+    /// (and zero for the zero polynomial).
+    pub fn trimmed(mut self) -> Fps {
+        while self.coef.last() == Some(&0) {
+            self.coef.pop();
+        }
+        self
+    }
+
+    /// Polynomial division with remainder: returns `(q, r)` with
+    /// `f = q g + r` and `deg r < deg g`, both trimmed. `g` must be non-zero.
+    ///
+    /// Reversing the coefficients turns the quotient into the first
+    /// `deg f - deg g + 1` terms of `rev(f) / rev(g)` as power series, so it
+    /// costs one series inverse and two products, `O(n log n)`.
+    pub fn div_rem(&self, g: &Fps) -> (Fps, Fps) {
+        let f = self.clone().trimmed();
+        let g = g.clone().trimmed();
+        assert!(!g.coef.is_empty(), "division by the zero polynomial");
+        if f.coef.len() < g.coef.len() {
+            return (Fps { coef: Vec::new() }, f);
+        }
+
+        let k = f.coef.len() - g.coef.len() + 1;
+        let rev_f: Vec<u64> = f.coef.iter().rev().take(k).copied().collect();
+        let rev_g = Fps { coef: g.coef.iter().rev().copied().collect() };
+        let mut q = convolve(&rev_f, &rev_g.inv(k).coef);
+        q.truncate(k);
+        q.reverse();
+
+        let r_len = g.coef.len() - 1;
+        let gq = convolve(&g.coef[..r_len], &q[..r_len.min(k)]);
+        let r = (0..r_len).map(|i| (f.coef[i] + MOD - gq.get(i).copied().unwrap_or(0)) % MOD).collect();
+
+        (Fps { coef: q }.trimmed(), Fps { coef: r }.trimmed())
+    }
+}
+
+/// Factorials and inverse factorials of `0..n` modulo `MOD`.
+fn factorials(n: usize) -> (Vec<u64>, Vec<u64>) {
+    let mut fact = vec![1; n.max(1)];
+    for i in 1..fact.len() {
+        fact[i] = fact[i - 1] * i as u64 % MOD;
+    }
+    let mut inv_fact = vec![1; fact.len()];
+    inv_fact[fact.len() - 1] = pow_mod(fact[fact.len() - 1], MOD - 2);
+    for i in (1..fact.len()).rev() {
+        inv_fact[i - 1] = inv_fact[i] * i as u64 % MOD;
+    }
+    (fact, inv_fact)
+}
+
+/// The Bernoulli numbers `B_0..=B_n` modulo `MOD` (with `B_1 = -1/2`), from
+/// the exponential generating function `x / (e^x - 1)`: one series inverse
+/// of `(e^x - 1) / x = sum x^i / (i + 1)!`, so `O(n log n)`.
+pub fn bernoulli(n: usize) -> Vec<u64> {
+    let (fact, inv_fact) = factorials(n + 2);
+    let series = Fps::new((0..=n).map(|i| inv_fact[i + 1]).collect());
+    let egf = series.inv(n + 1);
+    egf.coef().iter().enumerate().map(|(i, &c)| c * fact[i] % MOD).collect()
+}
+
+/// `0^k + 1^k + ... + (m - 1)^k` modulo `MOD` by Faulhaber's formula,
+/// `(1 / (k + 1)) sum_{j <= k} C(k + 1, j) B_j m^{k+1-j}`, given
+/// `b = bernoulli(k)` or a longer prefix.
+pub fn power_sum(k: usize, m: u64, b: &[u64]) -> u64 {
+    let (fact, inv_fact) = factorials(k + 2);
+    let m = m % MOD;
+    let mut total = 0;
+    for (j, &bj) in b.iter().enumerate().take(k + 1) {
+        let binom = fact[k + 1] * inv_fact[j] % MOD * inv_fact[k + 1 - j] % MOD;
+        total = (total + binom * bj % MOD * pow_mod(m, (k + 1 - j) as u64)) % MOD;
+    }
+    total * pow_mod(k as u64 + 1, MOD - 2) % MOD
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    // --- Output ---
+    let output: Vec<String> = bernoulli(n).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,203 @@
+use std::io::{self, BufRead};
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// The inverse of `a` modulo `m`, for `gcd(a, m) = 1`, by the extended
+/// Euclidean algorithm.
+fn inv_mod(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i64 % m as i64, m as i64);
+    let (mut old_s, mut s) = (1i64, 0i64);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(m as i64) as u64
+}
+
+/// Binomial coefficients modulo a prime power `q = p^e` for `n` up to `10^18`.
+///
+/// Write `n! = p^{v(n)} * prod_{i >= 0} F(n / p^i)`, where `F(x)` is the
+/// product of the numbers in `1..=x` not divisible by `p`. Modulo `q`, `F`
+/// is periodic up to a sign: `F(x) = F(q)^{x / q} F(x mod q)` with
+/// `F(q) = +-1`, so a table of `F` over one period answers everything.
+///
+/// That table takes `O(q)` time and memory, which suits the judge's
+/// `m <= 10^6` but not a large prime modulus such as `998244353`.
+#[derive(Debug)]
+pub struct PrimePowerBinomial {
+    p: u64,
+    e: u32,
+    q: u64,
+    /// `table[x]` is `F(x) mod q` for `0 <= x < q`.
+    table: Vec<u64>,
+    /// Whether `F(q) = -1 (mod q)`.
+    period_negates: bool,
+}
+
+impl PrimePowerBinomial {
+    /// The largest `q` whose table is built; products of two residues also
+    /// stay far below `u64::MAX`.
+    pub const MAX_MODULUS: u64 = 1_000_000;
+
+    pub fn new(p: u64, e: u32) -> Self {
+        let q = p.pow(e);
+        assert!(
+            q <= Self::MAX_MODULUS,
+            "prime power {}^{} = {} exceeds {}: its factorial table would take O(q) memory",
+            p,
+            e,
+            q,
+            Self::MAX_MODULUS
+        );
+        let mut table = vec![1 % q; q as usize];
+        for x in 1..q as usize {
+            table[x] = if (x as u64).is_multiple_of(p) { table[x - 1] } else { table[x - 1] * x as u64 % q };
+        }
+        let period_negates = q > 2 && table[q as usize - 1] == q - 1;
+        Self { p, e, q, table, period_negates }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.q
+    }
+
+    /// `F(x) mod q`.
+    fn unit_factorial(&self, x: u64) -> u64 {
+        let r = self.table[(x % self.q) as usize];
+        if self.period_negates && (x / self.q) % 2 == 1 { (self.q - r) % self.q } else { r }
+    }
+
+    /// `n choose k` modulo `p` by Lucas' theorem: the product of the
+    /// binomials of the base-`p` digits. For `e = 1` the table holds plain
+    /// factorials below `p`.
+    pub fn lucas(&self, mut n: u64, mut k: u64) -> u64 {
+        debug_assert_eq!(self.e, 1);
+        let p = self.p;
+        let mut result = 1 % p;
+        while k > 0 {
+            let (a, b) = (n % p, k % p);
+            if b > a {
+                return 0;
+            }
+            let denominator = self.table[b as usize] * self.table[(a - b) as usize] % p;
+            result = result * self.table[a as usize] % p * inv_mod(denominator, p) % p;
+            n /= p;
+            k /= p;
+        }
+        result
+    }
+
+    /// `n choose k` modulo `q`, zero when `k > n`.
+    pub fn binom(&self, n: u64, k: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+        if self.e == 1 {
+            return self.lucas(n, k);
+        }
+
+        let r = n - k;
+        let (mut a, mut b, mut c) = (n, k, r);
+        let mut exponent = 0;
+        let mut numerator = 1 % self.q;
+        let mut denominator = 1 % self.q;
+        while a > 0 {
+            numerator = numerator * self.unit_factorial(a) % self.q;
+            denominator = denominator * self.unit_factorial(b) % self.q * self.unit_factorial(c) % self.q;
+            a /= self.p;
+            b /= self.p;
+            c /= self.p;
+            // Legendre: `v(x!)` is the sum of `x / p^i` over `i >= 1`.
+            exponent += a - b - c;
+        }
+        if exponent >= self.e as u64 {
+            return 0;
+        }
+        numerator * inv_mod(denominator, self.q) % self.q * pow_mod(self.p, exponent, self.q) % self.q
+    }
+}
+
+/// Binomial coefficients modulo an arbitrary `m`: one `PrimePowerBinomial`
+/// per prime power of `m`, recombined by the Chinese remainder theorem.
+#[derive(Debug)]
+pub struct BinomialMod {
+    m: u64,
+    /// Each prime power with its CRT basis element, which is `1` modulo that
+    /// prime power and `0` modulo the others.
+    parts: Vec<(PrimePowerBinomial, u64)>,
+}
+
+impl BinomialMod {
+    pub fn new(m: u64) -> Self {
+        let mut factors = Vec::new();
+        let mut rest = m;
+        let mut p = 2;
+        while p * p <= rest {
+            if rest.is_multiple_of(p) {
+                let mut e = 0;
+                while rest.is_multiple_of(p) {
+                    rest /= p;
+                    e += 1;
+                }
+                factors.push((p, e));
+            }
+            p += 1;
+        }
+        if rest > 1 {
+            factors.push((rest, 1));
+        }
+
+        let parts = factors
+            .into_iter()
+            .map(|(p, e)| {
+                let part = PrimePowerBinomial::new(p, e);
+                let q = part.modulus();
+                let others = m / q;
+                let basis = others * inv_mod(others % q, q) % m;
+                (part, basis)
+            })
+            .collect();
+        Self { m, parts }
+    }
+
+    /// `n choose k` modulo `m`, zero when `k > n`.
+    pub fn binom(&self, n: u64, k: u64) -> u64 {
+        self.parts.iter().fold(0, |acc, (part, basis)| (acc + part.binom(n, k) * basis) % self.m)
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+    let m: u64 = parts.next().unwrap().parse().expect("Failed to parse m");
+    let binomial = BinomialMod::new(m);
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let n: u64 = parts.next().unwrap().parse().expect("Failed to parse n");
+        let k: u64 = parts.next().unwrap().parse().expect("Failed to parse k");
+        output.push(binomial.binom(n, k).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
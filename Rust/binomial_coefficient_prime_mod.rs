@@ -0,0 +1,99 @@
+use std::io::{self, BufRead};
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Factorials and inverse factorials modulo a prime, for `O(1)` counting
+/// formulas.
+#[derive(Debug)]
+pub struct Combinatorics {
+    modulus: u64,
+    fact: Vec<u64>,
+    inv_fact: Vec<u64>,
+}
+
+impl Combinatorics {
+    /// Precomputes `0!..=n!` and their inverses modulo the prime `modulus`.
+    /// `n` must be below `modulus`, otherwise `n!` would vanish.
+    pub fn new(n: usize, modulus: u64) -> Self {
+        assert!((n as u64) < modulus, "factorials up to the modulus vanish");
+        let mut fact = vec![1 % modulus; n + 1];
+        for i in 1..=n {
+            fact[i] = fact[i - 1] * i as u64 % modulus;
+        }
+        // One exponentiation, then walk down with `1/(i-1)! = i / i!`.
+        let mut inv_fact = vec![0; n + 1];
+        inv_fact[n] = pow_mod(fact[n], modulus - 2, modulus);
+        for i in (1..=n).rev() {
+            inv_fact[i - 1] = inv_fact[i] * i as u64 % modulus;
+        }
+        Self { modulus, fact, inv_fact }
+    }
+
+    pub fn fact(&self, n: usize) -> u64 {
+        self.fact[n]
+    }
+
+    pub fn inv_fact(&self, n: usize) -> u64 {
+        self.inv_fact[n]
+    }
+
+    /// `n choose k`, zero when `k > n`.
+    pub fn binom(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        self.fact[n] * self.inv_fact[k] % self.modulus * self.inv_fact[n - k] % self.modulus
+    }
+
+    /// Number of ordered selections of `k` out of `n`, zero when `k > n`.
+    pub fn perm(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        self.fact[n] * self.inv_fact[n - k] % self.modulus
+    }
+
+    /// The `n`-th Catalan number `(2n choose n) / (n + 1)`; needs `2n` precomputed.
+    pub fn catalan(&self, n: usize) -> u64 {
+        self.fact[2 * n] * self.inv_fact[n + 1] % self.modulus * self.inv_fact[n] % self.modulus
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+    let m: u64 = parts.next().unwrap().parse().expect("Failed to parse m");
+
+    let queries: Vec<(usize, usize)> = (0..t)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read a query line");
+            let mut parts = line.split_whitespace();
+            let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+            let k: usize = parts.next().unwrap().parse().expect("Failed to parse k");
+            (n, k)
+        })
+        .collect();
+
+    // --- Query Processing and Output ---
+    let max_n = queries.iter().map(|&(n, _)| n).max().unwrap_or(0);
+    let comb = Combinatorics::new(max_n, m);
+    let output: Vec<String> = queries.iter().map(|&(n, k)| comb.binom(n, k).to_string()).collect();
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,177 @@
+use std::io::{self, BufRead};
+
+/// Builds the (min-)Cartesian tree of `values` in `O(n)` with a monotone stack.
+///
+/// Returns the parent array, where the root (the position of the minimum) is
+/// its own parent. Ties are broken by position, so equal values keep the
+/// leftmost one as the ancestor. An in-order walk of the tree gives back the
+/// original sequence, and the LCA of `i` and `j` is the position of the
+/// minimum of `values[i..=j]`, which is what reduces RMQ to LCA.
+pub fn cartesian_tree<T: Ord>(values: &[T]) -> Vec<usize> {
+    let n = values.len();
+    let mut parent = vec![usize::MAX; n];
+    // Positions whose values are increasing from bottom to top: the right spine.
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let mut last_popped = None;
+        while let Some(&top) = stack.last() {
+            if values[top] <= values[i] {
+                break;
+            }
+            last_popped = stack.pop();
+        }
+
+        // The popped chain becomes the left subtree of `i` ...
+        if let Some(child) = last_popped {
+            parent[child] = i;
+        }
+        // ... and `i` hangs off the remaining spine as a right child.
+        if let Some(&top) = stack.last() {
+            parent[i] = top;
+        }
+        stack.push(i);
+    }
+
+    // The bottom of the stack is the overall minimum.
+    if let Some(&root) = stack.first() {
+        parent[root] = root;
+    }
+    parent
+}
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+pub struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    /// The Cartesian tree of the non-empty `values` (see `cartesian_tree`),
+    /// rooted at the position of the minimum, ready for any LCA backend:
+    /// the LCA of `i <= j` then answers the range minimum of `values[i..=j]`.
+    pub fn cartesian<T: Ord>(values: &[T]) -> Self {
+        let parent = cartesian_tree(values);
+        let root = (0..parent.len()).find(|&v| parent[v] == v).expect("values must be non-empty");
+        Self::new(parent, root)
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    pub fn parent(&self, v: usize) -> usize {
+        self.parent[v]
+    }
+
+    pub fn children(&self, v: usize) -> &[usize] {
+        &self.children[v]
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    let values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the values")
+        .split_whitespace()
+        .take(n)
+        .map(|s| s.parse().expect("Failed to parse a value"))
+        .collect();
+
+    // --- Output ---
+    let parent = cartesian_tree(&values);
+    let output: Vec<String> = parent.iter().map(|p| p.to_string()).collect();
+    println!("{}", output.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// The ancestors of `v` from `v` itself up to the root.
+    fn ancestors(tree: &Tree, mut v: usize) -> Vec<usize> {
+        let mut path = vec![v];
+        while v != tree.root() {
+            v = tree.parent(v);
+            path.push(v);
+        }
+        path
+    }
+
+    #[test]
+    fn lca_is_the_leftmost_range_minimum() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..500 {
+            let n = 1 + rng.below(30);
+            // Few distinct values, so ties are common.
+            let values: Vec<u8> = (0..n).map(|_| rng.below(5) as u8).collect();
+            let tree = Tree::cartesian(&values);
+            for i in 0..n {
+                let up = ancestors(&tree, i);
+                for j in i..n {
+                    let lca = ancestors(&tree, j).into_iter().find(|v| up.contains(v)).unwrap();
+                    let argmin = (i..=j).min_by_key(|&k| (values[k], k)).unwrap();
+                    assert_eq!(lca, argmin, "{:?} [{}, {}]", values, i, j);
+                }
+            }
+
+            // Every node has at most one child on each side, so an in-order
+            // walk gives back the positions in order.
+            let mut order = Vec::new();
+            let mut stack = vec![(tree.root(), false)];
+            while let Some((v, expanded)) = stack.pop() {
+                let kids = tree.children(v);
+                assert!(kids.len() <= 2 && kids.iter().filter(|&&c| c < v).count() <= 1);
+                if expanded {
+                    order.push(v);
+                    continue;
+                }
+                stack.extend(kids.iter().filter(|&&c| c > v).map(|&c| (c, false)));
+                stack.push((v, true));
+                stack.extend(kids.iter().filter(|&&c| c < v).map(|&c| (c, false)));
+            }
+            assert_eq!(order, (0..n).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::io::{self, BufRead};
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The orientation of `a -> b -> c`: positive for a left turn, negative for
+/// a right turn, zero when collinear.
+pub fn orient(a: Point, b: Point, c: Point) -> i128 {
+    (b - a).cross(c - a)
+}
+
+/// A point with floating coordinates, for constructions whose results are
+/// irrational. Every construction below decides how many points it returns
+/// exactly on integer inputs and only computes their positions in `f64`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PointF {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PointF {
+    pub fn new(x: f64, y: f64) -> Self {
+        PointF { x, y }
+    }
+
+    /// `self` rotated by a quarter turn counterclockwise.
+    pub fn perp(self) -> PointF {
+        PointF::new(-self.y, self.x)
+    }
+}
+
+impl From<Point> for PointF {
+    fn from(p: Point) -> Self {
+        PointF::new(p.x as f64, p.y as f64)
+    }
+}
+
+impl std::ops::Add for PointF {
+    type Output = PointF;
+    fn add(self, rhs: PointF) -> PointF {
+        PointF::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Mul<f64> for PointF {
+    type Output = PointF;
+    fn mul(self, k: f64) -> PointF {
+        PointF::new(self.x * k, self.y * k)
+    }
+}
+
+/// A circle with an integer center and radius. Squared distances and the
+/// discriminants below are taken in `i128`, exact for coordinates and radii
+/// up to `10^9` in absolute value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Circle {
+    pub center: Point,
+    pub r: i64,
+}
+
+/// A circle constructed from other objects, with a floating center and radius.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CircleF {
+    pub center: PointF,
+    pub r: f64,
+}
+
+/// How two circles lie relative to each other, decided exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    /// Each is outside the other.
+    Separate,
+    ExternallyTangent,
+    Intersecting,
+    InternallyTangent,
+    /// One is strictly inside the other.
+    Contained,
+    Identical,
+}
+
+impl Relation {
+    /// The number of common tangent lines, or `None` for identical circles.
+    pub fn common_tangents(self) -> Option<usize> {
+        match self {
+            Relation::Separate => Some(4),
+            Relation::ExternallyTangent => Some(3),
+            Relation::Intersecting => Some(2),
+            Relation::InternallyTangent => Some(1),
+            Relation::Contained => Some(0),
+            Relation::Identical => None,
+        }
+    }
+}
+
+impl Circle {
+    pub fn new(center: Point, r: i64) -> Self {
+        Circle { center, r }
+    }
+
+    /// Compares the squared center distance with `(r1 + r2)^2` and `(r1 - r2)^2`.
+    pub fn relation(self, other: Circle) -> Relation {
+        let v = other.center - self.center;
+        let d2 = v.dot(v);
+        let sum = (self.r + other.r) as i128;
+        let diff = (self.r - other.r) as i128;
+        if d2 == 0 && diff == 0 {
+            Relation::Identical
+        } else if d2 > sum * sum {
+            Relation::Separate
+        } else if d2 == sum * sum {
+            Relation::ExternallyTangent
+        } else if d2 > diff * diff {
+            Relation::Intersecting
+        } else if d2 == diff * diff {
+            Relation::InternallyTangent
+        } else {
+            Relation::Contained
+        }
+    }
+}
+
+/// The common points of two circles: none, the tangency point, or two
+/// points, the one right of the line from the center of `a` to that of `b`
+/// first. Identical circles give nothing.
+///
+/// The points lie on the center line at `(d^2 + ra^2 - rb^2) / 2d` from `a`,
+/// offset across it by `sqrt(k) / 2d`, where `k = 4 d^2 ra^2 - (d^2 + ra^2 -
+/// rb^2)^2` is sixteen times the squared area of the triangle of the two
+/// centers and a common point; its sign gives the count exactly.
+pub fn circle_circle_intersection(a: Circle, b: Circle) -> Vec<PointF> {
+    let v = b.center - a.center;
+    let d2 = v.dot(v);
+    if d2 == 0 {
+        return Vec::new();
+    }
+    let (ra2, rb2) = (a.r as i128 * a.r as i128, b.r as i128 * b.r as i128);
+    let along = d2 + ra2 - rb2;
+    let k = 4 * d2 * ra2 - along * along;
+    if k < 0 {
+        return Vec::new();
+    }
+    let (d2, vf) = (d2 as f64, PointF::from(v));
+    let foot = PointF::from(a.center) + vf * (along as f64 / (2.0 * d2));
+    if k == 0 {
+        return vec![foot];
+    }
+    let h = (k as f64).sqrt() / (2.0 * d2);
+    vec![foot + vf.perp() * -h, foot + vf.perp() * h]
+}
+
+/// The common points of circle `c` and the line through `p != q`, ordered
+/// from `p` towards `q`.
+///
+/// With `w = q - p`, the line is at distance `|w x (c - p)| / |w|` from the
+/// center, so `r^2 |w|^2 - (w x (c - p))^2` decides the count exactly.
+pub fn circle_line_intersection(c: Circle, p: Point, q: Point) -> Vec<PointF> {
+    let w = q - p;
+    let len2 = w.dot(w);
+    assert!(len2 > 0, "the line needs two distinct points");
+    let cross = w.cross(c.center - p);
+    let k = c.r as i128 * c.r as i128 * len2 - cross * cross;
+    if k < 0 {
+        return Vec::new();
+    }
+    let (len2, wf) = (len2 as f64, PointF::from(w));
+    let foot = PointF::from(p) + wf * (w.dot(c.center - p) as f64 / len2);
+    if k == 0 {
+        return vec![foot];
+    }
+    let h = (k as f64).sqrt() / len2;
+    vec![foot + wf * -h, foot + wf * h]
+}
+
+/// The common tangent lines of two circles, each as its touching points on
+/// `a` and on `b`: the external tangents first, then the internal ones. A
+/// tangent through a point where the circles touch appears once, with both
+/// touching points equal; the line is then perpendicular to the center
+/// line. Identical circles give nothing.
+///
+/// A unit normal `u` of a tangent satisfies `u . (cb - ca) = ra - s rb`,
+/// with `s = 1` for an external tangent and `-1` for an internal one, which
+/// has a real solution iff `d^2 >= (ra - s rb)^2`.
+pub fn common_tangents(a: Circle, b: Circle) -> Vec<(PointF, PointF)> {
+    let v = b.center - a.center;
+    let d2 = v.dot(v);
+    let mut lines = Vec::new();
+    if d2 == 0 {
+        return lines;
+    }
+    let vf = PointF::from(v);
+    for s in [1, -1] {
+        let dr = (a.r - s * b.r) as i128;
+        let h2 = d2 - dr * dr;
+        if h2 < 0 {
+            continue;
+        }
+        let signs: &[f64] = if h2 == 0 { &[1.0] } else { &[1.0, -1.0] };
+        for &sign in signs {
+            let u = (vf * dr as f64 + vf.perp() * (sign * (h2 as f64).sqrt())) * (1.0 / d2 as f64);
+            let on_a = PointF::from(a.center) + u * a.r as f64;
+            let on_b = PointF::from(b.center) + u * (s * b.r) as f64;
+            lines.push((on_a, on_b));
+        }
+    }
+    lines
+}
+
+fn distance(p: Point, q: Point) -> f64 {
+    let v = q - p;
+    (v.dot(v) as f64).sqrt()
+}
+
+/// The circle through the vertices of a non-degenerate triangle. Its center
+/// is `a + (|u|^2 w - |w|^2 u)^perp / 2 (u x w)` with `u = b - a`, `w = c - a`,
+/// whose numerator and denominator are exact integers.
+pub fn circumcircle(a: Point, b: Point, c: Point) -> CircleF {
+    let (u, w) = (b - a, c - a);
+    let den = 2 * u.cross(w);
+    assert!(den != 0, "the triangle is degenerate");
+    let (uu, ww) = (u.dot(u), w.dot(w));
+    let x = (w.y as i128 * uu - u.y as i128 * ww) as f64 / den as f64;
+    let y = (u.x as i128 * ww - w.x as i128 * uu) as f64 / den as f64;
+    let center = PointF::from(a) + PointF::new(x, y);
+    CircleF { center, r: x.hypot(y) }
+}
+
+/// The circle tangent to the three sides of a non-degenerate triangle. Its
+/// center weighs each vertex by the length of the opposite side, and its
+/// radius is twice the area over the perimeter.
+pub fn incircle(a: Point, b: Point, c: Point) -> CircleF {
+    let area2 = orient(a, b, c).abs();
+    assert!(area2 != 0, "the triangle is degenerate");
+    let (la, lb, lc) = (distance(b, c), distance(c, a), distance(a, b));
+    let perimeter = la + lb + lc;
+    let center = (PointF::from(a) * la + PointF::from(b) * lb + PointF::from(c) * lc) * (1.0 / perimeter);
+    CircleF { center, r: area2 as f64 / perimeter }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    // Each query is two distinct circles `x1 y1 r1 x2 y2 r2`; print the number
+    // of common tangents, then the common points one per line.
+    let mut output = Vec::new();
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let v: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a value")).collect();
+        let a = Circle::new(Point::new(v[0], v[1]), v[2]);
+        let b = Circle::new(Point::new(v[3], v[4]), v[5]);
+        let tangents = a.relation(b).common_tangents().expect("the circles must be distinct");
+        let points = circle_circle_intersection(a, b);
+        output.push(format!("{} {}", tangents, points.len()));
+        for p in points {
+            output.push(format!("{:.10} {:.10}", p.x, p.y));
+        }
+    }
+    println!("{}", output.join("\n"));
+}
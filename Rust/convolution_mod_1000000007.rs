@@ -0,0 +1,201 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly primes with primitive root 3. Their product is about
+/// 7.9 * 10^25, above every coefficient `n * (10^9 + 7)^2` of the exact
+/// product, so the three residues determine it.
+const MOD0: u64 = 998244353;
+const MOD1: u64 = 167772161;
+const MOD2: u64 = 469762049;
+const TARGET: u64 = 1_000_000_007;
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `M` of length
+/// `a.len()` (a power of two). `M` is a const parameter so the compiler can
+/// turn every `% M` into a multiplication.
+fn ntt<const M: u64>(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (M - 1) / len as u64, M);
+        if invert {
+            w_len = pow_mod(w_len, M - 2, M);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % M;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % M;
+                *x = if u + v >= M { u + v - M } else { u + v };
+                *y = if u >= v { u - v } else { u + M - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, M - 2, M);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % M;
+        }
+    }
+}
+
+/// The product of the polynomials `a` and `b` modulo `M`.
+fn convolve_mod<const M: u64>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.next_power_of_two();
+    let mut fa: Vec<u64> = a.iter().map(|&x| x % M).collect();
+    let mut fb: Vec<u64> = b.iter().map(|&x| x % M).collect();
+    fa.resize(size, 0);
+    fb.resize(size, 0);
+
+    ntt::<M>(&mut fa, false);
+    ntt::<M>(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y % M;
+    }
+    ntt::<M>(&mut fa, true);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `a x + b y = g = gcd(a, b)`
+/// and `g >= 0`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+/// The inverse of `a` modulo `m` in `[0, m)`, or `None` if `gcd(a, m) != 1`.
+pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Merges `x = r1 (mod m1)` and `x = r2 (mod m2)` into `x = r (mod lcm)`,
+/// returning `(r, lcm)`, or `None` if the congruences contradict each other.
+/// The moduli need not be coprime; `lcm` must fit in an `i64`.
+pub fn crt(r1: i64, m1: i64, r2: i64, m2: i64) -> Option<(i64, i64)> {
+    let (r1, r2) = (r1.rem_euclid(m1), r2.rem_euclid(m2));
+    let (g, p, _) = ext_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    // `x = r1 + m1 t` with `m1 t = r2 - r1 (mod m2)`, so `t = p (r2 - r1) / g`.
+    let lcm = m1 / g * m2;
+    let step = m2 / g;
+    let t = (p as i128 * ((r2 - r1) / g) as i128).rem_euclid(step as i128);
+    let r = (r1 as i128 + m1 as i128 * t).rem_euclid(lcm as i128) as i64;
+    Some((r, lcm))
+}
+
+/// Garner's algorithm: the unique `x < prod(moduli)` with
+/// `x = residues[i] (mod moduli[i])`, reduced modulo `target`.
+///
+/// The moduli must be pairwise coprime. `x` is built in mixed radix,
+/// `x = t_0 + t_1 m_0 + t_2 m_0 m_1 + ...`, so the (possibly huge) value
+/// itself is never formed; every quantity stays below the moduli.
+pub fn garner(residues: &[u64], moduli: &[u64], target: u64) -> u64 {
+    let k = residues.len();
+    // `prefix[j]` is `m_0 ... m_{i-1}` and `value[j]` is the partial sum of
+    // `x`, both modulo `moduli[j]` (index `k` standing for `target`).
+    let mut prefix = vec![1u64; k + 1];
+    let mut value = vec![0u64; k + 1];
+    let modulus = |j: usize| if j < k { moduli[j] } else { target };
+
+    for i in 0..k {
+        let m = moduli[i];
+        let diff = (residues[i] % m + m - value[i]) % m;
+        let inv = inv_mod(prefix[i] as i64, m as i64).expect("moduli must be pairwise coprime") as u64;
+        let t = diff as u128 * inv as u128 % m as u128;
+        for j in i + 1..=k {
+            let mj = modulus(j) as u128;
+            value[j] = ((value[j] as u128 + t * prefix[j] as u128) % mj) as u64;
+            prefix[j] = (prefix[j] as u128 * m as u128 % mj) as u64;
+        }
+    }
+    value[k]
+}
+
+/// The product of `a` and `b` modulo an arbitrary `target`: exact products
+/// modulo three NTT primes, recombined by Garner.
+fn convolve_arbitrary(a: &[u64], b: &[u64], target: u64) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let r0 = convolve_mod::<MOD0>(a, b);
+    let r1 = convolve_mod::<MOD1>(a, b);
+    let r2 = convolve_mod::<MOD2>(a, b);
+    (0..r0.len()).map(|i| garner(&[r0[i], r1[i], r2[i]], &[MOD0, MOD1, MOD2], target)).collect()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let mut read_poly = |len: usize| -> Vec<u64> {
+        lines
+            .next()
+            .unwrap()
+            .expect("Failed to read the coefficients")
+            .split_whitespace()
+            .take(len)
+            .map(|s| s.parse().expect("Failed to parse a coefficient"))
+            .collect()
+    };
+    let a = read_poly(n);
+    let b = read_poly(m);
+
+    // --- Output ---
+    let output: Vec<String> = convolve_arbitrary(&a, &b, TARGET).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,133 @@
+use std::io::{self, BufRead};
+
+/// Largest `r` with `r * r <= n`.
+fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
+}
+
+/// Iterator over the distinct values of `n / i` (floor division) for
+/// `1 <= i <= n`, as `(q, lo, hi)` with `n / i = q` exactly for
+/// `lo <= i <= hi`. Ranges come in increasing `i`, so `q` decreases.
+#[derive(Clone, Debug)]
+pub struct FloorQuotients {
+    n: u64,
+    i: u64,
+}
+
+/// The `O(sqrt n)` ranges of `i` on which `n / i` is constant.
+pub fn floor_quotients(n: u64) -> FloorQuotients {
+    FloorQuotients { n, i: 1 }
+}
+
+impl Iterator for FloorQuotients {
+    type Item = (u64, u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.n {
+            return None;
+        }
+        let (q, lo) = (self.n / self.i, self.i);
+        let hi = self.n / q;
+        self.i = hi + 1;
+        Some((q, lo, hi))
+    }
+}
+
+/// The `O(sqrt n)` distinct values of `n / i` (floor division) for `1 <= i <= n`.
+///
+/// Sublinear multiplicative-function sums only ever look at these values, so
+/// they keep one table entry per value. Values are stored in decreasing
+/// order and `index` finds a value's slot in `O(1)`.
+#[derive(Debug)]
+pub struct QuotientSet {
+    n: u64,
+    sqrt: u64,
+    values: Vec<u64>,
+}
+
+impl QuotientSet {
+    pub fn new(n: u64) -> Self {
+        let values = floor_quotients(n).map(|(q, _, _)| q).collect();
+        Self { n, sqrt: isqrt(n), values }
+    }
+
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// All the values, in decreasing order.
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    /// Position of `v` in `values()`; `v` must be of the form `n / i`.
+    ///
+    /// Every `v <= sqrt n` occurs and they fill the tail in increasing order,
+    /// while a larger `v` is `n / i` for a unique `i < sqrt n` at position `i - 1`.
+    pub fn index(&self, v: u64) -> usize {
+        if v <= self.sqrt {
+            self.values.len() - v as usize
+        } else {
+            (self.n / v - 1) as usize
+        }
+    }
+}
+
+/// Counts the primes up to every value of `qs` with the Lucy_Hedgehog
+/// recurrence in `O(n^{3/4})`. Returns `pi(v)` for each `v`, in the order of
+/// `qs.values()`.
+///
+/// `s[v]` starts as the count of `2..=v` and sieving by each prime `p` removes
+/// the numbers whose smallest prime factor is `p`:
+/// `s[v] -= s[v / p] - s[p - 1]` for every `v >= p^2`.
+pub fn prime_pi_table(qs: &QuotientSet) -> Vec<u64> {
+    let values = qs.values();
+    let mut s: Vec<u64> = values.iter().map(|&v| v - 1).collect();
+    for p in 2..=isqrt(qs.n()) {
+        let below_p = s[qs.index(p - 1)];
+        if s[qs.index(p)] == below_p {
+            // `p` was crossed off, so it isn't prime.
+            continue;
+        }
+        let p2 = p * p;
+        // Values are decreasing, so stop at the first one below `p^2`.
+        for i in 0..values.len() {
+            let v = values[i];
+            if v < p2 {
+                break;
+            }
+            s[i] -= s[qs.index(v / p)] - below_p;
+        }
+    }
+    s
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: u64 = first_line.trim().parse().expect("Failed to parse n");
+
+    // --- Output ---
+    let qs = QuotientSet::new(n);
+    let pi = prime_pi_table(&qs);
+    println!("{}", if n == 0 { 0 } else { pi[qs.index(n)] });
+}
@@ -0,0 +1,143 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// An integer modulo the judge's prime `MOD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModInt {
+    val: u64,
+}
+
+impl ModInt {
+    fn new(val: u64) -> Self {
+        ModInt { val: val % MOD }
+    }
+
+    fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat's little theorem; `self` must be non-zero.
+    fn inv(self) -> Self {
+        self.pow(MOD - 2)
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = ModInt;
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + rhs.val)
+    }
+}
+
+impl std::ops::Sub for ModInt {
+    type Output = ModInt;
+    fn sub(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + MOD - rhs.val)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = ModInt;
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val * rhs.val)
+    }
+}
+
+/// Returns the determinant of the square matrix `a` by Gaussian elimination
+/// in `O(n^3)`. Every row swap flips the sign.
+fn determinant(mut a: Vec<Vec<ModInt>>) -> ModInt {
+    let n = a.len();
+    let mut det = ModInt::new(1);
+
+    for col in 0..n {
+        let pivot = match (col..n).find(|&r| a[r][col].val != 0) {
+            Some(r) => r,
+            None => return ModInt::new(0), // Singular.
+        };
+        if pivot != col {
+            a.swap(pivot, col);
+            det = ModInt::new(0) - det;
+        }
+
+        det = det * a[col][col];
+        let inv = a[col][col].inv();
+        let (top, bottom) = a.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        for row in bottom.iter_mut() {
+            let factor = row[col] * inv;
+            if factor.val == 0 {
+                continue;
+            }
+            for (x, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x = *x - factor * p;
+            }
+        }
+    }
+
+    det
+}
+
+/// Builds the Laplacian `D - A` of an undirected multigraph, ignoring self-loops.
+fn undirected_laplacian(size: usize, edges: &[(usize, usize)]) -> Vec<Vec<ModInt>> {
+    let mut laplacian = vec![vec![ModInt::new(0); size]; size];
+    for &(u, v) in edges {
+        if u == v {
+            continue;
+        }
+        laplacian[u][u] = laplacian[u][u] + ModInt::new(1);
+        laplacian[v][v] = laplacian[v][v] + ModInt::new(1);
+        laplacian[u][v] = laplacian[u][v] - ModInt::new(1);
+        laplacian[v][u] = laplacian[v][u] - ModInt::new(1);
+    }
+    laplacian
+}
+
+/// Removes row and column `k`, leaving the minor the Matrix-Tree theorem needs.
+fn remove_row_and_column(mut a: Vec<Vec<ModInt>>, k: usize) -> Vec<Vec<ModInt>> {
+    a.remove(k);
+    for row in a.iter_mut() {
+        row.remove(k);
+    }
+    a
+}
+
+/// Counts the spanning trees of an undirected multigraph modulo `MOD`
+/// (Kirchhoff's Matrix-Tree theorem: any cofactor of the Laplacian).
+fn count_spanning_trees(size: usize, edges: &[(usize, usize)]) -> ModInt {
+    determinant(remove_row_and_column(undirected_laplacian(size, edges), 0))
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+
+    let edges: Vec<(usize, usize)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    // --- Output ---
+    println!("{}", count_spanning_trees(n, &edges).val);
+}
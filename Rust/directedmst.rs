@@ -0,0 +1,416 @@
+use std::io::{self, BufRead};
+
+const NONE: u32 = u32::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct SkewNode {
+    key: i64,
+    value: usize,
+    /// Pending addition for both children's subtrees; `key` is already up to date.
+    lazy: i64,
+    left: u32,
+    right: u32,
+}
+
+/// Meldable min-heaps of `(key, value)` pairs by skew heaps, every node in
+/// one arena. A heap is named by its root, `NONE` when empty; `meld`, `push`
+/// and `pop` consume their heaps and return the root of the result. Adding
+/// a constant to every key of a heap is lazy and `O(1)`.
+///
+/// Skew heaps keep no balance information: a meld walks both right spines
+/// and swaps the children of every node it passes, which is `O(log n)`
+/// amortized. The spines can be long, so the meld is iterative.
+#[derive(Clone, Debug, Default)]
+pub struct SkewHeap {
+    nodes: Vec<SkewNode>,
+}
+
+impl SkewHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// Adds `delta` to every key of the heap at `root`.
+    pub fn add(&mut self, root: u32, delta: i64) {
+        if root != NONE {
+            let node = &mut self.nodes[root as usize];
+            node.key += delta;
+            node.lazy += delta;
+        }
+    }
+
+    fn push_down(&mut self, v: u32) {
+        let SkewNode { lazy, left, right, .. } = self.nodes[v as usize];
+        if lazy != 0 {
+            self.add(left, lazy);
+            self.add(right, lazy);
+            self.nodes[v as usize].lazy = 0;
+        }
+    }
+
+    pub fn meld(&mut self, mut a: u32, mut b: u32) -> u32 {
+        // Merge the right spines top-down, then swap children bottom-up.
+        let mut spine = Vec::new();
+        while a != NONE && b != NONE {
+            if self.nodes[a as usize].key > self.nodes[b as usize].key {
+                std::mem::swap(&mut a, &mut b);
+            }
+            self.push_down(a);
+            spine.push(a);
+            a = self.nodes[a as usize].right;
+        }
+        let mut root = if a == NONE { b } else { a };
+        while let Some(v) = spine.pop() {
+            let node = &mut self.nodes[v as usize];
+            node.right = node.left;
+            node.left = root;
+            root = v;
+        }
+        root
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(SkewNode { key, value, lazy: 0, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        self.push_down(root);
+        let SkewNode { left, right, .. } = self.nodes[root as usize];
+        self.meld(left, right)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LeftistNode {
+    key: i64,
+    value: usize,
+    /// The length of the right spine; never more than the left child's.
+    rank: u32,
+    left: u32,
+    right: u32,
+}
+
+/// Persistent meldable min-heaps of `(key, value)` pairs by leftist heaps,
+/// every node in one arena. A heap is named by its root, `NONE` when empty,
+/// and is never modified: `meld`, `push` and `pop` copy the nodes they
+/// change and return a new root, so every earlier version stays valid and
+/// shares the rest of its nodes.
+///
+/// The right spine of a leftist heap has `O(log n)` nodes, and a meld only
+/// walks the two right spines, so every operation copies `O(log n)` nodes
+/// in the worst case. That bound survives persistence, where the amortized
+/// bounds of skew or pairing heaps do not.
+#[derive(Clone, Debug, Default)]
+pub struct PersistentHeap {
+    nodes: Vec<LeftistNode>,
+}
+
+impl PersistentHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// The two subheaps under `root`, which together hold everything but its top.
+    pub fn children(&self, root: u32) -> [u32; 2] {
+        let node = &self.nodes[root as usize];
+        [node.left, node.right]
+    }
+
+    fn rank(&self, v: u32) -> u32 {
+        self.nodes.get(v as usize).map_or(0, |node| node.rank)
+    }
+
+    pub fn meld(&mut self, a: u32, b: u32) -> u32 {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        let (a, b) = if self.nodes[a as usize].key <= self.nodes[b as usize].key { (a, b) } else { (b, a) };
+        let mut node = self.nodes[a as usize];
+        node.right = self.meld(node.right, b);
+        if self.rank(node.left) < self.rank(node.right) {
+            std::mem::swap(&mut node.left, &mut node.right);
+        }
+        node.rank = self.rank(node.right) + 1;
+        self.nodes.push(node);
+        (self.nodes.len() - 1) as u32
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(LeftistNode { key, value, rank: 1, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        let [left, right] = self.children(root);
+        self.meld(left, right)
+    }
+
+    /// A heap of `items` in `O(n)` by melding them pairwise in rounds.
+    pub fn build(&mut self, items: impl IntoIterator<Item = (i64, usize)>) -> u32 {
+        let mut queue: std::collections::VecDeque<u32> =
+            items.into_iter().map(|(key, value)| self.push(NONE, key, value)).collect();
+        while queue.len() > 1 {
+            let (a, b) = (queue.pop_front().unwrap(), queue.pop_front().unwrap());
+            let melded = self.meld(a, b);
+            queue.push_back(melded);
+        }
+        queue.pop_front().unwrap_or(NONE)
+    }
+}
+
+/// Union-find without path compression, so that unions can be undone in
+/// reverse order.
+struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// The root attached by every union, in order.
+    history: Vec<usize>,
+}
+
+impl RollbackUnionFind {
+    fn new(n: usize) -> Self {
+        Self { parent: (0..n).collect(), size: vec![1; n], history: Vec::new() }
+    }
+
+    fn find(&self, mut u: usize) -> usize {
+        while self.parent[u] != u {
+            u = self.parent[u];
+        }
+        u
+    }
+
+    /// Returns whether `u` and `v` were in different sets.
+    fn join(&mut self, u: usize, v: usize) -> bool {
+        let (mut u, mut v) = (self.find(u), self.find(v));
+        if u == v {
+            return false;
+        }
+        if self.size[u] < self.size[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        self.parent[v] = u;
+        self.size[u] += self.size[v];
+        self.history.push(v);
+        true
+    }
+
+    fn time(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes every union after `time`.
+    fn rollback(&mut self, time: usize) {
+        while self.history.len() > time {
+            let v = self.history.pop().unwrap();
+            let u = self.parent[v];
+            self.size[u] -= self.size[v];
+            self.parent[v] = v;
+        }
+    }
+}
+
+/// A minimum-cost arborescence rooted at `root` by Tarjan's contraction
+/// algorithm with meldable heaps, in `O(m log m)`. Returns the cost and the
+/// parent of every vertex (`root` is its own parent), or `None` if some
+/// vertex is unreachable from `root`.
+///
+/// Every vertex in turn follows its cheapest incoming edge, paying for it
+/// and lowering the other incoming edges by the same amount, until the walk
+/// reaches a finished vertex or closes a cycle; a cycle is contracted into
+/// one vertex whose heap is the meld of its members'. The contractions are
+/// then undone in reverse, each cycle keeping all its edges except the one
+/// into the vertex where the edge entering the cycle lands.
+pub fn directed_mst(n: usize, root: usize, edges: &[(usize, usize, i64)]) -> Option<(i64, Vec<usize>)> {
+    const UNSEEN: usize = usize::MAX;
+    let mut heaps = SkewHeap::new();
+    let mut heap = vec![NONE; n];
+    for (i, &(_, b, c)) in edges.iter().enumerate() {
+        heap[b] = heaps.push(heap[b], c, i);
+    }
+
+    let mut uf = RollbackUnionFind::new(n);
+    let mut seen = vec![UNSEEN; n];
+    seen[root] = root;
+    let mut path = vec![0; n];
+    let mut queue = vec![0; n];
+    let mut in_edge = vec![usize::MAX; n];
+    // `(contracted vertex, union-find time before it, edges of the cycle)`.
+    let mut cycles = Vec::new();
+    let mut cost = 0;
+    for s in 0..n {
+        let (mut u, mut len) = (s, 0);
+        while seen[u] == UNSEEN {
+            let (w, e) = heaps.top(heap[u])?;
+            heaps.add(heap[u], -w);
+            heap[u] = heaps.pop(heap[u]);
+            queue[len] = e;
+            path[len] = u;
+            len += 1;
+            seen[u] = s;
+            cost += w;
+            u = uf.find(edges[e].0);
+            if seen[u] == s {
+                let (mut cycle, end, time) = (NONE, len, uf.time());
+                loop {
+                    len -= 1;
+                    let w = path[len];
+                    cycle = heaps.meld(cycle, heap[w]);
+                    if !uf.join(u, w) {
+                        break;
+                    }
+                }
+                u = uf.find(u);
+                heap[u] = cycle;
+                seen[u] = UNSEEN;
+                cycles.push((u, time, queue[len..end].to_vec()));
+            }
+        }
+        for &e in &queue[..len] {
+            in_edge[uf.find(edges[e].1)] = e;
+        }
+    }
+
+    for (u, time, cycle) in cycles.into_iter().rev() {
+        uf.rollback(time);
+        let entering = in_edge[u];
+        for e in cycle {
+            in_edge[uf.find(edges[e].1)] = e;
+        }
+        in_edge[uf.find(edges[entering].1)] = entering;
+    }
+    let parent = (0..n).map(|v| if v == root { root } else { edges[in_edge[v]].0 }).collect();
+    Some((cost, parent))
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let s: usize = parts.next().unwrap().parse().expect("Failed to parse s");
+    let edges: Vec<(usize, usize, i64)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            let c: i64 = parts.next().unwrap().parse().expect("Failed to parse c");
+            (a, b, c)
+        })
+        .collect();
+
+    // --- Output ---
+    // The input guarantees an arborescence exists.
+    let (cost, parent) = directed_mst(n, s, &edges).expect("Every vertex must be reachable");
+    let parent: Vec<String> = parent.iter().map(|p| p.to_string()).collect();
+    println!("{}\n{}", cost, parent.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// Whether every vertex reaches `root` by following `parent`.
+    fn is_arborescence(parent: &[usize], root: usize) -> bool {
+        (0..parent.len()).all(|v| {
+            let mut u = v;
+            for _ in 0..parent.len() {
+                u = parent[u];
+            }
+            u == root && parent[root] == root
+        })
+    }
+
+    /// The cheapest edge `a -> b` of every pair.
+    fn cheapest(n: usize, edges: &[(usize, usize, i64)]) -> Vec<Vec<Option<i64>>> {
+        let mut best = vec![vec![None; n]; n];
+        for &(a, b, c) in edges {
+            let slot: &mut Option<i64> = &mut best[a][b];
+            *slot = Some(slot.map_or(c, |x: i64| x.min(c)));
+        }
+        best
+    }
+
+    /// The optimum over every choice of a parent for each non-root vertex.
+    fn brute_force(n: usize, root: usize, edges: &[(usize, usize, i64)]) -> Option<i64> {
+        let best = cheapest(n, edges);
+        let mut parent = vec![0; n];
+        let mut answer = None;
+        for code in 0..n.pow(n as u32) {
+            let mut code = code;
+            let mut cost = Some(0);
+            for v in 0..n {
+                parent[v] = code % n;
+                code /= n;
+                if v == root {
+                    parent[v] = root;
+                } else {
+                    cost = cost.zip(best[parent[v]][v]).map(|(x, y)| x + y);
+                }
+            }
+            if let Some(cost) = cost.filter(|_| is_arborescence(&parent, root)) {
+                answer = Some(answer.map_or(cost, |a: i64| a.min(cost)));
+            }
+        }
+        answer
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..1000 {
+            let n = 1 + rng.below(5);
+            let root = rng.below(n);
+            // Self-loops, parallel edges and negative costs included.
+            let edges: Vec<(usize, usize, i64)> =
+                (0..rng.below(12)).map(|_| (rng.below(n), rng.below(n), rng.below(21) as i64 - 10)).collect();
+            let result = directed_mst(n, root, &edges);
+            assert_eq!(result.as_ref().map(|&(cost, _)| cost), brute_force(n, root, &edges), "{:?}", edges);
+            if let Some((cost, parent)) = result {
+                assert!(is_arborescence(&parent, root));
+                let best = cheapest(n, &edges);
+                let total: i64 = (0..n).filter(|&v| v != root).map(|v| best[parent[v]][v].unwrap()).sum();
+                assert_eq!(total, cost);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Finds the smallest `k >= 0` with `x^k = y (mod m)` for any modulus
+/// `m < 2^32`, in `O(sqrt m)` expected time.
+///
+/// While `g = gcd(x, m) > 1`, one factor `x` is peeled off: `x^k = y` needs
+/// `g | y`, and then `(x / g) x^{k-1} = y / g (mod m / g)`. Each step removes
+/// a factor from `m`, so there are `O(log m)` of them, with the accumulated
+/// `x / g` factors kept in `prefix`. What remains has `x` coprime to `m` and
+/// is solved by baby-step giant-step: `prefix x^{n p} = y x^q` for some
+/// giant step `p` and baby step `q`, and then `k = n p - q`.
+pub fn discrete_log(x: u64, y: u64, m: u64) -> Option<u64> {
+    let (mut x, mut y, mut m) = (x % m, y % m, m);
+    let mut prefix = 1 % m;
+    let mut peeled = 0;
+    loop {
+        let g = gcd(x, m);
+        if g == 1 {
+            break;
+        }
+        // The answer might be one of the small exponents peeled so far.
+        if y == prefix {
+            return Some(peeled);
+        }
+        if y % g != 0 {
+            return None;
+        }
+        y /= g;
+        m /= g;
+        peeled += 1;
+        prefix = prefix * (x / g) % m;
+        x %= m;
+    }
+
+    let n = (m as f64).sqrt() as u64 + 1;
+    // Baby steps: for every `y x^q`, remember the largest `q` so that the
+    // resulting `k = n p - q` is as small as possible.
+    let mut baby = HashMap::with_capacity(n as usize + 1);
+    let mut cur = y;
+    for q in 0..=n {
+        baby.insert(cur, q);
+        cur = cur * x % m;
+    }
+
+    let giant = pow_mod(x, n, m);
+    let mut cur = prefix;
+    for p in 1..=n {
+        cur = cur * giant % m;
+        if let Some(&q) = baby.get(&cur) {
+            return Some(n * p - q + peeled);
+        }
+    }
+    None
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let x: u64 = parts.next().unwrap().parse().expect("Failed to parse x");
+        let y: u64 = parts.next().unwrap().parse().expect("Failed to parse y");
+        let m: u64 = parts.next().unwrap().parse().expect("Failed to parse m");
+        output.push(match discrete_log(x, y, m) {
+            Some(k) => k.to_string(),
+            None => "-1".to_string(),
+        });
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,114 @@
+use std::io::{self, BufRead};
+
+/// Lowercase letters only; transitions are a dense table per state.
+const SIGMA: usize = 26;
+const NONE: u32 = u32::MAX;
+
+/// The suffix automaton of a string, built online one character at a time.
+///
+/// Each state is a class of substrings with the same set of end positions
+/// (`endpos`); its strings are the suffixes of the longest one, `len[v]`,
+/// down to just above `len[link[v]]`. There are at most `2n - 1` states and
+/// `3n - 4` transitions.
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton {
+    len: Vec<usize>,
+    link: Vec<u32>,
+    next: Vec<[u32; SIGMA]>,
+    /// The state of the whole string read so far.
+    last: usize,
+    /// Distinct non-empty substrings of the string read so far.
+    distinct: u64,
+}
+
+impl SuffixAutomaton {
+    /// The automaton of the empty string: a single root.
+    pub fn new() -> Self {
+        Self { len: vec![0], link: vec![NONE], next: vec![[NONE; SIGMA]], last: 0, distinct: 0 }
+    }
+
+    /// The number of states, including the root.
+    pub fn state_count(&self) -> usize {
+        self.len.len()
+    }
+
+    fn new_state(&mut self, len: usize, link: u32, next: [u32; SIGMA]) -> usize {
+        self.len.push(len);
+        self.link.push(link);
+        self.next.push(next);
+        self.len.len() - 1
+    }
+
+    /// Appends the lowercase letter `c` in amortised `O(1)` and returns how
+    /// many distinct substrings appeared: the suffixes of the new string
+    /// longer than `len[link[cur]]`, which occurred nowhere before.
+    pub fn extend(&mut self, c: u8) -> u64 {
+        let c = (c - b'a') as usize;
+        let cur = self.new_state(self.len[self.last] + 1, 0, [NONE; SIGMA]);
+
+        // Every suffix of the old string without a `c` edge gains one to `cur`.
+        let mut p = self.last as u32;
+        while p != NONE && self.next[p as usize][c] == NONE {
+            self.next[p as usize][c] = cur as u32;
+            p = self.link[p as usize];
+        }
+
+        if p != NONE {
+            let q = self.next[p as usize][c] as usize;
+            if self.len[p as usize] + 1 == self.len[q] {
+                self.link[cur] = q as u32;
+            } else {
+                // `q` also holds strings longer than `p + c`, whose endpos no
+                // longer matches; split the shorter ones off into a clone.
+                let clone = self.new_state(self.len[p as usize] + 1, self.link[q], self.next[q]);
+                while p != NONE && self.next[p as usize][c] == q as u32 {
+                    self.next[p as usize][c] = clone as u32;
+                    p = self.link[p as usize];
+                }
+                self.link[q] = clone as u32;
+                self.link[cur] = clone as u32;
+            }
+        }
+        self.last = cur;
+        let added = (self.len[cur] - self.len[self.link[cur] as usize]) as u64;
+        self.distinct += added;
+        added
+    }
+
+    /// The number of distinct non-empty substrings: each state other than the
+    /// root holds `len[v] - len[link[v]]` of them. A clone only splits that
+    /// count between itself and the state it came from, so the total is
+    /// kept up to date by `extend`.
+    pub fn distinct_substrings(&self) -> u64 {
+        self.distinct
+    }
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+
+    // --- Output ---
+    // The number of distinct substrings after each appended character, the
+    // automaton growing with the input instead of being built from all of it.
+    let mut sam = SuffixAutomaton::new();
+    let output: Vec<String> = s
+        .trim()
+        .bytes()
+        .map(|c| {
+            sam.extend(c);
+            sam.distinct_substrings().to_string()
+        })
+        .collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,190 @@
+use std::io::{self, BufRead};
+
+/// Splits `[0, n)` into `m` non-empty consecutive segments minimizing the
+/// total cost, where `cost(i, j)` is the cost of the segment `[i, j)`.
+/// Returns the last layer: entry `j` is the best cost for `[0, j)` in `m`
+/// segments, or `i64::MAX` when `j < m`.
+///
+/// Layer `k` is `dp_k[j] = min_{i < j} dp_{k-1}[i] + cost(i, j)`. When
+/// `cost` satisfies the quadrangle inequality
+/// `cost(a, c) + cost(b, d) <= cost(a, d) + cost(b, c)` for `a <= b <= c <= d`,
+/// the best `i` is monotone in `j`: the best split of the middle `j` bounds
+/// the search for both halves, so each layer takes `O(n log n)` evaluations
+/// of `cost` and the whole DP `O(m n log n)`.
+pub fn dnc_optimize<F: Fn(usize, usize) -> i64>(cost_fn: F, n: usize, m: usize) -> Vec<i64> {
+    let mut prev = vec![i64::MAX; n + 1];
+    prev[0] = 0;
+    for k in 1..=m {
+        let mut next = vec![i64::MAX; n + 1];
+        if k <= n {
+            // `j >= k` and `i >= k - 1` keep every segment non-empty.
+            solve_layer(&cost_fn, &prev, &mut next, k..n + 1, k - 1, n - 1);
+        }
+        prev = next;
+    }
+    prev
+}
+
+/// Fills `next[j]` for `j` in `js`, knowing the best split lies in
+/// `[opt_lo, opt_hi]`.
+fn solve_layer<F: Fn(usize, usize) -> i64>(
+    cost_fn: &F,
+    prev: &[i64],
+    next: &mut [i64],
+    js: std::ops::Range<usize>,
+    opt_lo: usize,
+    opt_hi: usize,
+) {
+    if js.is_empty() {
+        return;
+    }
+    let mid = js.start + js.len() / 2;
+    let (mut best, mut best_i) = (i64::MAX, opt_lo);
+    for (i, &p) in prev.iter().enumerate().take(opt_hi.min(mid - 1) + 1).skip(opt_lo) {
+        // Only the first layer has unreachable entries: every `i > 0`.
+        if p == i64::MAX {
+            continue;
+        }
+        let value = p + cost_fn(i, mid);
+        if value < best {
+            (best, best_i) = (value, i);
+        }
+    }
+    next[mid] = best;
+    solve_layer(cost_fn, prev, next, js.start..mid, opt_lo, best_i);
+    solve_layer(cost_fn, prev, next, mid + 1..js.end, best_i, opt_hi);
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n m`, then `n` non-negative values; the cost of a segment is the
+    // square of its sum, which satisfies the quadrangle inequality.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let a: Vec<i64> = if n > 0 {
+        let line = lines.next().unwrap().expect("Failed to read the values");
+        line.split_whitespace().map(|s| s.parse().expect("Failed to parse a value")).collect()
+    } else {
+        Vec::new()
+    };
+
+    // --- Solving and Output ---
+    let mut prefix = vec![0; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = prefix[i] + a[i];
+    }
+    let dp = dnc_optimize(|i, j| (prefix[j] - prefix[i]).pow(2), n, m);
+    println!("{}", if dp[n] == i64::MAX { -1 } else { dp[n] });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// The `O(m n^2)` DP with the same conventions as `dnc_optimize`.
+    fn naive(cost: &[Vec<i64>], n: usize, m: usize) -> Vec<i64> {
+        let mut prev = vec![i64::MAX; n + 1];
+        prev[0] = 0;
+        for _ in 0..m {
+            let mut next = vec![i64::MAX; n + 1];
+            for j in 1..=n {
+                for i in 0..j {
+                    if prev[i] != i64::MAX {
+                        next[j] = next[j].min(prev[i] + cost[i][j]);
+                    }
+                }
+            }
+            prev = next;
+        }
+        prev
+    }
+
+    /// A random cost with the quadrangle inequality on non-empty segments:
+    /// `cost(i, j)` sums non-negative weights `w[a][b]` over `i <= a <= b < j`,
+    /// which makes `cost(a, d) + cost(b, c) - cost(a, c) - cost(b, d)` a sum
+    /// of weights, plus arbitrary terms in `i` and in `j` alone, which cancel.
+    fn random_monge(rng: &mut Rng, n: usize) -> Vec<Vec<i64>> {
+        let w: Vec<Vec<i64>> = (0..n).map(|_| (0..n).map(|_| rng.below(10) as i64).collect()).collect();
+        let row: Vec<i64> = (0..=n).map(|_| rng.below(200) as i64 - 100).collect();
+        let col: Vec<i64> = (0..=n).map(|_| rng.below(200) as i64 - 100).collect();
+        let mut cost = vec![vec![0; n + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in i + 1..=n {
+                // Extending `[i + 1, j)` by `i` adds the weights `w[i][b]`, `b < j`.
+                cost[i][j] = cost[i + 1][j] + w[i][i..j].iter().sum::<i64>();
+            }
+        }
+        for i in 0..=n {
+            for j in i + 1..=n {
+                cost[i][j] += row[i] + col[j];
+            }
+        }
+        cost
+    }
+
+    #[test]
+    fn random_monge_matrices_match_naive() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..500 {
+            let n = rng.below(25);
+            // Up to `n + 2` segments, so some layers cannot be filled.
+            let m = rng.below(n + 3);
+            let cost = random_monge(&mut rng, n);
+            assert_eq!(dnc_optimize(|i, j| cost[i][j], n, m), naive(&cost, n, m), "n = {} m = {}", n, m);
+        }
+    }
+
+    #[test]
+    fn squared_segment_sums_match_naive() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..300 {
+            let n = 1 + rng.below(30);
+            let m = 1 + rng.below(n);
+            let mut prefix = vec![0; n + 1];
+            for i in 0..n {
+                prefix[i + 1] = prefix[i] + rng.below(20) as i64;
+            }
+            let cost: Vec<Vec<i64>> =
+                (0..=n).map(|i| (0..=n).map(|j| (prefix[j] - prefix[i]).pow(2)).collect()).collect();
+            assert_eq!(dnc_optimize(|i, j| cost[i][j], n, m), naive(&cost, n, m));
+        }
+    }
+
+    #[test]
+    fn more_segments_than_elements() {
+        let cost = |i: usize, j: usize| (j - i) as i64;
+        assert_eq!(dnc_optimize(cost, 3, 3), vec![i64::MAX, i64::MAX, i64::MAX, 3]);
+        assert_eq!(dnc_optimize(cost, 3, 4), vec![i64::MAX; 4]);
+        assert_eq!(dnc_optimize(cost, 2, 7), vec![i64::MAX; 3]);
+    }
+
+    #[test]
+    fn empty_input() {
+        let cost = |_: usize, _: usize| -> i64 { unreachable!("no segment to cost") };
+        assert_eq!(dnc_optimize(cost, 0, 0), vec![0]);
+        assert_eq!(dnc_optimize(cost, 0, 1), vec![i64::MAX]);
+        assert_eq!(dnc_optimize(cost, 0, 5), vec![i64::MAX]);
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{self, BufRead};
+
+/// Represents a directed graph using an adjacency list.
+#[derive(Debug)]
+struct Graph {
+    /// `adj[i]` contains the heads of the edges leaving node `i`.
+    adj: Vec<Vec<usize>>,
+    /// `radj[i]` contains the tails of the edges entering node `i`.
+    radj: Vec<Vec<usize>>,
+}
+
+/// Link-eval forest of the Lengauer–Tarjan algorithm, over DFS numbers.
+///
+/// `eval(v)` returns the vertex with minimum semidominator on the forest
+/// path from `v` up to (but excluding) its root, using path compression.
+struct LinkEval {
+    ancestor: Vec<usize>,
+    label: Vec<usize>,
+}
+
+impl LinkEval {
+    fn new(size: usize) -> Self {
+        Self { ancestor: vec![usize::MAX; size], label: (0..size).collect() }
+    }
+
+    fn link(&mut self, parent: usize, child: usize) {
+        self.ancestor[child] = parent;
+    }
+
+    fn eval(&mut self, v: usize, semi: &[usize]) -> usize {
+        if self.ancestor[v] == usize::MAX {
+            return v;
+        }
+
+        // Collect the path whose ancestors can be compressed, then fold
+        // labels top-down. Done iteratively to survive deep DFS trees.
+        let mut path = Vec::new();
+        let mut x = v;
+        while self.ancestor[self.ancestor[x]] != usize::MAX {
+            path.push(x);
+            x = self.ancestor[x];
+        }
+        for &x in path.iter().rev() {
+            let a = self.ancestor[x];
+            if semi[self.label[a]] < semi[self.label[x]] {
+                self.label[x] = self.label[a];
+            }
+            self.ancestor[x] = self.ancestor[a];
+        }
+
+        self.label[v]
+    }
+}
+
+impl Graph {
+    /// Creates a new Graph from a given size and a list of edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        let mut radj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            radj[v].push(u);
+        }
+
+        Self { adj, radj }
+    }
+
+    /// Computes immediate dominators from `source` with the Lengauer–Tarjan
+    /// algorithm in `O(m log n)`.
+    ///
+    /// Returns `idom`, where `idom[source] == Some(source)` and unreachable
+    /// nodes are `None`.
+    pub fn dominators(&self, source: usize) -> Vec<Option<usize>> {
+        let n = self.adj.len();
+
+        // --- DFS numbering ---
+        // `order[i]` is the node with DFS number `i`, `num[v]` the inverse.
+        let mut num = vec![usize::MAX; n];
+        let mut order = Vec::with_capacity(n);
+        // `parent[i]` is the DFS number of the tree parent of DFS number `i`.
+        let mut parent = Vec::with_capacity(n);
+        let mut stack = vec![(source, usize::MAX)];
+        while let Some((u, p)) = stack.pop() {
+            if num[u] != usize::MAX {
+                continue;
+            }
+            num[u] = order.len();
+            order.push(u);
+            parent.push(p);
+            for &v in self.adj[u].iter().rev() {
+                if num[v] == usize::MAX {
+                    stack.push((v, num[u]));
+                }
+            }
+        }
+
+        // --- Semidominators and implicit immediate dominators ---
+        let reached = order.len();
+        let mut semi: Vec<usize> = (0..reached).collect();
+        let mut idom = vec![0; reached];
+        let mut bucket = vec![Vec::new(); reached];
+        let mut forest = LinkEval::new(reached);
+
+        for w in (1..reached).rev() {
+            for &v in &self.radj[order[w]] {
+                if num[v] == usize::MAX {
+                    continue; // Edges from unreachable nodes don't matter.
+                }
+                let u = forest.eval(num[v], &semi);
+                semi[w] = semi[w].min(semi[u]);
+            }
+            bucket[semi[w]].push(w);
+            forest.link(parent[w], w);
+
+            for v in std::mem::take(&mut bucket[parent[w]]) {
+                let u = forest.eval(v, &semi);
+                idom[v] = if semi[u] < semi[v] { u } else { parent[w] };
+            }
+        }
+
+        // --- Explicit immediate dominators, in DFS order ---
+        for w in 1..reached {
+            if idom[w] != semi[w] {
+                idom[w] = idom[idom[w]];
+            }
+        }
+
+        let mut result = vec![None; n];
+        result[source] = Some(source);
+        for w in 1..reached {
+            result[order[w]] = Some(order[idom[w]]);
+        }
+        result
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let s: usize = parts.next().unwrap().parse().expect("Failed to parse s");
+
+    let edges: Vec<(usize, usize)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            (a, b)
+        })
+        .collect();
+
+    // --- Output ---
+    let idom = Graph::new(n, &edges).dominators(s);
+    let output: Vec<String> = idom
+        .iter()
+        .map(|d| match d {
+            Some(d) => d.to_string(),
+            None => "-1".to_string(),
+        })
+        .collect();
+    println!("{}", output.join(" "));
+}
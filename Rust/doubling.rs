@@ -0,0 +1,119 @@
+use std::io::{self, BufRead};
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// No values: a table of `()` only answers where a walk ends.
+impl Monoid for () {
+    fn id() -> Self {}
+    fn op(_: &Self, _: &Self) -> Self {}
+}
+
+/// Binary lifting over a functional graph (every node has exactly one
+/// successor), with a value on every node's outgoing step: where the walk
+/// from `v` is after `k` steps, and the fold of the `k` values it passes, in
+/// walking order. `O(n log K)` preprocessing and `O(log K)` per query for
+/// `k <= K`.
+///
+/// Unlike `FunctionalGraph`, which jumps along cycles by modular arithmetic,
+/// this works for any monoid, including non-commutative ones.
+#[derive(Clone, Debug)]
+pub struct Doubling<T: Monoid + Clone> {
+    /// `up[j][v]` is the node `2^j` steps after `v`.
+    up: Vec<Vec<usize>>,
+    /// `fold[j][v]` is the fold of the `2^j` values from `v` on.
+    fold: Vec<Vec<T>>,
+}
+
+impl Doubling<()> {
+    /// A table over `next` without values, for walks of up to `max_steps`.
+    pub fn new(next: Vec<usize>, max_steps: u64) -> Self {
+        let values = vec![(); next.len()];
+        Self::with_values(next, values, max_steps)
+    }
+}
+
+impl<T: Monoid + Clone> Doubling<T> {
+    /// A table over `next`, where stepping from `v` passes `values[v]`, for
+    /// walks of up to `max_steps`.
+    pub fn with_values(next: Vec<usize>, values: Vec<T>, max_steps: u64) -> Self {
+        let n = next.len();
+        let levels = (u64::BITS - max_steps.leading_zeros()).max(1) as usize;
+        let mut up = vec![next];
+        let mut fold = vec![values];
+        for j in 1..levels {
+            let (prev_up, prev_fold) = (&up[j - 1], &fold[j - 1]);
+            let next_up = (0..n).map(|v| prev_up[prev_up[v]]).collect();
+            let next_fold = (0..n).map(|v| T::op(&prev_fold[v], &prev_fold[prev_up[v]])).collect();
+            up.push(next_up);
+            fold.push(next_fold);
+        }
+        Self { up, fold }
+    }
+
+    /// The node reached from `v` after `k` steps and the fold of the values
+    /// passed on the way.
+    pub fn walk(&self, mut v: usize, k: u64) -> (usize, T) {
+        assert!(k.checked_shr(self.up.len() as u32).unwrap_or(0) == 0, "more steps than the table was built for");
+        let mut acc = T::id();
+        for (j, (up, fold)) in self.up.iter().zip(&self.fold).enumerate() {
+            if k >> j & 1 == 1 {
+                acc = T::op(&acc, &fold[v]);
+                v = up[v];
+            }
+        }
+        (v, acc)
+    }
+
+    /// The node reached from `v` after `k` steps.
+    pub fn jump(&self, v: usize, k: u64) -> usize {
+        self.walk(v, k).0
+    }
+}
+
+/// The smallest weight on a walk; `u64::MAX` for the empty walk.
+#[derive(Clone, Copy, Debug)]
+struct Min(u64);
+
+impl Monoid for Min {
+    fn id() -> Self { Min(u64::MAX) }
+    fn op(a: &Self, b: &Self) -> Self { Min(a.0.min(b.0)) }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `N Q`, the successors `a_i`, the weights `w_i` of the steps `i -> a_i`,
+    // then `Q` queries `v k` with `0 <= k <= 10^18`.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+    let mut read_row = || -> Vec<u64> {
+        let line = lines.next().unwrap().expect("Failed to read a row");
+        line.split_whitespace().take(n).map(|s| s.parse().expect("Failed to parse a value")).collect()
+    };
+    let next: Vec<usize> = read_row().into_iter().map(|a| a as usize).collect();
+    let weights: Vec<Min> = read_row().into_iter().map(Min).collect();
+    let table = Doubling::with_values(next, weights, 1_000_000_000_000_000_000);
+
+    // --- Query Processing and Output ---
+    // The node reached after `k` steps and the lightest step taken, `-1` if none.
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        let k: u64 = parts.next().unwrap().parse().expect("Failed to parse k");
+        let (end, Min(lightest)) = table.walk(v, k);
+        let lightest = if k == 0 { "-1".to_string() } else { lightest.to_string() };
+        output.push(format!("{} {}", end, lightest));
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,510 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufWriter, Read, Write};
+
+/// The whole input read at once and split into whitespace-separated tokens.
+/// With up to 10^6 queries, one `read_line` per query is too slow.
+struct Tokenizer {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Tokenizer {
+    fn from_stdin() -> io::Result<Self> {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
+
+    /// Returns the next token as raw bytes.
+    fn next_token(&mut self) -> &[u8] {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        &self.buf[start..self.pos]
+    }
+
+    fn next_i64(&mut self) -> i64 {
+        let token = self.next_token();
+        let (negative, digits) = match token.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, token),
+        };
+        let value = digits.iter().fold(0i64, |acc, &d| acc * 10 + (d - b'0') as i64);
+        if negative { -value } else { value }
+    }
+}
+
+const NONE: u32 = u32::MAX;
+/// On a vertex node: the vertex has non-tree edges of the forest's level.
+const NONTREE: u8 = 1;
+/// On an arc node: the tree edge has exactly the forest's level.
+const TREE: u8 = 2;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    left: u32,
+    right: u32,
+    parent: u32,
+    /// A vertex node has `from == to`; an arc node goes from `from` to `to`.
+    from: u32,
+    to: u32,
+    /// Vertex nodes in the subtree.
+    size: u32,
+    value: i64,
+    sum: i64,
+    flags: u8,
+    /// The union of `flags` over the subtree.
+    subtree_flags: u8,
+}
+
+/// Euler tour trees: every tree of a forest is kept as its Euler tour, the
+/// sequence of its vertex nodes and of both arcs of every edge, in a splay
+/// tree. Rerooting rotates the sequence, and linking or cutting an edge is a
+/// constant number of splits and joins, all `O(log n)` amortized.
+///
+/// Nodes of many forests may share the arena; they never mix.
+#[derive(Clone, Debug, Default)]
+struct EulerTourForest {
+    nodes: Vec<Node>,
+    free: Vec<u32>,
+}
+
+impl EulerTourForest {
+    /// A new single-node tree: a vertex node if `from == to`, else an arc.
+    fn alloc(&mut self, from: usize, to: usize) -> u32 {
+        let size = (from == to) as u32;
+        let node = Node {
+            left: NONE,
+            right: NONE,
+            parent: NONE,
+            from: from as u32,
+            to: to as u32,
+            size,
+            value: 0,
+            sum: 0,
+            flags: 0,
+            subtree_flags: 0,
+        };
+        match self.free.pop() {
+            Some(x) => {
+                self.nodes[x as usize] = node;
+                x
+            }
+            None => {
+                self.nodes.push(node);
+                (self.nodes.len() - 1) as u32
+            }
+        }
+    }
+
+    fn update(&mut self, x: u32) {
+        let Node { left, right, from, to, value, flags, .. } = self.nodes[x as usize];
+        let (mut size, mut sum, mut subtree_flags) = ((from == to) as u32, value, flags);
+        for child in [left, right] {
+            if child != NONE {
+                let c = &self.nodes[child as usize];
+                size += c.size;
+                sum += c.sum;
+                subtree_flags |= c.subtree_flags;
+            }
+        }
+        let node = &mut self.nodes[x as usize];
+        (node.size, node.sum, node.subtree_flags) = (size, sum, subtree_flags);
+    }
+
+    fn rotate(&mut self, x: u32) {
+        let p = self.nodes[x as usize].parent;
+        let g = self.nodes[p as usize].parent;
+        if self.nodes[p as usize].left == x {
+            let b = self.nodes[x as usize].right;
+            self.nodes[p as usize].left = b;
+            if b != NONE {
+                self.nodes[b as usize].parent = p;
+            }
+            self.nodes[x as usize].right = p;
+        } else {
+            let b = self.nodes[x as usize].left;
+            self.nodes[p as usize].right = b;
+            if b != NONE {
+                self.nodes[b as usize].parent = p;
+            }
+            self.nodes[x as usize].left = p;
+        }
+        self.nodes[p as usize].parent = x;
+        self.nodes[x as usize].parent = g;
+        if g != NONE {
+            if self.nodes[g as usize].left == p {
+                self.nodes[g as usize].left = x;
+            } else {
+                self.nodes[g as usize].right = x;
+            }
+        }
+        self.update(p);
+        self.update(x);
+    }
+
+    /// Makes `x` the root of its splay tree.
+    fn splay(&mut self, x: u32) {
+        while self.nodes[x as usize].parent != NONE {
+            let p = self.nodes[x as usize].parent;
+            let g = self.nodes[p as usize].parent;
+            if g != NONE {
+                let zig_zig = (self.nodes[g as usize].left == p) == (self.nodes[p as usize].left == x);
+                self.rotate(if zig_zig { p } else { x });
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Concatenates the sequences rooted at `a` and `b`; returns the new root.
+    fn join(&mut self, a: u32, b: u32) -> u32 {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        let mut last = a;
+        while self.nodes[last as usize].right != NONE {
+            last = self.nodes[last as usize].right;
+        }
+        self.splay(last);
+        self.nodes[last as usize].right = b;
+        self.nodes[b as usize].parent = last;
+        self.update(last);
+        last
+    }
+
+    /// Splits the sequence of `x` into the part before `x` and the rest.
+    fn split_before(&mut self, x: u32) -> (u32, u32) {
+        self.splay(x);
+        let left = self.nodes[x as usize].left;
+        if left != NONE {
+            self.nodes[left as usize].parent = NONE;
+            self.nodes[x as usize].left = NONE;
+            self.update(x);
+        }
+        (left, x)
+    }
+
+    /// Splits the sequence of `x` into the part up to `x` and the rest.
+    fn split_after(&mut self, x: u32) -> (u32, u32) {
+        self.splay(x);
+        let right = self.nodes[x as usize].right;
+        if right != NONE {
+            self.nodes[right as usize].parent = NONE;
+            self.nodes[x as usize].right = NONE;
+            self.update(x);
+        }
+        (x, right)
+    }
+
+    /// Rotates the tour of `x`'s tree to start at `x`; returns the new root.
+    fn reroot(&mut self, x: u32) -> u32 {
+        let (before, rest) = self.split_before(x);
+        self.join(rest, before)
+    }
+
+    /// Links the trees of vertex nodes `u` and `v` with the fresh arcs `uv`
+    /// and `vu`.
+    fn link(&mut self, u: u32, v: u32, uv: u32, vu: u32) {
+        let tu = self.reroot(u);
+        let tv = self.reroot(v);
+        let joined = self.join(tu, uv);
+        let joined = self.join(joined, tv);
+        self.join(joined, vu);
+    }
+
+    /// Cuts the edge with arcs `uv` and `vu` out of the tree of vertex node
+    /// `u`, and frees both arcs.
+    fn cut(&mut self, u: u32, uv: u32, vu: u32) {
+        // Rooted at `u`, the tour reads `a uv b vu c`, where `b` is the
+        // subtree of the other endpoint.
+        self.reroot(u);
+        let (a, _) = self.split_before(uv);
+        let (_, c) = self.split_after(vu);
+        // Detach both arcs from `uv b vu`, leaving `b` as its own tree.
+        self.split_after(uv);
+        self.split_before(vu);
+        self.join(a, c);
+        self.free.extend([uv, vu]);
+    }
+
+    fn same_tree(&mut self, x: u32, y: u32) -> bool {
+        if x == y {
+            return true;
+        }
+        self.splay(x);
+        let mut root = y;
+        while self.nodes[root as usize].parent != NONE {
+            root = self.nodes[root as usize].parent;
+        }
+        // Splaying `y` pays for the walk.
+        self.splay(y);
+        root == x
+    }
+
+    /// The number of vertices in the tree of `x`.
+    fn tree_size(&mut self, x: u32) -> usize {
+        self.splay(x);
+        self.nodes[x as usize].size as usize
+    }
+
+    fn tree_sum(&mut self, x: u32) -> i64 {
+        self.splay(x);
+        self.nodes[x as usize].sum
+    }
+
+    fn add_value(&mut self, x: u32, delta: i64) {
+        self.splay(x);
+        self.nodes[x as usize].value += delta;
+        self.update(x);
+    }
+
+    fn set_flag(&mut self, x: u32, flag: u8, on: bool) {
+        self.splay(x);
+        let node = &mut self.nodes[x as usize];
+        if on {
+            node.flags |= flag;
+        } else {
+            node.flags &= !flag;
+        }
+        self.update(x);
+    }
+
+    /// Some node carrying `flag` in the tree of `x`.
+    fn find_flag(&mut self, x: u32, flag: u8) -> Option<u32> {
+        self.splay(x);
+        if self.nodes[x as usize].subtree_flags & flag == 0 {
+            return None;
+        }
+        let mut y = x;
+        while self.nodes[y as usize].flags & flag == 0 {
+            let left = self.nodes[y as usize].left;
+            y = if left != NONE && self.nodes[left as usize].subtree_flags & flag != 0 {
+                left
+            } else {
+                self.nodes[y as usize].right
+            };
+        }
+        self.splay(y);
+        Some(y)
+    }
+}
+
+/// Fully dynamic connectivity on `n` vertices, online, by the
+/// Holm–de Lichtenberg–Thorup structure: edge insertions and deletions in
+/// `O(log^2 n)` amortized, connectivity and component sums in `O(log n)`.
+/// The graph must stay simple; self-loops are ignored.
+///
+/// Every edge has a level, which only grows. Forest `i` is a spanning forest
+/// of the edges of level at least `i`, forest 0 one of the whole graph, and
+/// no tree of forest `i` has more than `n / 2^i` vertices. Deleting a tree
+/// edge of level `l` looks for a replacement from level `l` down: the
+/// smaller side's tree edges of that level move up first, then its non-tree
+/// edges of that level are tried, and each one that fails moves up too,
+/// which pays for the search.
+#[derive(Clone, Debug)]
+pub struct DynamicConnectivity {
+    n: usize,
+    ett: EulerTourForest,
+    /// `vertex[i][v]` is the node of `v` in forest `i`; forest 0 has every
+    /// vertex, the others are allocated when first used.
+    vertex: Vec<Vec<u32>>,
+    /// `arcs[i][(u, v)]` is the arc `u -> v` of a tree edge in forest `i`.
+    arcs: Vec<HashMap<(usize, usize), u32>>,
+    /// `nontree[i][v]` holds the other ends of the non-tree edges of level
+    /// `i` at `v`; a vertex without any has no entry.
+    nontree: Vec<HashMap<usize, HashSet<usize>>>,
+    /// The level of every edge and whether it is a tree edge, keyed `(min, max)`.
+    edges: HashMap<(usize, usize), (usize, bool)>,
+}
+
+impl DynamicConnectivity {
+    pub fn new(n: usize) -> Self {
+        let levels = n.max(1).ilog2() as usize + 1;
+        let mut ett = EulerTourForest::default();
+        let mut vertex = vec![Vec::new(); levels];
+        vertex[0] = (0..n).map(|v| ett.alloc(v, v)).collect();
+        Self {
+            n,
+            ett,
+            vertex,
+            arcs: vec![HashMap::new(); levels],
+            nontree: vec![HashMap::new(); levels],
+            edges: HashMap::new(),
+        }
+    }
+
+    fn node(&mut self, level: usize, v: usize) -> u32 {
+        if self.vertex[level].is_empty() {
+            self.vertex[level] = vec![NONE; self.n];
+        }
+        if self.vertex[level][v] == NONE {
+            self.vertex[level][v] = self.ett.alloc(v, v);
+        }
+        self.vertex[level][v]
+    }
+
+    /// Links `u` and `v` in forest `level` by a tree edge of level `edge_level`.
+    fn link(&mut self, level: usize, u: usize, v: usize, edge_level: usize) {
+        let (x, y) = (self.node(level, u), self.node(level, v));
+        let (uv, vu) = (self.ett.alloc(u, v), self.ett.alloc(v, u));
+        if level == edge_level {
+            self.ett.set_flag(uv, TREE, true);
+        }
+        self.arcs[level].insert((u, v), uv);
+        self.arcs[level].insert((v, u), vu);
+        self.ett.link(x, y, uv, vu);
+    }
+
+    fn cut(&mut self, level: usize, u: usize, v: usize) {
+        let uv = self.arcs[level].remove(&(u, v)).unwrap();
+        let vu = self.arcs[level].remove(&(v, u)).unwrap();
+        let x = self.node(level, u);
+        self.ett.cut(x, uv, vu);
+    }
+
+    /// Records `v` as a non-tree neighbor of `u` at `level`.
+    fn add_half(&mut self, level: usize, u: usize, v: usize) {
+        let set = self.nontree[level].entry(u).or_default();
+        set.insert(v);
+        if set.len() == 1 {
+            let x = self.node(level, u);
+            self.ett.set_flag(x, NONTREE, true);
+        }
+    }
+
+    fn remove_half(&mut self, level: usize, u: usize, v: usize) {
+        let set = self.nontree[level].get_mut(&u).unwrap();
+        set.remove(&v);
+        if set.is_empty() {
+            self.nontree[level].remove(&u);
+            let x = self.node(level, u);
+            self.ett.set_flag(x, NONTREE, false);
+        }
+    }
+
+    pub fn connected(&mut self, u: usize, v: usize) -> bool {
+        self.ett.same_tree(self.vertex[0][u], self.vertex[0][v])
+    }
+
+    pub fn component_size(&mut self, v: usize) -> usize {
+        self.ett.tree_size(self.vertex[0][v])
+    }
+
+    /// Adds `delta` to the value of `v`.
+    pub fn add_value(&mut self, v: usize, delta: i64) {
+        self.ett.add_value(self.vertex[0][v], delta);
+    }
+
+    /// The sum of the values in the component of `v`.
+    pub fn component_sum(&mut self, v: usize) -> i64 {
+        self.ett.tree_sum(self.vertex[0][v])
+    }
+
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        if u == v {
+            return;
+        }
+        let tree = !self.connected(u, v);
+        if tree {
+            self.link(0, u, v, 0);
+        } else {
+            self.add_half(0, u, v);
+            self.add_half(0, v, u);
+        }
+        self.edges.insert((u.min(v), u.max(v)), (0, tree));
+    }
+
+    pub fn remove_edge(&mut self, u: usize, v: usize) {
+        if u == v {
+            return;
+        }
+        let (level, tree) = self.edges.remove(&(u.min(v), u.max(v))).expect("No such edge");
+        if !tree {
+            self.remove_half(level, u, v);
+            self.remove_half(level, v, u);
+            return;
+        }
+        for i in 0..=level {
+            self.cut(i, u, v);
+        }
+        for i in (0..=level).rev() {
+            let (x, y) = (self.node(i, u), self.node(i, v));
+            let (small, other) = if self.ett.tree_size(x) <= self.ett.tree_size(y) { (x, y) } else { (y, x) };
+
+            // The smaller side fits in forest `i + 1`.
+            while let Some(arc) = self.ett.find_flag(small, TREE) {
+                self.ett.set_flag(arc, TREE, false);
+                let (p, q) = (self.ett.nodes[arc as usize].from as usize, self.ett.nodes[arc as usize].to as usize);
+                self.edges.insert((p.min(q), p.max(q)), (i + 1, true));
+                self.link(i + 1, p, q, i + 1);
+            }
+
+            while let Some(found) = self.ett.find_flag(small, NONTREE) {
+                let p = self.ett.nodes[found as usize].from as usize;
+                let neighbors: Vec<usize> = self.nontree[i].remove(&p).unwrap().into_iter().collect();
+                self.ett.set_flag(found, NONTREE, false);
+                for (k, &q) in neighbors.iter().enumerate() {
+                    self.remove_half(i, q, p);
+                    let end = self.node(i, q);
+                    if self.ett.same_tree(end, other) {
+                        // A replacement: the untried edges stay at level `i`.
+                        for &r in &neighbors[k + 1..] {
+                            self.add_half(i, p, r);
+                        }
+                        self.edges.insert((p.min(q), p.max(q)), (i, true));
+                        for j in 0..=i {
+                            self.link(j, p, q, i);
+                        }
+                        return;
+                    }
+                    self.edges.insert((p.min(q), p.max(q)), (i + 1, false));
+                    self.add_half(i + 1, p, q);
+                    self.add_half(i + 1, q, p);
+                }
+            }
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut tokens = Tokenizer::from_stdin()?;
+    let n = tokens.next_i64() as usize;
+    let q = tokens.next_i64() as usize;
+    let mut graph = DynamicConnectivity::new(n);
+    for v in 0..n {
+        let a = tokens.next_i64();
+        graph.add_value(v, a);
+    }
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for _ in 0..q {
+        match tokens.next_i64() {
+            0 => {
+                let (u, v) = (tokens.next_i64() as usize, tokens.next_i64() as usize);
+                graph.add_edge(u, v);
+            }
+            1 => {
+                let (u, v) = (tokens.next_i64() as usize, tokens.next_i64() as usize);
+                graph.remove_edge(u, v);
+            }
+            2 => {
+                let (v, x) = (tokens.next_i64() as usize, tokens.next_i64());
+                graph.add_value(v, x);
+            }
+            3 => {
+                let v = tokens.next_i64() as usize;
+                writeln!(out, "{}", graph.component_sum(v))?;
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(())
+}
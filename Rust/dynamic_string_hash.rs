@@ -0,0 +1,278 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// Marks a missing child in the node arena.
+const NONE: u32 = u32::MAX;
+
+/// Represents a single node in the segment tree.
+/// Children are indices into the tree's node arena, or `NONE` for a leaf; a node's
+/// [start, end) interval is not stored but recomputed on the way down from the root.
+#[derive(Debug)]
+struct Node<T: Monoid + Clone> {
+    value: T,
+    left: u32,
+    right: u32,
+}
+
+/// A segment tree implementation for sum queries on a range.
+///
+/// Every node lives in one `Vec` and refers to its children by `u32` index instead
+/// of owning them through a `Box`, so building the tree is a single allocation and
+/// the whole structure can be inspected as a flat array.
+#[derive(Debug)]
+pub struct SegmentTree<T>
+where
+    T: Monoid + Clone
+{
+    /// The root, if any, is `nodes[0]`; nodes are stored in preorder.
+    nodes: Vec<Node<T>>,
+    size: usize,
+}
+
+impl<T> SegmentTree<T>
+where
+    T: Monoid + Clone,
+{
+    /// Creates a new SegmentTree for a sequence of `size` elements.
+    pub fn new(size: usize) -> Self {
+        let mut tree = Self {
+            nodes: Vec::with_capacity(2 * size),
+            size,
+        };
+        // An empty range results in no node.
+        if size > 0 {
+            tree.build(0..size);
+        }
+        tree
+    }
+
+    /// Creates the nodes covering the given range and returns the index of the topmost one.
+    fn build(&mut self, range: Range<usize>) -> u32 {
+        let index = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            value: T::id(),
+            left: NONE,
+            right: NONE,
+        });
+
+        // If the range represents more than one element, it's an internal node, so create children.
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            let left = self.build(range.start..mid);
+            let right = self.build(mid..range.end);
+            let node = &mut self.nodes[index as usize];
+            node.left = left;
+            node.right = right;
+        }
+
+        index
+    }
+
+    /// Recalculates a node's value based on its children's values.
+    /// This is called after a child's value has been updated.
+    fn update_value(&mut self, index: u32) {
+        let node = &self.nodes[index as usize];
+        let value = T::op(&self.nodes[node.left as usize].value, &self.nodes[node.right as usize].value);
+        self.nodes[index as usize].value = value;
+    }
+
+    /// Sets the value at a specific index.
+    pub fn set(&mut self, index: usize, val: T) {
+        // Ensure the index is within the bounds of the tree.
+        if index >= self.size {
+            return;
+        }
+        self.set_recursive(0, 0..self.size, index, val);
+    }
+
+    /// Helper function to recursively find the correct leaf node and update values up the tree.
+    fn set_recursive(&mut self, node: u32, range: Range<usize>, index: usize, val: T) {
+        // Base case: we have reached the leaf node corresponding to the index.
+        if range.len() == 1 {
+            self.nodes[node as usize].value = val;
+            return;
+        }
+
+        // Recursive step: determine whether to go left or right.
+        // Non-leaf nodes always have both children.
+        let mid = range.start + range.len() / 2;
+        let Node { left, right, .. } = self.nodes[node as usize];
+        if index < mid {
+            self.set_recursive(left, range.start..mid, index, val);
+        } else {
+            self.set_recursive(right, mid..range.end, index, val);
+        }
+
+        // After recursion, update the current node's value based on its children.
+        self.update_value(node);
+    }
+
+    /// Returns the sum of values in the given half-open range `[start, end)`.
+    pub fn get(&self, query_range: Range<usize>) -> T {
+        if self.size == 0 {
+            return T::id();
+        }
+        self.get_recursive(0, 0..self.size, &query_range)
+    }
+
+    /// Helper function to recursively calculate the sum over a given query range.
+    fn get_recursive(&self, node: u32, range: Range<usize>, query_range: &Range<usize>) -> T {
+        // Case 1: The node's range has no overlap with the query range.
+        if query_range.end <= range.start || query_range.start >= range.end {
+            return T::id();
+        }
+
+        let node = &self.nodes[node as usize];
+        // Case 2: The node's range is completely contained within the query range.
+        if query_range.start <= range.start && query_range.end >= range.end {
+            return node.value.clone();
+        }
+
+        // Case 3: Partial overlap. Recurse into children and sum their results.
+        let mid = range.start + range.len() / 2;
+        let left_sum = self.get_recursive(node.left, range.start..mid, query_range);
+        let right_sum = self.get_recursive(node.right, mid..range.end, query_range);
+
+        T::op(&left_sum, &right_sum)
+    }
+}
+
+/// The Mersenne prime `2^61 - 1`, as in the static rolling hash.
+const MOD: u64 = (1 << 61) - 1;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    let t = a as u128 * b as u128;
+    // `2^61 = 1`, so the high part folds back onto the low one.
+    let x = (t >> 61) as u64 + (t as u64 & MOD);
+    if x >= MOD { x - MOD } else { x }
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let x = a + b;
+    if x >= MOD { x - MOD } else { x }
+}
+
+/// A random base in `[2^32, MOD)`, seeded from the clock so that an
+/// anti-hash test cannot be prepared against it.
+pub fn random_base() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+    // splitmix64 spreads the low-entropy seed over every bit.
+    let mut z = nanos.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    (1 << 32) + z % (MOD - (1 << 32))
+}
+
+/// The rolling-hash monoid: a string's hash together with `base^len`, which
+/// is all concatenation needs, `h(ab) = h(a) base^{|b|} + h(b)`. Keeping
+/// the power instead of the length lets `op` run without knowing the base.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StrHash {
+    value: u64,
+    power: u64,
+}
+
+impl StrHash {
+    /// The hash of the single character `c` under `base`.
+    pub fn char(c: u8, base: u64) -> Self {
+        // Offset by one so that a zero character still changes the hash.
+        StrHash { value: c as u64 + 1, power: base }
+    }
+}
+
+impl Monoid for StrHash {
+    fn id() -> Self { StrHash { value: 0, power: 1 } }
+    fn op(a: &Self, b: &Self) -> Self {
+        StrHash { value: add_mod(mul_mod(a.value, b.power), b.value), power: mul_mod(a.power, b.power) }
+    }
+}
+
+/// A string under single-character updates with `O(log n)` substring hashes.
+///
+/// A second tree holds the reversed string, so a substring can also be
+/// compared with its own reverse, which no static hash supports once the
+/// string changes.
+pub struct DynamicHash {
+    base: u64,
+    forward: SegmentTree<StrHash>,
+    backward: SegmentTree<StrHash>,
+    len: usize,
+}
+
+impl DynamicHash {
+    pub fn new(s: &[u8], base: u64) -> Self {
+        let len = s.len();
+        let mut dh = DynamicHash { base, forward: SegmentTree::new(len), backward: SegmentTree::new(len), len };
+        for (i, &c) in s.iter().enumerate() {
+            dh.set(i, c);
+        }
+        dh
+    }
+
+    /// Replaces the character at `i` with `c`.
+    pub fn set(&mut self, i: usize, c: u8) {
+        let h = StrHash::char(c, self.base);
+        self.forward.set(i, h);
+        self.backward.set(self.len - 1 - i, h);
+    }
+
+    /// The hash of `s[l..r]`.
+    pub fn substring(&self, l: usize, r: usize) -> StrHash {
+        self.forward.get(l..r)
+    }
+
+    /// The hash of `s[l..r]` read backwards.
+    pub fn reversed_substring(&self, l: usize, r: usize) -> StrHash {
+        self.backward.get(self.len - r..self.len - l)
+    }
+
+    /// Whether `s[l..r]` is a palindrome.
+    pub fn is_palindrome(&self, l: usize, r: usize) -> bool {
+        self.substring(l, r) == self.reversed_substring(l, r)
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n q`, the string, then queries `0 i c` (set `s[i] = c`) or `1 l r`
+    // (is `s[l..r]` a palindrome?).
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let _n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+    let s = lines.next().unwrap().expect("Failed to read the string");
+    let mut dh = DynamicHash::new(s.trim().as_bytes(), random_base());
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::new();
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        match parts.next().unwrap() {
+            "0" => {
+                let i: usize = parts.next().unwrap().parse().expect("Failed to parse i");
+                let c = parts.next().unwrap().as_bytes()[0];
+                dh.set(i, c);
+            }
+            "1" => {
+                let l: usize = parts.next().unwrap().parse().expect("Failed to parse l");
+                let r: usize = parts.next().unwrap().parse().expect("Failed to parse r");
+                output.push(if dh.is_palindrome(l, r) { "Yes" } else { "No" });
+            }
+            _ => unreachable!(),
+        }
+    }
+    println!("{}", output.join("\n"));
+}
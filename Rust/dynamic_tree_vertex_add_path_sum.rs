@@ -0,0 +1,246 @@
+use std::io::{self, BufRead};
+
+/// Sentinel for "no node"; real nodes are stored at indices `1..=n`.
+const NIL: usize = 0;
+
+/// A link-cut tree over a forest with `i64` vertex values.
+///
+/// Every preferred path is kept in a splay tree keyed by depth, with a lazy
+/// reversal flag so any node can be made the root (`evert`). Each splay node
+/// aggregates the sum of its splay subtree (`path`) and the sum of the whole
+/// represented subtree including light children (`total`, with the light
+/// part cached in `virt`), so both path sums and subtree sums are available.
+/// All operations are amortised `O(log n)`.
+#[derive(Debug)]
+pub struct LinkCutTree {
+    ch: Vec<[usize; 2]>,
+    par: Vec<usize>,
+    rev: Vec<bool>,
+    val: Vec<i64>,
+    /// Sum of `val` over the splay subtree, i.e. over a stretch of a preferred path.
+    path: Vec<i64>,
+    /// Sum of `total` over the light (path-parent) children.
+    virt: Vec<i64>,
+    /// Sum over the splay subtree and everything hanging off it.
+    total: Vec<i64>,
+}
+
+impl LinkCutTree {
+    /// Creates a forest of isolated nodes with the given values.
+    pub fn new(values: &[i64]) -> Self {
+        let n = values.len() + 1;
+        let mut val = vec![0; n];
+        val[1..].copy_from_slice(values);
+
+        Self {
+            ch: vec![[NIL; 2]; n],
+            par: vec![NIL; n],
+            rev: vec![false; n],
+            path: val.clone(),
+            total: val.clone(),
+            virt: vec![0; n],
+            val,
+        }
+    }
+
+    /// Returns whether `x` is the root of its splay tree.
+    fn is_splay_root(&self, x: usize) -> bool {
+        let p = self.par[x];
+        p == NIL || (self.ch[p][0] != x && self.ch[p][1] != x)
+    }
+
+    fn update(&mut self, x: usize) {
+        let [l, r] = self.ch[x];
+        self.path[x] = self.path[l] + self.path[r] + self.val[x];
+        self.total[x] = self.total[l] + self.total[r] + self.val[x] + self.virt[x];
+    }
+
+    /// Reverses the preferred path stretch stored under `x`.
+    fn apply_rev(&mut self, x: usize) {
+        if x != NIL {
+            self.ch[x].swap(0, 1);
+            self.rev[x] ^= true;
+        }
+    }
+
+    fn push(&mut self, x: usize) {
+        if self.rev[x] {
+            let [l, r] = self.ch[x];
+            self.apply_rev(l);
+            self.apply_rev(r);
+            self.rev[x] = false;
+        }
+    }
+
+    fn rotate(&mut self, x: usize) {
+        let p = self.par[x];
+        let g = self.par[p];
+        let d = (self.ch[p][1] == x) as usize;
+
+        if !self.is_splay_root(p) {
+            let pd = (self.ch[g][1] == p) as usize;
+            self.ch[g][pd] = x;
+        }
+        self.par[x] = g;
+
+        let b = self.ch[x][1 - d];
+        self.ch[p][d] = b;
+        if b != NIL {
+            self.par[b] = p;
+        }
+        self.ch[x][1 - d] = p;
+        self.par[p] = x;
+
+        self.update(p);
+        self.update(x);
+    }
+
+    fn splay(&mut self, x: usize) {
+        // Push pending reversals from the splay root down to `x` first.
+        let mut path = vec![x];
+        let mut y = x;
+        while !self.is_splay_root(y) {
+            y = self.par[y];
+            path.push(y);
+        }
+        for &y in path.iter().rev() {
+            self.push(y);
+        }
+
+        while !self.is_splay_root(x) {
+            let p = self.par[x];
+            if !self.is_splay_root(p) {
+                let g = self.par[p];
+                let zig_zig = (self.ch[p][1] == x) == (self.ch[g][1] == p);
+                self.rotate(if zig_zig { p } else { x });
+            }
+            self.rotate(x);
+        }
+    }
+
+    /// Makes the root-to-`x` path preferred and splays `x` to the top.
+    fn access(&mut self, x: usize) {
+        let mut last = NIL;
+        let mut y = x;
+        while y != NIL {
+            self.splay(y);
+            // The old preferred child becomes light and `last` becomes heavy.
+            let r = self.ch[y][1];
+            self.virt[y] += self.total[r] - self.total[last];
+            self.ch[y][1] = last;
+            self.update(y);
+            last = y;
+            y = self.par[y];
+        }
+        self.splay(x);
+    }
+
+    /// Makes `x` the root of its tree.
+    fn evert(&mut self, x: usize) {
+        self.access(x);
+        self.apply_rev(x);
+    }
+
+    /// Adds the edge `u`-`v`; they must be in different trees.
+    pub fn link(&mut self, u: usize, v: usize) {
+        let (u, v) = (u + 1, v + 1);
+        self.evert(u);
+        self.access(v);
+        self.par[u] = v;
+        self.virt[v] += self.total[u];
+        self.update(v);
+    }
+
+    /// Removes the edge `u`-`v`, which must exist.
+    pub fn cut(&mut self, u: usize, v: usize) {
+        let (u, v) = (u + 1, v + 1);
+        self.evert(u);
+        self.access(v);
+        // The path is now exactly `u -> v`, so `u` is the left child of `v`.
+        self.ch[v][0] = NIL;
+        self.par[u] = NIL;
+        self.update(v);
+    }
+
+    /// Adds `x` to the value of node `v`.
+    pub fn add(&mut self, v: usize, x: i64) {
+        let v = v + 1;
+        self.access(v);
+        self.val[v] += x;
+        self.update(v);
+    }
+
+    /// Returns the sum of the values on the path between `u` and `v`.
+    pub fn path_sum(&mut self, u: usize, v: usize) -> i64 {
+        let (u, v) = (u + 1, v + 1);
+        self.evert(u);
+        self.access(v);
+        self.path[v]
+    }
+
+    /// Returns the sum of the values in the subtree of `v` when the tree is
+    /// rooted at its neighbour `parent`.
+    pub fn subtree_sum(&mut self, v: usize, parent: usize) -> i64 {
+        let (v, parent) = (v + 1, parent + 1);
+        self.evert(parent);
+        self.access(v);
+        // After access `v` has no preferred child, so light children cover its subtree.
+        self.val[v] + self.virt[v]
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let initial_values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the initial values")
+        .split_whitespace()
+        .take(n)
+        .map(|s| s.parse().expect("Failed to parse initial value"))
+        .collect();
+
+    let mut lct = LinkCutTree::new(&initial_values);
+    for _ in 0..n - 1 {
+        let line = lines.next().unwrap().expect("Failed to read an edge line");
+        let mut parts = line.split_whitespace();
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        lct.link(u, v);
+    }
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+        let mut next = || -> usize { parts.next().unwrap().parse().expect("Failed to parse a query argument") };
+
+        match t {
+            0 => {
+                let (u, v, w, x) = (next(), next(), next(), next());
+                lct.cut(u, v);
+                lct.link(w, x);
+            }
+            1 => {
+                let p = next();
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                lct.add(p, x);
+            }
+            2 => {
+                let (u, v) = (next(), next());
+                println!("{}", lct.path_sum(u, v));
+            }
+            _ => unreachable!()
+        }
+    }
+}
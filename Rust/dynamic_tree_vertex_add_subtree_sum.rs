@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+/// Sentinel for "no node"; real treap nodes start at index 1.
+const NIL: usize = 0;
+
+/// An Euler tour tree over a dynamic forest with `i64` vertex values.
+///
+/// Each tree is stored as its Euler tour in an implicit treap: one node per
+/// vertex (carrying the vertex value) and one node per directed arc of every
+/// edge. Rerooting rotates the tour, `link` concatenates two tours and `cut`
+/// splits one out, all in expected `O(log n)`. Since the subtree of `v` below
+/// its parent `p` is exactly the stretch of the tour between the arcs `p -> v`
+/// and `v -> p`, subtree aggregates are range sums over the treap.
+#[derive(Debug)]
+pub struct EulerTourTree {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    parent: Vec<usize>,
+    priority: Vec<u64>,
+    size: Vec<usize>,
+    val: Vec<i64>,
+    sum: Vec<i64>,
+    /// Treap node of every directed arc `(u, v)` currently in the forest.
+    arcs: HashMap<(usize, usize), usize>,
+    rng: u64,
+}
+
+impl EulerTourTree {
+    /// Creates a forest of isolated vertices with the given values.
+    pub fn new(values: &[i64]) -> Self {
+        let mut ett = Self {
+            left: vec![NIL],
+            right: vec![NIL],
+            parent: vec![NIL],
+            priority: vec![0],
+            size: vec![0],
+            val: vec![0],
+            sum: vec![0],
+            arcs: HashMap::new(),
+            rng: 0x9E3779B97F4A7C15,
+        };
+        for &v in values {
+            ett.new_node(v);
+        }
+        ett
+    }
+
+    fn new_node(&mut self, val: i64) -> usize {
+        // xorshift64 for treap priorities.
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+
+        self.left.push(NIL);
+        self.right.push(NIL);
+        self.parent.push(NIL);
+        self.priority.push(self.rng);
+        self.size.push(1);
+        self.val.push(val);
+        self.sum.push(val);
+        self.val.len() - 1
+    }
+
+    /// Treap node of vertex `v`.
+    fn vertex_node(v: usize) -> usize {
+        v + 1
+    }
+
+    fn update(&mut self, x: usize) {
+        let (l, r) = (self.left[x], self.right[x]);
+        self.size[x] = 1 + self.size[l] + self.size[r];
+        self.sum[x] = self.val[x] + self.sum[l] + self.sum[r];
+        if l != NIL {
+            self.parent[l] = x;
+        }
+        if r != NIL {
+            self.parent[r] = x;
+        }
+    }
+
+    /// Concatenates the sequences rooted at `a` and `b`.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL || b == NIL {
+            let root = a.max(b);
+            self.parent[root] = NIL;
+            return root;
+        }
+        if self.priority[a] > self.priority[b] {
+            let r = self.merge(self.right[a], b);
+            self.right[a] = r;
+            self.update(a);
+            self.parent[a] = NIL;
+            a
+        } else {
+            let l = self.merge(a, self.left[b]);
+            self.left[b] = l;
+            self.update(b);
+            self.parent[b] = NIL;
+            b
+        }
+    }
+
+    /// Splits the sequence rooted at `t` into its first `k` elements and the rest.
+    fn split(&mut self, t: usize, k: usize) -> (usize, usize) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        self.parent[t] = NIL;
+        if self.size[self.left[t]] >= k {
+            let (a, b) = self.split(self.left[t], k);
+            self.left[t] = b;
+            self.update(t);
+            self.parent[a] = NIL;
+            (a, t)
+        } else {
+            let (a, b) = self.split(self.right[t], k - self.size[self.left[t]] - 1);
+            self.right[t] = a;
+            self.update(t);
+            self.parent[b] = NIL;
+            (t, b)
+        }
+    }
+
+    /// Returns the position of node `x` in its sequence and the sequence's root.
+    fn index(&self, mut x: usize) -> (usize, usize) {
+        let mut pos = self.size[self.left[x]];
+        while self.parent[x] != NIL {
+            let p = self.parent[x];
+            if self.right[p] == x {
+                pos += self.size[self.left[p]] + 1;
+            }
+            x = p;
+        }
+        (pos, x)
+    }
+
+    /// Rotates the tour of `v`'s tree so that it starts at `v`; returns the root.
+    fn reroot(&mut self, v: usize) -> usize {
+        let (pos, root) = self.index(Self::vertex_node(v));
+        let (a, b) = self.split(root, pos);
+        self.merge(b, a)
+    }
+
+    /// Adds the edge `u`-`v`; they must be in different trees.
+    pub fn link(&mut self, u: usize, v: usize) {
+        let tu = self.reroot(u);
+        let tv = self.reroot(v);
+        let uv = self.new_node(0);
+        let vu = self.new_node(0);
+        self.arcs.insert((u, v), uv);
+        self.arcs.insert((v, u), vu);
+
+        let t = self.merge(tu, uv);
+        let t = self.merge(t, tv);
+        self.merge(t, vu);
+    }
+
+    /// Removes the edge `u`-`v`, which must exist.
+    pub fn cut(&mut self, u: usize, v: usize) {
+        let uv = self.arcs.remove(&(u, v)).expect("edge to cut must exist");
+        let vu = self.arcs.remove(&(v, u)).unwrap();
+
+        // Rooted at `u`, the tour is `A (u->v) B (v->u) C` with `B` the subtree of `v`.
+        self.reroot(u);
+        let (i, root) = self.index(uv);
+        let (j, _) = self.index(vu);
+        let (ab, c) = self.split(root, j + 1);
+        let (ab, _vu) = self.split(ab, j);
+        let (a, b) = self.split(ab, i + 1);
+        let (a, _uv) = self.split(a, i);
+        self.parent[b] = NIL;
+        self.merge(a, c);
+    }
+
+    /// Adds `x` to the value of vertex `v`.
+    pub fn add(&mut self, v: usize, x: i64) {
+        let mut node = Self::vertex_node(v);
+        self.val[node] += x;
+        while node != NIL {
+            self.sum[node] += x;
+            node = self.parent[node];
+        }
+    }
+
+    /// Returns the sum of the values in the subtree of `v` when the tree is
+    /// rooted at its neighbour `parent`.
+    pub fn subtree_sum(&mut self, v: usize, parent: usize) -> i64 {
+        self.reroot(parent);
+        let (i, root) = self.index(self.arcs[&(parent, v)]);
+        let (j, _) = self.index(self.arcs[&(v, parent)]);
+
+        // Everything strictly between the two arcs.
+        let (ab, c) = self.split(root, j);
+        let (a, b) = self.split(ab, i + 1);
+        let sum = self.sum[b];
+        let ab = self.merge(a, b);
+        self.merge(ab, c);
+        sum
+    }
+
+    /// Returns the sum of the values in the whole tree containing `v`.
+    pub fn tree_sum(&self, v: usize) -> i64 {
+        let (_, root) = self.index(Self::vertex_node(v));
+        self.sum[root]
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let initial_values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the initial values")
+        .split_whitespace()
+        .take(n)
+        .map(|s| s.parse().expect("Failed to parse initial value"))
+        .collect();
+
+    let mut ett = EulerTourTree::new(&initial_values);
+    for _ in 0..n - 1 {
+        let line = lines.next().unwrap().expect("Failed to read an edge line");
+        let mut parts = line.split_whitespace();
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        ett.link(u, v);
+    }
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+        let mut next = || -> usize { parts.next().unwrap().parse().expect("Failed to parse a query argument") };
+
+        match t {
+            0 => {
+                let (u, v, w, x) = (next(), next(), next(), next());
+                ett.cut(u, v);
+                ett.link(w, x);
+            }
+            1 => {
+                let p = next();
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                ett.add(p, x);
+            }
+            2 => {
+                let (v, p) = (next(), next());
+                println!("{}", ett.subtree_sum(v, p));
+            }
+            _ => unreachable!()
+        }
+    }
+}
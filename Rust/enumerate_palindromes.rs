@@ -0,0 +1,65 @@
+use std::io::{self, BufRead};
+
+/// Palindromic radii at every center by Manacher's algorithm in `O(n)`.
+///
+/// Returns `(odd, even)`: `s[i - odd[i] + 1..i + odd[i]]` is the longest
+/// odd palindrome centered at `i`, and `s[i - even[i]..i + even[i]]` the
+/// longest even one centered between `i - 1` and `i` (`even[0] = 0`).
+///
+/// Both passes keep the rightmost palindrome `[l, r)` found so far; a center
+/// inside it starts from the radius of its mirror, clipped to `r`, and only
+/// characters past `r` are ever compared, so `r` moves right monotonically.
+pub fn manacher<T: PartialEq>(s: &[T]) -> (Vec<usize>, Vec<usize>) {
+    let n = s.len();
+
+    let mut odd = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { odd[l + r - 1 - i].min(r - i) } else { 1 };
+        while i + k < n && k <= i && s[i - k] == s[i + k] {
+            k += 1;
+        }
+        odd[i] = k;
+        if i + k > r {
+            (l, r) = (i + 1 - k, i + k);
+        }
+    }
+
+    let mut even = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { even[l + r - i].min(r - i) } else { 0 };
+        while i + k < n && k < i && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+        even[i] = k;
+        if i + k > r {
+            (l, r) = (i - k, i + k);
+        }
+    }
+
+    (odd, even)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+    let s = s.trim().as_bytes();
+
+    // --- Output ---
+    // The longest palindrome at each of the `2n - 1` centers, characters and
+    // gaps alternating.
+    let (odd, even) = manacher(s);
+    let mut output = Vec::with_capacity(2 * s.len());
+    for i in 0..s.len() {
+        if i > 0 {
+            output.push((2 * even[i]).to_string());
+        }
+        output.push((2 * odd[i] - 1).to_string());
+    }
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,151 @@
+use std::fmt::Write;
+use std::io::{self, BufRead};
+
+/// A linear sieve: the smallest prime factor of every number below `n`
+/// together with the list of primes, in `O(n)`.
+#[derive(Debug)]
+pub struct Sieve {
+    /// `spf[i]` is the smallest prime factor of `i` (zero for 0 and 1).
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl Sieve {
+    /// Sieves every number in `0..n`.
+    pub fn new(n: usize) -> Self {
+        let mut spf = vec![0u32; n];
+        let mut primes = Vec::new();
+        for i in 2..n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            // Every composite is crossed off exactly once, by its smallest prime factor.
+            for &p in &primes {
+                if p > spf[i] || i * p as usize >= n {
+                    break;
+                }
+                spf[i * p as usize] = p;
+            }
+        }
+        Self { spf, primes }
+    }
+
+    pub fn is_prime(&self, x: usize) -> bool {
+        x >= 2 && self.spf[x] as usize == x
+    }
+
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    /// Factorises `x` (below the sieve limit) into `(prime, exponent)` pairs
+    /// in increasing order of primes.
+    pub fn factorize(&self, mut x: usize) -> Vec<(u32, u32)> {
+        let mut factors: Vec<(u32, u32)> = Vec::new();
+        while x > 1 {
+            let p = self.spf[x];
+            match factors.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+            x /= p as usize;
+        }
+        factors
+    }
+}
+
+/// Largest `r` with `r * r <= n`.
+fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
+}
+
+/// Number of odd numbers covered by one segment of the segmented sieve;
+/// a segment's flags fit comfortably in L2 cache.
+const SEGMENT: usize = 1 << 18;
+
+/// Calls `f` on every prime in `[lo, hi)` in increasing order.
+///
+/// Only odd numbers are sieved, one cache-sized segment at a time, using
+/// the base primes up to `sqrt(hi)`, so memory stays `O(sqrt(hi))` however
+/// wide the range is.
+pub fn segmented_sieve<F: FnMut(u64)>(lo: u64, hi: u64, mut f: F) {
+    if lo <= 2 && 2 < hi {
+        f(2);
+    }
+    let base = Sieve::new(isqrt(hi) as usize + 1);
+    let odd_primes: Vec<u64> = base.primes().iter().skip(1).map(|&p| p as u64).collect();
+    // `next[i]` is the next odd multiple of `odd_primes[i]` still to cross off.
+    let first_odd = lo.max(3) | 1;
+    let mut next: Vec<u64> = odd_primes
+        .iter()
+        .map(|&p| {
+            let start = (p * p).max(first_odd.div_ceil(p) * p);
+            if start % 2 == 0 { start + p } else { start }
+        })
+        .collect();
+
+    let mut composite = vec![false; SEGMENT];
+    let mut seg_lo = first_odd;
+    while seg_lo < hi {
+        // The segment holds the odd numbers `seg_lo, seg_lo + 2, ...` below `seg_hi`.
+        let seg_hi = hi.min(seg_lo + 2 * SEGMENT as u64);
+        let len = (seg_hi - seg_lo).div_ceil(2) as usize;
+        composite[..len].iter_mut().for_each(|c| *c = false);
+
+        for (&p, m) in odd_primes.iter().zip(next.iter_mut()) {
+            if p * p >= seg_hi {
+                break;
+            }
+            while *m < seg_hi {
+                composite[((*m - seg_lo) / 2) as usize] = true;
+                *m += 2 * p;
+            }
+        }
+        for (i, &c) in composite[..len].iter().enumerate() {
+            if !c {
+                f(seg_lo + 2 * i as u64);
+            }
+        }
+        seg_lo += 2 * SEGMENT as u64;
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: u64 = parts.next().unwrap().parse().expect("Failed to parse n");
+    let a: u64 = parts.next().unwrap().parse().expect("Failed to parse a");
+    let b: u64 = parts.next().unwrap().parse().expect("Failed to parse b");
+
+    // --- Output ---
+    // Print `pi(n)` and every prime `p_i` (0-indexed) with `i = a k + b`.
+    // There can be tens of millions of them, so they go straight into one buffer.
+    let mut count = 0;
+    let mut chosen = 0;
+    let mut output = String::new();
+    segmented_sieve(2, n + 1, |p| {
+        if count >= b && (count - b).is_multiple_of(a) {
+            if chosen > 0 {
+                output.push(' ');
+            }
+            write!(output, "{}", p).unwrap();
+            chosen += 1;
+        }
+        count += 1;
+    });
+    println!("{} {}", count, chosen);
+    println!("{}", output);
+}
@@ -0,0 +1,45 @@
+use std::io::{self, BufRead};
+
+/// Iterator over the distinct values of `n / i` (floor division) for
+/// `1 <= i <= n`, as `(q, lo, hi)` with `n / i = q` exactly for
+/// `lo <= i <= hi`. Ranges come in increasing `i`, so `q` decreases.
+#[derive(Clone, Debug)]
+pub struct FloorQuotients {
+    n: u64,
+    i: u64,
+}
+
+/// The `O(sqrt n)` ranges of `i` on which `n / i` is constant.
+pub fn floor_quotients(n: u64) -> FloorQuotients {
+    FloorQuotients { n, i: 1 }
+}
+
+impl Iterator for FloorQuotients {
+    type Item = (u64, u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.n {
+            return None;
+        }
+        let (q, lo) = (self.n / self.i, self.i);
+        let hi = self.n / q;
+        self.i = hi + 1;
+        Some((q, lo, hi))
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: u64 = first_line.trim().parse().expect("Failed to parse n");
+
+    // --- Output ---
+    let mut quotients: Vec<String> = floor_quotients(n).map(|(q, _, _)| q.to_string()).collect();
+    quotients.reverse();
+    println!("{}", quotients.len());
+    println!("{}", quotients.join(" "));
+}
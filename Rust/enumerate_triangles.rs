@@ -0,0 +1,118 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// An integer modulo the judge's prime `MOD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModInt {
+    val: u64,
+}
+
+impl ModInt {
+    fn new(val: u64) -> Self {
+        ModInt { val: val % MOD }
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = ModInt;
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + rhs.val)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = ModInt;
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val * rhs.val)
+    }
+}
+
+/// An undirected graph stored with every edge oriented from the endpoint of
+/// lower degree to the one of higher degree (ties broken by index).
+///
+/// With this orientation every node has out-degree `O(sqrt(m))`, which is
+/// what bounds triangle enumeration by `O(m sqrt(m))`.
+#[derive(Debug)]
+struct OrientedGraph {
+    /// `out[i]` contains the heads of the edges leaving node `i`.
+    out: Vec<Vec<usize>>,
+}
+
+impl OrientedGraph {
+    /// Creates a new OrientedGraph from a given size and a list of undirected edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        let mut degree = vec![0; size];
+        for &(u, v) in edges {
+            degree[u] += 1;
+            degree[v] += 1;
+        }
+
+        let mut out = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            if (degree[u], u) < (degree[v], v) {
+                out[u].push(v);
+            } else {
+                out[v].push(u);
+            }
+        }
+
+        Self { out }
+    }
+
+    /// Calls `f(a, b, c)` exactly once for every triangle of the graph.
+    pub fn for_each_triangle<F: FnMut(usize, usize, usize)>(&self, mut f: F) {
+        // `marked[w] == u + 1` means `w` is an out-neighbour of the current `u`.
+        let mut marked = vec![0; self.out.len()];
+        for u in 0..self.out.len() {
+            for &v in &self.out[u] {
+                marked[v] = u + 1;
+            }
+            for &v in &self.out[u] {
+                for &w in &self.out[v] {
+                    if marked[w] == u + 1 {
+                        f(u, v, w);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+
+    let weights: Vec<ModInt> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the weights")
+        .split_whitespace()
+        .take(n)
+        .map(|s| ModInt::new(s.parse().expect("Failed to parse a weight")))
+        .collect();
+
+    let edges: Vec<(usize, usize)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    // --- Output ---
+    let mut sum = ModInt::new(0);
+    OrientedGraph::new(n, &edges).for_each_triangle(|a, b, c| {
+        sum = sum + weights[a] * weights[b] * weights[c];
+    });
+    println!("{}", sum.val);
+}
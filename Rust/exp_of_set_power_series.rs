@@ -0,0 +1,162 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// `inverses[i]` is the inverse of `i` modulo `MOD` for `1 <= i < n`.
+fn inverses(n: usize) -> Vec<u64> {
+    let mut inv = vec![0, 1];
+    for i in 2..n as u64 {
+        inv.push((MOD - MOD / i) * inv[(MOD % i) as usize] % MOD);
+    }
+    inv.truncate(n);
+    inv
+}
+
+/// The ranked representation of a set power series over `n` elements:
+/// `data[s * (n + 1) + r]` is the coefficient of `s` when `|s| = r` and
+/// zero otherwise, so each set carries a polynomial in a rank variable.
+///
+/// After the ranked zeta transform, set union of disjoint sets becomes
+/// pointwise polynomial multiplication truncated at degree `n`, which is
+/// what makes subset convolution, exp and log pointwise operations.
+/// Entries are `u32` to keep `n = 20` within `2^20 * 21` words.
+pub struct Ranked {
+    n: usize,
+    data: Vec<u32>,
+}
+
+impl Ranked {
+    /// Lifts `a` (of length `2^n`) into ranked form and applies the zeta
+    /// transform, in `O(2^n n^2)`.
+    pub fn zeta(a: &[u64], n: usize) -> Self {
+        let w = n + 1;
+        let mut data = vec![0u32; a.len() * w];
+        for (s, &x) in a.iter().enumerate() {
+            data[s * w + s.count_ones() as usize] = (x % MOD) as u32;
+        }
+        for bit in 0..n {
+            for s in 0..a.len() {
+                if s >> bit & 1 == 1 {
+                    let (lo, hi) = data.split_at_mut(s * w);
+                    let from = &lo[(s ^ 1 << bit) * w..][..w];
+                    for (x, &y) in hi[..w].iter_mut().zip(from) {
+                        let sum = *x as u64 + y as u64;
+                        *x = if sum >= MOD { sum - MOD } else { sum } as u32;
+                    }
+                }
+            }
+        }
+        Self { n, data }
+    }
+
+    /// Applies the Möbius transform and keeps rank `|s|` of every set,
+    /// inverting `zeta`.
+    pub fn mobius(mut self) -> Vec<u64> {
+        let (n, w) = (self.n, self.n + 1);
+        let size = 1 << n;
+        for bit in 0..n {
+            for s in 0..size {
+                if s >> bit & 1 == 1 {
+                    let (lo, hi) = self.data.split_at_mut(s * w);
+                    let from = &lo[(s ^ 1 << bit) * w..][..w];
+                    for (x, &y) in hi[..w].iter_mut().zip(from) {
+                        *x = if *x >= y { *x - y } else { (*x as u64 + MOD - y as u64) as u32 };
+                    }
+                }
+            }
+        }
+        (0..size).map(|s: usize| self.data[s * w + s.count_ones() as usize] as u64).collect()
+    }
+
+    /// Replaces the rank polynomial of every set by `f` of it.
+    pub fn map_pointwise(&mut self, mut f: impl FnMut(&[u64]) -> Vec<u64>) {
+        let w = self.n + 1;
+        for chunk in self.data.chunks_exact_mut(w) {
+            let poly: Vec<u64> = chunk.iter().map(|&x| x as u64).collect();
+            for (x, y) in chunk.iter_mut().zip(f(&poly)) {
+                *x = y as u32;
+            }
+        }
+    }
+}
+
+/// `c[s] = sum over disjoint t, u with t | u = s of a[t] b[u]`, in `O(2^n n^2)`.
+pub fn subset_convolution(a: &[u64], b: &[u64], n: usize) -> Vec<u64> {
+    let rb = Ranked::zeta(b, n);
+    let mut ra = Ranked::zeta(a, n);
+    let w = n + 1;
+    let mut idx = 0;
+    ra.map_pointwise(|p| {
+        let q = &rb.data[idx * w..(idx + 1) * w];
+        idx += 1;
+        let mut c = vec![0; w];
+        for (i, &x) in p.iter().enumerate() {
+            for (j, &y) in q[..w - i].iter().enumerate() {
+                c[i + j] = (c[i + j] + x * y as u64) % MOD;
+            }
+        }
+        c
+    });
+    ra.mobius()
+}
+
+/// `exp(f)` truncated at degree `len - 1` for `f_0 = 0`, from `g' = f' g`:
+/// `k g_k = sum_{i=1}^k i f_i g_{k-i}`.
+fn poly_exp(f: &[u64], inv: &[u64]) -> Vec<u64> {
+    let mut g = vec![0; f.len()];
+    g[0] = 1;
+    for k in 1..f.len() {
+        let s = (1..=k).fold(0, |acc, i| (acc + i as u64 * f[i] % MOD * g[k - i]) % MOD);
+        g[k] = s * inv[k] % MOD;
+    }
+    g
+}
+
+/// `log(g)` truncated at degree `len - 1` for `g_0 = 1`, inverting the
+/// recurrence of `poly_exp` for `f_k`.
+fn poly_log(g: &[u64], inv: &[u64]) -> Vec<u64> {
+    let mut f = vec![0; g.len()];
+    for k in 1..g.len() {
+        let s = (1..k).fold(0, |acc, i| (acc + i as u64 * f[i] % MOD * g[k - i]) % MOD);
+        f[k] = (g[k] + MOD - s * inv[k] % MOD) % MOD;
+    }
+    f
+}
+
+/// `exp(b) = sum_k b^k / k!` under subset convolution, for `b[0] = 0`, in
+/// `O(2^n n^2)`. The coefficient of `s` sums over the partitions of `s`
+/// into blocks `t` the product of `b[t]`, which is what connected-component
+/// DPs count.
+pub fn set_exp(b: &[u64], n: usize) -> Vec<u64> {
+    assert_eq!(b[0] % MOD, 0, "exp needs a zero constant term");
+    let inv = inverses(n + 1);
+    let mut r = Ranked::zeta(b, n);
+    r.map_pointwise(|p| poly_exp(p, &inv));
+    r.mobius()
+}
+
+/// The inverse of `set_exp`, for `a[0] = 1`: recovers connected counts
+/// from counts over all structures.
+pub fn set_log(a: &[u64], n: usize) -> Vec<u64> {
+    assert_eq!(a[0] % MOD, 1, "log needs a constant term of one");
+    let inv = inverses(n + 1);
+    let mut r = Ranked::zeta(a, n);
+    r.map_pointwise(|p| poly_log(p, &inv));
+    r.mobius()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let second_line = lines.next().unwrap().expect("Failed to read the second line");
+    let b: Vec<u64> = second_line.split_whitespace().map(|s| s.parse().expect("Failed to parse b")).collect();
+
+    // --- Output ---
+    let output: Vec<String> = set_exp(&b, n).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,203 @@
+use std::io::{self, BufRead};
+
+/// Montgomery arithmetic modulo an odd `n < 2^64`.
+///
+/// Values are kept in Montgomery form `x R mod n` with `R = 2^64`, so a
+/// modular product needs two 64x64 multiplications and a shift instead of
+/// a `u128` division.
+#[derive(Clone, Copy, Debug)]
+pub struct Montgomery {
+    n: u64,
+    /// `n^{-1} mod 2^64`.
+    n_inv: u64,
+    /// `R^2 mod n`, used to convert into Montgomery form.
+    r2: u64,
+}
+
+impl Montgomery {
+    pub fn new(n: u64) -> Self {
+        assert!(n % 2 == 1, "Montgomery form needs an odd modulus");
+        // Newton's iteration doubles the number of correct low bits each step.
+        let mut n_inv = n;
+        for _ in 0..5 {
+            n_inv = n_inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(n_inv)));
+        }
+        let r = ((1u128 << 64) % n as u128) as u64;
+        let r2 = (r as u128 * r as u128 % n as u128) as u64;
+        Self { n, n_inv, r2 }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.n
+    }
+
+    /// `t R^{-1} mod n` for `t < n R`: subtracting `m n` with
+    /// `m = t n^{-1} mod R` clears the low word exactly.
+    fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.n_inv);
+        let mn = m as u128 * self.n as u128;
+        let (t_hi, mn_hi) = ((t >> 64) as u64, (mn >> 64) as u64);
+        if t_hi >= mn_hi { t_hi - mn_hi } else { t_hi.wrapping_sub(mn_hi).wrapping_add(self.n) }
+    }
+
+    pub fn to_mont(&self, x: u64) -> u64 {
+        self.reduce((x % self.n) as u128 * self.r2 as u128)
+    }
+
+    pub fn from_mont(&self, x: u64) -> u64 {
+        self.reduce(x as u128)
+    }
+
+    /// Product of two values in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let (s, overflow) = a.overflowing_add(b);
+        if overflow || s >= self.n { s.wrapping_sub(self.n) } else { s }
+    }
+
+    /// `base^exp` for `base` in Montgomery form; the result is too.
+    pub fn pow(&self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = self.to_mont(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Deterministic Miller-Rabin for all `n < 2^64`, using a base set known
+/// to have no strong pseudoprimes in that range.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let mont = Montgomery::new(n);
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let one = mont.to_mont(1);
+    let minus_one = mont.to_mont(n - 1);
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022].iter().all(|&a| {
+        if a % n == 0 {
+            return true;
+        }
+        let mut x = mont.pow(mont.to_mont(a), d);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = mont.mul(x, x);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// A non-trivial factor of the odd composite `n`, by Pollard's rho with
+/// Brent's cycle finding. Differences are multiplied together in batches
+/// so only one `gcd` is needed per batch.
+fn pollard_rho(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+    let mont = Montgomery::new(n);
+    for c in 1.. {
+        let c = mont.to_mont(c);
+        let f = |x: u64| mont.add(mont.mul(x, x), c);
+        let (mut x, mut y, mut ys) = (0, mont.to_mont(2), 0);
+        let mut q = mont.to_mont(1);
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mont.mul(q, x.abs_diff(y));
+                }
+                g = gcd(mont.from_mont(q), n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        // The batch overshot: step through it again one gcd at a time.
+        if g == n {
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// The prime factors of `n` with multiplicity, in increasing order.
+pub fn factorize(n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut n = n;
+    while n > 1 && n.is_multiple_of(2) {
+        factors.push(2);
+        n /= 2;
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            factors.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+    factors.sort_unstable();
+    factors
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let q: usize = first_line.trim().parse().expect("Failed to parse q");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let a: u64 = line.trim().parse().expect("Failed to parse a");
+        let factors = factorize(a);
+        let words: Vec<String> = std::iter::once(factors.len().to_string()).chain(factors.iter().map(|p| p.to_string())).collect();
+        output.push(words.join(" "));
+    }
+    println!("{}", output.join("\n"));
+}
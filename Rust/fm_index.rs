@@ -0,0 +1,155 @@
+use std::io::{self, BufRead};
+
+/// Prefix doubling: after the round for `k`, suffixes are ranked by their
+/// first `2k` characters. Each round sorts the pairs `(rank[i], rank[i + k])`
+/// with two stable counting sorts, the second key first.
+fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by_key(|&i| s[i]);
+    let mut rank = vec![0; n];
+    for w in 1..n {
+        rank[sa[w]] = rank[sa[w - 1]] + usize::from(s[sa[w]] != s[sa[w - 1]]);
+    }
+
+    let mut k = 1;
+    let mut tmp = vec![0; n];
+    let mut count = vec![0; n + 1];
+    while k < n && rank[sa[n - 1]] < n - 1 {
+        // Suffixes shorter than `k` have an empty second key and come first.
+        let mut by_second: Vec<usize> = (n - k..n).collect();
+        by_second.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+
+        count.iter_mut().for_each(|c| *c = 0);
+        for &i in &by_second {
+            count[rank[i] + 1] += 1;
+        }
+        for r in 1..=n {
+            count[r] += count[r - 1];
+        }
+        for &i in &by_second {
+            sa[count[rank[i]]] = i;
+            count[rank[i]] += 1;
+        }
+
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        tmp[sa[0]] = 0;
+        for w in 1..n {
+            tmp[sa[w]] = tmp[sa[w - 1]] + usize::from(key(sa[w]) != key(sa[w - 1]));
+        }
+        std::mem::swap(&mut rank, &mut tmp);
+        k *= 2;
+    }
+    sa
+}
+
+/// The Burrows-Wheeler transform of `s + '\0'`, read off the suffix array:
+/// the character before each suffix in sorted order. `s` must not contain
+/// the sentinel byte 0.
+pub fn bwt(s: &[u8]) -> Vec<u8> {
+    let mut t = s.to_vec();
+    t.push(0);
+    let sa = suffix_array(&t);
+    sa.iter().map(|&i| t[(i + t.len() - 1) % t.len()]).collect()
+}
+
+/// Rank checkpoints are stored every this many positions of the BWT.
+const BLOCK: usize = 64;
+
+/// An FM-index of a byte string for counting pattern occurrences in
+/// `O(|pattern| BLOCK)` without touching the text.
+///
+/// Backward search keeps the range of sorted suffixes starting with a
+/// growing suffix of the pattern; prepending `c` maps `[lo, hi)` to
+/// `[C[c] + occ(c, lo), C[c] + occ(c, hi))`, where `C[c]` counts smaller
+/// characters and `occ(c, i)` counts `c` in `bwt[..i]`.
+#[derive(Clone, Debug)]
+pub struct FmIndex {
+    bwt: Vec<u8>,
+    /// Compact index of every byte in the text, or `None`.
+    code: [Option<usize>; 256],
+    /// `less[code]` is `C[c]`.
+    less: Vec<usize>,
+    /// `checkpoints[b * sigma + code]` is `occ(c, b BLOCK)`.
+    checkpoints: Vec<u32>,
+}
+
+impl FmIndex {
+    pub fn new(s: &[u8]) -> Self {
+        let bwt = bwt(s);
+        let mut code = [None; 256];
+        let mut sigma = 0;
+        let mut freq = [0; 256];
+        for &c in &bwt {
+            freq[c as usize] += 1;
+        }
+        let mut less = Vec::new();
+        let mut total = 0;
+        for c in 0..256 {
+            if freq[c] > 0 {
+                code[c] = Some(sigma);
+                less.push(total);
+                total += freq[c];
+                sigma += 1;
+            }
+        }
+
+        let mut checkpoints = Vec::with_capacity((bwt.len() / BLOCK + 1) * sigma);
+        let mut running = vec![0u32; sigma];
+        for (i, &c) in bwt.iter().enumerate() {
+            if i.is_multiple_of(BLOCK) {
+                checkpoints.extend_from_slice(&running);
+            }
+            running[code[c as usize].unwrap()] += 1;
+        }
+        if bwt.len().is_multiple_of(BLOCK) {
+            checkpoints.extend_from_slice(&running);
+        }
+        Self { bwt, code, less, checkpoints }
+    }
+
+    /// `occ(c, i)`: the checkpoint before `i` plus a scan of the rest.
+    fn occ(&self, c: u8, code: usize, i: usize) -> usize {
+        let block = i / BLOCK;
+        let base = self.checkpoints[block * self.less.len() + code] as usize;
+        base + self.bwt[block * BLOCK..i].iter().filter(|&&b| b == c).count()
+    }
+
+    /// Occurrences of `pattern` in the text; the empty pattern occurs at
+    /// every one of the `n + 1` positions.
+    pub fn count(&self, pattern: &[u8]) -> usize {
+        let (mut lo, mut hi) = (0, self.bwt.len());
+        for &c in pattern.iter().rev() {
+            let Some(code) = self.code[c as usize] else {
+                return 0;
+            };
+            lo = self.less[code] + self.occ(c, code, lo);
+            hi = self.less[code] + self.occ(c, code, hi);
+            if lo >= hi {
+                return 0;
+            }
+        }
+        hi - lo
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // The text, `q`, then one pattern per line.
+    let text = lines.next().unwrap().expect("Failed to read the text");
+    let fm = FmIndex::new(text.trim().as_bytes());
+    let second_line = lines.next().unwrap().expect("Failed to read q");
+    let q: usize = second_line.trim().parse().expect("Failed to parse q");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let pattern = lines.next().unwrap().expect("Failed to read a pattern");
+        output.push(fm.count(pattern.trim().as_bytes()).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
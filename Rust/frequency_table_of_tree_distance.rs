@@ -0,0 +1,291 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly primes with primitive root 3. Their product is about
+/// 1.6 * 10^17, which is plenty to recover exact pair counts (at most n^2) by CRT.
+const MOD0: u64 = 998244353;
+const MOD1: u64 = 167772161;
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `M` of length
+/// `a.len()` (a power of two). `M` is a const parameter so the compiler can
+/// turn every `% M` into a multiplication.
+fn ntt<const M: u64>(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (M - 1) / len as u64, M);
+        if invert {
+            w_len = pow_mod(w_len, M - 2, M);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % M;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % M;
+                *x = if u + v >= M { u + v - M } else { u + v };
+                *y = if u >= v { u - v } else { u + M - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, M - 2, M);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % M;
+        }
+    }
+}
+
+/// The square of the polynomial `a` modulo `M`.
+fn square_mod<const M: u64>(a: &[u64]) -> Vec<u64> {
+    let result_len = 2 * a.len() - 1;
+    let mut fa: Vec<u64> = a.iter().map(|&x| x % M).collect();
+    fa.resize(result_len.next_power_of_two(), 0);
+
+    ntt::<M>(&mut fa, false);
+    for x in fa.iter_mut() {
+        *x = *x * *x % M;
+    }
+    ntt::<M>(&mut fa, true);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// Exact square of a non-negative integer sequence whose result fits below
+/// `MOD0 * MOD1`. Small inputs use the schoolbook product; larger ones run
+/// NTTs modulo both primes and recombine the residues by CRT.
+fn square_u64(a: &[u64]) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    if a.len() <= 32 {
+        let mut result = vec![0; 2 * a.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in a.iter().enumerate() {
+                result[i + j] += x * y;
+            }
+        }
+        return result;
+    }
+
+    let r0 = square_mod::<MOD0>(a);
+    let r1 = square_mod::<MOD1>(a);
+    let m0_inv_m1 = pow_mod(MOD0, MOD1 - 2, MOD1);
+    r0.iter()
+        .zip(&r1)
+        .map(|(&x0, &x1)| {
+            // x = x0 + MOD0 * t with t chosen so that x = x1 (mod MOD1).
+            let t = (x1 + MOD1 - x0 % MOD1) % MOD1 * m0_inv_m1 % MOD1;
+            x0 + MOD0 * t
+        })
+        .collect()
+}
+
+/// Centroid decomposition of a tree.
+///
+/// Each step picks the centroid of the current component, lets the caller
+/// process every path through it, removes it and recurses into the remaining
+/// components. Every vertex belongs to `O(log n)` components, so per-component
+/// work linear in its size gives `O(n log n)` overall.
+#[derive(Debug)]
+pub struct CentroidDecomposition {
+    adj: Vec<Vec<usize>>,
+}
+
+impl CentroidDecomposition {
+    /// Creates a new CentroidDecomposition from a given size and a list of undirected edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        Self { adj }
+    }
+
+    pub fn adj(&self) -> &[Vec<usize>] {
+        &self.adj
+    }
+
+    /// Runs the decomposition, calling `visit(centroid, removed)` for every
+    /// centroid with the vertices removed so far, so that `visit` can walk the
+    /// current component by never crossing a removed vertex.
+    ///
+    /// Returns the parent array of the centroid tree; the top centroid of every
+    /// connected component is its own parent.
+    pub fn run<F: FnMut(usize, &[bool])>(&self, mut visit: F) -> Vec<usize> {
+        let n = self.adj.len();
+        let mut removed = vec![false; n];
+        let mut centroid_parent = vec![usize::MAX; n];
+        let mut subtree_size = vec![0; n];
+        let mut parent = vec![usize::MAX; n];
+
+        // Each entry is `(any vertex of a component, centroid it hangs below)`.
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for root in 0..n {
+            // Every vertex ends up as a centroid, so this skips decomposed components.
+            if centroid_parent[root] != usize::MAX {
+                continue;
+            }
+            stack.push((root, root));
+            while let Some((start, above)) = stack.pop() {
+                // Recompute subtree sizes of the component with a BFS from `start`.
+                let mut order = vec![start];
+                parent[start] = usize::MAX;
+                let mut i = 0;
+                while i < order.len() {
+                    let u = order[i];
+                    i += 1;
+                    for &v in &self.adj[u] {
+                        if v != parent[u] && !removed[v] {
+                            parent[v] = u;
+                            order.push(v);
+                        }
+                    }
+                }
+                for &u in order.iter().rev() {
+                    subtree_size[u] = 1 + self.adj[u]
+                        .iter()
+                        .filter(|&&v| v != parent[u] && !removed[v])
+                        .map(|&v| subtree_size[v])
+                        .sum::<usize>();
+                }
+
+                // Walk towards the heavy side until no part exceeds half the component.
+                let total = order.len();
+                let mut centroid = start;
+                loop {
+                    let heavy = self.adj[centroid]
+                        .iter()
+                        .find(|&&v| v != parent[centroid] && !removed[v] && subtree_size[v] * 2 > total);
+                    match heavy {
+                        Some(&v) => centroid = v,
+                        None => break,
+                    }
+                }
+
+                // The top centroid of a component is its own parent.
+                centroid_parent[centroid] = if above == start { centroid } else { above };
+
+                visit(centroid, &removed);
+                removed[centroid] = true;
+                for &v in &self.adj[centroid] {
+                    if !removed[v] {
+                        stack.push((v, centroid));
+                    }
+                }
+            }
+        }
+
+        centroid_parent
+    }
+}
+
+/// Counts the vertices at every distance from `start` without entering `blocked`
+/// or removed vertices, starting the count at `offset`.
+fn depth_counts(adj: &[Vec<usize>], removed: &[bool], start: usize, blocked: usize, offset: usize) -> Vec<u64> {
+    let mut counts = Vec::new();
+    // BFS layer by layer; `(vertex, came_from)` pairs of the current layer.
+    let mut layer = vec![(start, blocked)];
+    let mut depth = offset;
+    while !layer.is_empty() {
+        if counts.len() <= depth {
+            counts.resize(depth + 1, 0);
+        }
+        counts[depth] += layer.len() as u64;
+
+        let mut next = Vec::new();
+        for &(u, from) in &layer {
+            for &v in &adj[u] {
+                if v != from && !removed[v] {
+                    next.push((v, u));
+                }
+            }
+        }
+        layer = next;
+        depth += 1;
+    }
+    counts
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    let edges: Vec<(usize, usize)> = (0..n - 1)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            (a, b)
+        })
+        .collect();
+
+    // --- Counting Paths Through Each Centroid ---
+    // Ordered pairs through a centroid are all pairs of the component minus
+    // the pairs that stay within one child subtree. Lengths of `n` or more
+    // only come from such same-subtree pairs, so they cancel and are skipped.
+    let cd = CentroidDecomposition::new(n, &edges);
+    let mut ordered_pairs = vec![0i64; n];
+    cd.run(|centroid, removed| {
+        let all = depth_counts(cd.adj(), removed, centroid, usize::MAX, 0);
+        for (d, c) in square_u64(&all).into_iter().take(n).enumerate() {
+            ordered_pairs[d] += c as i64;
+        }
+        for &child in &cd.adj()[centroid] {
+            if removed[child] {
+                continue;
+            }
+            let part = depth_counts(cd.adj(), removed, child, centroid, 1);
+            for (d, c) in square_u64(&part).into_iter().take(n).enumerate() {
+                ordered_pairs[d] -= c as i64;
+            }
+        }
+    });
+
+    // --- Output ---
+    let output: Vec<String> = ordered_pairs[1..].iter().map(|c| (c / 2).to_string()).collect();
+    println!("{}", output.join(" "));
+}
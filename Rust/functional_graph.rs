@@ -0,0 +1,168 @@
+// Rho decomposition of a functional graph (every node has out-degree 1).
+//
+// Input: `N Q`, the successors `a_0 .. a_{N-1}`, then `Q` queries `v k`.
+// For every query prints the node reached from `v` after `k` steps
+// (`0 <= k <= 10^18`).
+use std::io::{self, BufRead};
+
+/// A functional graph split into its cycles and the trees hanging off them.
+///
+/// Every walk `v, next[v], next[next[v]], ...` runs along a tail of
+/// `tail_len[v]` nodes into the cycle `cycle_id[v]`, which it enters at
+/// `cycle_entry[v]`, and goes around that cycle forever.
+#[derive(Debug)]
+pub struct FunctionalGraph {
+    next: Vec<usize>,
+    /// Index of the cycle the walk from `v` ends up on.
+    cycle_id: Vec<usize>,
+    /// First node of the walk from `v` that lies on a cycle (`v` itself if on a cycle).
+    cycle_entry: Vec<usize>,
+    /// Number of steps from `v` to `cycle_entry[v]`.
+    tail_len: Vec<usize>,
+    /// Position of each cycle node within its cycle.
+    cycle_pos: Vec<usize>,
+    /// `cycles[c]` lists the nodes of cycle `c` in walking order.
+    cycles: Vec<Vec<usize>>,
+    /// `up[j][v]` is the node `2^j` steps after `v`, for jumps within tails.
+    up: Vec<Vec<usize>>,
+}
+
+impl FunctionalGraph {
+    /// Decomposes the graph given by the successor array `next` in `O(n log n)`.
+    pub fn new(next: Vec<usize>) -> Self {
+        let n = next.len();
+        let mut cycle_id = vec![usize::MAX; n];
+        let mut cycle_entry = vec![usize::MAX; n];
+        let mut tail_len = vec![0; n];
+        let mut cycle_pos = vec![usize::MAX; n];
+        let mut cycles = Vec::new();
+        // 0 = unvisited, 1 = on the current walk, 2 = finished.
+        let mut state = vec![0u8; n];
+
+        for start in 0..n {
+            if state[start] != 0 {
+                continue;
+            }
+
+            // Walk until we hit a node we've seen before.
+            let mut walk = Vec::new();
+            let mut v = start;
+            while state[v] == 0 {
+                state[v] = 1;
+                walk.push(v);
+                v = next[v];
+            }
+
+            // Hitting the current walk means it closed a new cycle.
+            let mut tail_end = walk.len();
+            if state[v] == 1 {
+                let begin = walk.iter().position(|&u| u == v).unwrap();
+                let cycle = walk[begin..].to_vec();
+                for (i, &u) in cycle.iter().enumerate() {
+                    cycle_id[u] = cycles.len();
+                    cycle_entry[u] = u;
+                    cycle_pos[u] = i;
+                    state[u] = 2;
+                }
+                cycles.push(cycle);
+                tail_end = begin;
+            }
+
+            // The remaining prefix is a tail leading into already known nodes.
+            for &u in walk[..tail_end].iter().rev() {
+                let w = next[u];
+                cycle_id[u] = cycle_id[w];
+                cycle_entry[u] = cycle_entry[w];
+                tail_len[u] = tail_len[w] + 1;
+                state[u] = 2;
+            }
+        }
+
+        let mut up = vec![next.clone()];
+        let mut levels = 1;
+        while (1 << levels) < n {
+            let prev = &up[levels - 1];
+            let step = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(step);
+            levels += 1;
+        }
+
+        Self { next, cycle_id, cycle_entry, tail_len, cycle_pos, cycles, up }
+    }
+
+    pub fn cycle_id(&self, v: usize) -> usize {
+        self.cycle_id[v]
+    }
+
+    pub fn cycle_entry(&self, v: usize) -> usize {
+        self.cycle_entry[v]
+    }
+
+    pub fn tail_len(&self, v: usize) -> usize {
+        self.tail_len[v]
+    }
+
+    pub fn cycle_len(&self, v: usize) -> usize {
+        self.cycles[self.cycle_id[v]].len()
+    }
+
+    /// Returns the node reached from `v` after `k` steps.
+    ///
+    /// Steps inside the tail use binary lifting (`k < n` there); anything
+    /// past the cycle entry is reduced modulo the cycle length.
+    pub fn jump(&self, mut v: usize, k: u64) -> usize {
+        let tail = self.tail_len[v] as u64;
+        if k < tail {
+            for (j, up) in self.up.iter().enumerate() {
+                if k >> j & 1 == 1 {
+                    v = up[v];
+                }
+            }
+            return v;
+        }
+
+        let entry = self.cycle_entry[v];
+        let cycle = &self.cycles[self.cycle_id[v]];
+        let offset = ((k - tail) % cycle.len() as u64) as usize;
+        cycle[(self.cycle_pos[entry] + offset) % cycle.len()]
+    }
+
+    /// Returns the successor of `v`.
+    pub fn next(&self, v: usize) -> usize {
+        self.next[v]
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let next: Vec<usize> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the successors")
+        .split_whitespace()
+        .take(n)
+        .map(|s| s.parse().expect("Failed to parse a successor"))
+        .collect();
+
+    let fg = FunctionalGraph::new(next);
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        let k: u64 = parts.next().unwrap().parse().expect("Failed to parse k");
+        output.push(fg.jump(v, k).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
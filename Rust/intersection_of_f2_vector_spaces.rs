@@ -0,0 +1,230 @@
+use std::io::{self, BufRead};
+
+/// A dense matrix over F2 with each row packed into `u64` words, so row
+/// operations handle 64 columns per instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    /// Words per row.
+    stride: usize,
+    data: Vec<u64>,
+}
+
+impl BitMatrix {
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        let stride = cols.div_ceil(64);
+        Self { rows, cols, stride, data: vec![0; rows * stride] }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        self.data[i * self.stride + j / 64] >> (j % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: bool) {
+        let word = &mut self.data[i * self.stride + j / 64];
+        if value {
+            *word |= 1 << (j % 64);
+        } else {
+            *word &= !(1 << (j % 64));
+        }
+    }
+
+    pub fn row(&self, i: usize) -> &[u64] {
+        &self.data[i * self.stride..(i + 1) * self.stride]
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for w in 0..self.stride {
+                self.data.swap(a * self.stride + w, b * self.stride + w);
+            }
+        }
+    }
+
+    /// Gauss-Jordan elimination choosing pivots among the first `pivot_cols`
+    /// columns; any further columns (an augmented right-hand side) are
+    /// carried along. Returns the pivot column of every non-zero row, which
+    /// come first. Eliminating a row is a word-wise xor from the pivot's
+    /// word onwards, so the whole pass is `O(rows * cols * rank / 64)`.
+    pub fn eliminate(&mut self, pivot_cols: usize) -> Vec<usize> {
+        let stride = self.stride;
+        let mut pivots = Vec::new();
+        for col in 0..pivot_cols {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+            let Some(p) = (r..self.rows).find(|&i| self.get(i, col)) else {
+                continue;
+            };
+            self.swap_rows(p, r);
+
+            let first_word = col / 64;
+            let pivot_row: Vec<u64> = self.row(r)[first_word..].to_vec();
+            for (i, row) in self.data.chunks_exact_mut(stride).enumerate() {
+                if i != r && row[first_word] >> (col % 64) & 1 == 1 {
+                    for (x, &y) in row[first_word..].iter_mut().zip(&pivot_row) {
+                        *x ^= y;
+                    }
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(self.cols).len()
+    }
+
+    /// Solves `A x = b` over F2. Returns one solution together with a basis
+    /// of the kernel of `A`, or `None` if the system is inconsistent.
+    pub fn solve(&self, b: &[bool]) -> Option<(Vec<bool>, Vec<Vec<bool>>)> {
+        assert_eq!(self.rows, b.len(), "dimension mismatch");
+        let m = self.cols;
+        let mut aug = BitMatrix::zero(self.rows, m + 1);
+        for (i, &bit) in b.iter().enumerate() {
+            let start = i * aug.stride;
+            aug.data[start..start + self.stride].copy_from_slice(self.row(i));
+            aug.set(i, m, bit);
+        }
+        let pivots = aug.eliminate(m);
+
+        // A set right-hand side below the pivot rows reads `0 = 1`.
+        if (pivots.len()..self.rows).any(|i| aug.get(i, m)) {
+            return None;
+        }
+
+        // Free variables are zero in the particular solution.
+        let mut solution = vec![false; m];
+        for (i, &p) in pivots.iter().enumerate() {
+            solution[p] = aug.get(i, m);
+        }
+
+        // Setting one free variable determines every pivot variable.
+        let mut is_pivot = vec![false; m];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let kernel = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![false; m];
+                v[f] = true;
+                for (i, &p) in pivots.iter().enumerate() {
+                    v[p] = aug.get(i, f);
+                }
+                v
+            })
+            .collect();
+
+        Some((solution, kernel))
+    }
+}
+
+/// A basis of a subspace of F2^64, kept in echelon form: `pivot[b]` is the
+/// basis vector whose highest set bit is `b`, if any.
+#[derive(Clone, Debug)]
+pub struct XorBasis {
+    pivot: [u64; 64],
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self { pivot: [0; 64] }
+    }
+}
+
+impl XorBasis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `x` reduced by the basis: zero exactly when `x` is in the span.
+    pub fn reduce(&self, mut x: u64) -> u64 {
+        while x != 0 {
+            let b = 63 - x.leading_zeros() as usize;
+            if self.pivot[b] == 0 {
+                break;
+            }
+            x ^= self.pivot[b];
+        }
+        x
+    }
+
+    /// Adds `x` to the span; returns whether the dimension grew.
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        self.pivot[63 - x.leading_zeros() as usize] = x;
+        true
+    }
+
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    pub fn dim(&self) -> usize {
+        self.pivot.iter().filter(|&&v| v != 0).count()
+    }
+
+    pub fn vectors(&self) -> impl Iterator<Item = u64> + '_ {
+        self.pivot.iter().copied().filter(|&v| v != 0)
+    }
+
+    /// A basis of the intersection of the spans of `u` and `v`, whose
+    /// vectors must fit in `bits <= 32` bits.
+    ///
+    /// Zassenhaus: row-reduce the pairs `(u, u)` and `(v, 0)`, packed as
+    /// `high << bits | low`. The reduced pairs with zero high half are
+    /// exactly `(0, w)` for `w` running over a basis of the intersection.
+    pub fn intersection(u: &[u64], v: &[u64], bits: u32) -> Vec<u64> {
+        assert!(bits <= 32, "vectors too wide to pack in pairs");
+        let mut basis = XorBasis::new();
+        for &x in u {
+            basis.insert(x << bits | x);
+        }
+        for &x in v {
+            basis.insert(x << bits);
+        }
+        basis.pivot[..bits as usize].iter().copied().filter(|&w| w != 0).collect()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    // Each test case is two lines, `N u_1 .. u_N` and `M v_1 .. v_M`, with
+    // vectors below `2^30`.
+    let mut read_vectors = || -> Vec<u64> {
+        let line = lines.next().unwrap().expect("Failed to read a vector line");
+        line.split_whitespace().skip(1).map(|s| s.parse().expect("Failed to parse a vector")).collect()
+    };
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let u = read_vectors();
+        let v = read_vectors();
+        let w = XorBasis::intersection(&u, &v, 30);
+        let words: Vec<String> = std::iter::once(w.len().to_string()).chain(w.iter().map(|x| x.to_string())).collect();
+        output.push(words.join(" "));
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,221 @@
+use std::env;
+use std::io::{self, BufRead};
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+
+/// The weight type of the shortest-path engine: totally ordered, with a
+/// zero and an addition that saturates at `infinity`, the "unreachable"
+/// sentinel. A path whose length reaches `infinity` is treated as
+/// unreachable, so adversarial weight sums cannot overflow.
+pub trait Cost: Copy + Ord {
+    // Required methods
+    fn zero() -> Self;
+    fn infinity() -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {$(
+        impl Cost for $t {
+            fn zero() -> Self { 0 }
+            fn infinity() -> Self { <$t>::MAX }
+            fn saturating_add(self, other: Self) -> Self { <$t>::saturating_add(self, other) }
+        }
+    )*};
+}
+
+impl_cost!(u32, u64, u128, i64);
+
+/// Lexicographic pairs, e.g. `(distance, hops)` to prefer the shortest path
+/// with the fewest edges. The pair is infinite as soon as its first
+/// component is.
+impl<A: Cost, B: Cost> Cost for (A, B) {
+    fn zero() -> Self { (A::zero(), B::zero()) }
+    fn infinity() -> Self { (A::infinity(), B::infinity()) }
+    fn saturating_add(self, other: Self) -> Self {
+        let first = self.0.saturating_add(other.0);
+        if first == A::infinity() {
+            return Self::infinity();
+        }
+        (first, self.1.saturating_add(other.1))
+    }
+}
+
+/// Represents a directed graph using an adjacency list.
+#[derive(Debug)]
+pub struct Graph<W: Cost> {
+    /// `adj[i]` contains a list of triples `(neighbor, weight, edge index)` for node `i`.
+    adj: Vec<Vec<(usize, W, usize)>>,
+    prev_node: Vec<usize>,
+    /// `prev_edge[i]` is the index of the edge that last improved the distance to node `i`.
+    prev_edge: Vec<usize>,
+    source_node: usize,
+    shortest_path_vec: Vec<W>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct DijState<W> {
+    node: usize,
+    distance: W,
+}
+
+impl<W: Ord> Ord for DijState<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl<W: Ord> PartialOrd for DijState<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // Some(other.distance.cmp(&self.distance))
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Cost> Graph<W> {
+    /// Creates a new directed Graph from a given size and a list of edges.
+    pub fn new(size: usize, edges: &[(usize, usize, W)], source_node: usize) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            adj[u].push((v, w, i));
+        }
+        let prev_node = vec![usize::MAX; size];
+        let prev_edge = vec![usize::MAX; size];
+        let shortest_path_vec = vec![W::infinity(); size];
+
+        Self { adj, prev_node, prev_edge, source_node, shortest_path_vec }
+    }
+
+    /// The distance from the source to every node, `W::infinity()` where
+    /// unreachable. Must be called after `populate_all_shortest_path`.
+    pub fn distances(&self) -> &[W] {
+        &self.shortest_path_vec
+    }
+
+    pub fn populate_all_shortest_path(&mut self) {
+        let mut pq = BinaryHeap::new();
+        self.shortest_path_vec[self.source_node] = W::zero();
+        self.prev_node[self.source_node] = self.source_node;
+
+        pq.push(DijState {node: self.source_node, distance: W::zero() });
+
+        while let Some(s) = pq.pop() {
+            if s.distance > self.shortest_path_vec[s.node] {
+                continue;
+            }
+            for (neighbour, weight, edge) in &self.adj[s.node] {
+                let candidate = s.distance.saturating_add(*weight);
+                if self.shortest_path_vec[*neighbour] > candidate {
+                    self.shortest_path_vec[*neighbour] = candidate;
+                    self.prev_node[*neighbour] = s.node;
+                    self.prev_edge[*neighbour] = *edge;
+                    pq.push(DijState {node: *neighbour, distance: self.shortest_path_vec[*neighbour]});
+                }
+            }
+        }
+    }
+}
+
+/// Single-source shortest paths with arbitrary weights by Bellman-Ford in
+/// `O(nm)`: `Some(distances)` with `None` for unreachable nodes, or `None`
+/// if a negative cycle is reachable from `source`. With `source = None`,
+/// every node starts at distance 0, as if from a virtual source with a
+/// zero-weight edge to each; then any negative cycle is found.
+pub fn bellman_ford(n: usize, edges: &[(usize, usize, i64)], source: Option<usize>) -> Option<Vec<Option<i64>>> {
+    let mut dist = match source {
+        Some(s) => {
+            let mut dist = vec![None; n];
+            dist[s] = Some(0);
+            dist
+        }
+        None => vec![Some(0); n],
+    };
+    // A shortest path has at most `n - 1` edges (`n` from the virtual
+    // source), so an improvement in round `n + 1` means a negative cycle.
+    for _ in 0..=n {
+        let mut changed = false;
+        for &(u, v, w) in edges {
+            if let Some(du) = dist[u] {
+                if dist[v].is_none_or(|dv| du + w < dv) {
+                    dist[v] = Some(du + w);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return Some(dist);
+        }
+    }
+    None
+}
+
+/// All-pairs shortest paths with negative weights by Johnson's algorithm:
+/// `dist[u][v]`, `None` where unreachable, or `None` on a negative cycle.
+/// `O(nm + n m log n)`, which beats Floyd-Warshall on sparse graphs.
+///
+/// With `h` the Bellman-Ford distances from a virtual source, every edge
+/// satisfies `h[v] <= h[u] + w`, so `w + h[u] - h[v]` is a non-negative
+/// weight under which every path `s -> t` costs its true length plus
+/// `h[s] - h[t]`; Dijkstra then runs from each node.
+pub fn johnson(n: usize, edges: &[(usize, usize, i64)]) -> Option<Vec<Vec<Option<i64>>>> {
+    let h: Vec<i64> = bellman_ford(n, edges, None)?.into_iter().map(Option::unwrap).collect();
+    let reweighted: Vec<(usize, usize, i64)> = edges.iter().map(|&(u, v, w)| (u, v, w + h[u] - h[v])).collect();
+    let dist = (0..n)
+        .map(|s| {
+            let mut g = Graph::<i64>::new(n, &reweighted, s);
+            g.populate_all_shortest_path();
+            g.distances()
+                .iter()
+                .enumerate()
+                .map(|(t, &d)| (d != i64::infinity()).then(|| d - h[s] + h[t]))
+                .collect()
+        })
+        .collect();
+    Some(dist)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let edges: Vec<(usize, usize, i64)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            let c: i64 = parts.next().unwrap().parse().expect("Failed to parse c");
+            (u, v, c)
+        })
+        .collect();
+
+    // --- Solving and Output ---
+    // `bellman-ford` runs Bellman-Ford from every node instead, in
+    // `O(n^2 m)`, to cross-check the two engines on small graphs.
+    let dist = match env::args().nth(1).as_deref() {
+        Some("bellman-ford") => bellman_ford(n, &edges, None)
+            .map(|_| (0..n).map(|s| bellman_ford(n, &edges, Some(s)).unwrap()).collect()),
+        _ => johnson(n, &edges),
+    };
+
+    match dist {
+        None => println!("NEGATIVE CYCLE"),
+        Some(dist) => {
+            let output: Vec<String> = dist
+                .iter()
+                .map(|row| {
+                    let row: Vec<String> = row.iter().map(|d| d.map_or("INF".to_string(), |d| d.to_string())).collect();
+                    row.join(" ")
+                })
+                .collect();
+            println!("{}", output.join("\n"));
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::io::{self, BufRead};
+
+/// A tree rooted at a fixed node, built from an undirected edge list.
+#[derive(Debug)]
+struct Tree {
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+}
+
+impl Tree {
+    /// Creates a new Tree from a given size and a list of undirected edges.
+    /// Parents and depths are found with an iterative BFS from `root`.
+    pub fn new(size: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+
+        let mut parent = vec![usize::MAX; size];
+        let mut depth = vec![0; size];
+        let mut queue = vec![root];
+        parent[root] = root;
+
+        let mut head = 0;
+        while head < queue.len() {
+            let u = queue[head];
+            head += 1;
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    queue.push(v);
+                }
+            }
+        }
+
+        Self { parent, depth }
+    }
+}
+
+/// Binary lifting table over a rooted tree, answering LCA, k-th ancestor and
+/// path jump queries in `O(log n)`.
+#[derive(Debug)]
+pub struct BinaryLifting {
+    /// `up[k][v]` is the `2^k`-th ancestor of `v` (clamped at the root).
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl BinaryLifting {
+    fn new(tree: Tree) -> Self {
+        let n = tree.parent.len();
+
+        let mut levels = 1;
+        while (1 << levels) < n {
+            levels += 1;
+        }
+
+        let mut up = vec![tree.parent];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+
+        Self { up, depth: tree.depth }
+    }
+
+    /// Returns the `k`-th ancestor of `v`, or `None` if `v` is less than `k` deep.
+    pub fn kth_ancestor(&self, mut v: usize, k: usize) -> Option<usize> {
+        if k > self.depth[v] {
+            return None;
+        }
+        for (i, up) in self.up.iter().enumerate() {
+            if k >> i & 1 == 1 {
+                v = up[v];
+            }
+        }
+        Some(v)
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (u, mut v) = if self.depth[u] < self.depth[v] { (v, u) } else { (u, v) };
+
+        // Lift `u` up to the depth of `v`.
+        let mut u = self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        if u == v {
+            return u;
+        }
+
+        // Lift both as long as they stay below the common ancestor.
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+
+    /// Returns the `k`-th vertex (0-indexed) on the path from `u` to `v`,
+    /// or `None` if the path has fewer than `k + 1` vertices.
+    pub fn jump(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let w = self.lca(u, v);
+        let up_len = self.depth[u] - self.depth[w];
+        let down_len = self.depth[v] - self.depth[w];
+
+        if k <= up_len {
+            // The target is on the way up from `u` to the LCA.
+            self.kth_ancestor(u, k)
+        } else if k <= up_len + down_len {
+            // The target is on the way down to `v`, counted from `v` upwards.
+            self.kth_ancestor(v, up_len + down_len - k)
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let edges: Vec<(usize, usize)> = (0..n - 1)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            (a, b)
+        })
+        .collect();
+
+    let bl = BinaryLifting::new(Tree::new(n, &edges, 0));
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let s: usize = parts.next().unwrap().parse().expect("Failed to parse s");
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+        let i: usize = parts.next().unwrap().parse().expect("Failed to parse i");
+
+        output.push(match bl.jump(s, t, i) {
+            Some(v) => v.to_string(),
+            None => "-1".to_string(),
+        });
+    }
+    println!("{}", output.join("\n"));
+}
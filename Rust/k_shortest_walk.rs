@@ -0,0 +1,366 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+const NONE: u32 = u32::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct SkewNode {
+    key: i64,
+    value: usize,
+    /// Pending addition for both children's subtrees; `key` is already up to date.
+    lazy: i64,
+    left: u32,
+    right: u32,
+}
+
+/// Meldable min-heaps of `(key, value)` pairs by skew heaps, every node in
+/// one arena. A heap is named by its root, `NONE` when empty; `meld`, `push`
+/// and `pop` consume their heaps and return the root of the result. Adding
+/// a constant to every key of a heap is lazy and `O(1)`.
+///
+/// Skew heaps keep no balance information: a meld walks both right spines
+/// and swaps the children of every node it passes, which is `O(log n)`
+/// amortized. The spines can be long, so the meld is iterative.
+#[derive(Clone, Debug, Default)]
+pub struct SkewHeap {
+    nodes: Vec<SkewNode>,
+}
+
+impl SkewHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// Adds `delta` to every key of the heap at `root`.
+    pub fn add(&mut self, root: u32, delta: i64) {
+        if root != NONE {
+            let node = &mut self.nodes[root as usize];
+            node.key += delta;
+            node.lazy += delta;
+        }
+    }
+
+    fn push_down(&mut self, v: u32) {
+        let SkewNode { lazy, left, right, .. } = self.nodes[v as usize];
+        if lazy != 0 {
+            self.add(left, lazy);
+            self.add(right, lazy);
+            self.nodes[v as usize].lazy = 0;
+        }
+    }
+
+    pub fn meld(&mut self, mut a: u32, mut b: u32) -> u32 {
+        // Merge the right spines top-down, then swap children bottom-up.
+        let mut spine = Vec::new();
+        while a != NONE && b != NONE {
+            if self.nodes[a as usize].key > self.nodes[b as usize].key {
+                std::mem::swap(&mut a, &mut b);
+            }
+            self.push_down(a);
+            spine.push(a);
+            a = self.nodes[a as usize].right;
+        }
+        let mut root = if a == NONE { b } else { a };
+        while let Some(v) = spine.pop() {
+            let node = &mut self.nodes[v as usize];
+            node.right = node.left;
+            node.left = root;
+            root = v;
+        }
+        root
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(SkewNode { key, value, lazy: 0, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        self.push_down(root);
+        let SkewNode { left, right, .. } = self.nodes[root as usize];
+        self.meld(left, right)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LeftistNode {
+    key: i64,
+    value: usize,
+    /// The length of the right spine; never more than the left child's.
+    rank: u32,
+    left: u32,
+    right: u32,
+}
+
+/// Persistent meldable min-heaps of `(key, value)` pairs by leftist heaps,
+/// every node in one arena. A heap is named by its root, `NONE` when empty,
+/// and is never modified: `meld`, `push` and `pop` copy the nodes they
+/// change and return a new root, so every earlier version stays valid and
+/// shares the rest of its nodes.
+///
+/// The right spine of a leftist heap has `O(log n)` nodes, and a meld only
+/// walks the two right spines, so every operation copies `O(log n)` nodes
+/// in the worst case. That bound survives persistence, where the amortized
+/// bounds of skew or pairing heaps do not.
+#[derive(Clone, Debug, Default)]
+pub struct PersistentHeap {
+    nodes: Vec<LeftistNode>,
+}
+
+impl PersistentHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// The two subheaps under `root`, which together hold everything but its top.
+    pub fn children(&self, root: u32) -> [u32; 2] {
+        let node = &self.nodes[root as usize];
+        [node.left, node.right]
+    }
+
+    fn rank(&self, v: u32) -> u32 {
+        self.nodes.get(v as usize).map_or(0, |node| node.rank)
+    }
+
+    pub fn meld(&mut self, a: u32, b: u32) -> u32 {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        let (a, b) = if self.nodes[a as usize].key <= self.nodes[b as usize].key { (a, b) } else { (b, a) };
+        let mut node = self.nodes[a as usize];
+        node.right = self.meld(node.right, b);
+        if self.rank(node.left) < self.rank(node.right) {
+            std::mem::swap(&mut node.left, &mut node.right);
+        }
+        node.rank = self.rank(node.right) + 1;
+        self.nodes.push(node);
+        (self.nodes.len() - 1) as u32
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(LeftistNode { key, value, rank: 1, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        let [left, right] = self.children(root);
+        self.meld(left, right)
+    }
+
+    /// A heap of `items` in `O(n)` by melding them pairwise in rounds.
+    pub fn build(&mut self, items: impl IntoIterator<Item = (i64, usize)>) -> u32 {
+        let mut queue: std::collections::VecDeque<u32> =
+            items.into_iter().map(|(key, value)| self.push(NONE, key, value)).collect();
+        while queue.len() > 1 {
+            let (a, b) = (queue.pop_front().unwrap(), queue.pop_front().unwrap());
+            let melded = self.meld(a, b);
+            queue.push_back(melded);
+        }
+        queue.pop_front().unwrap_or(NONE)
+    }
+}
+
+/// The lengths of the `k` shortest walks from `s` to `t`, shortest first,
+/// counting walks that repeat vertices and edges; fewer if there are not
+/// that many. Edge costs must be non-negative. Eppstein's algorithm, in
+/// `O(m log m + k log k)`.
+///
+/// Every walk is the shortest-path tree towards `t` plus the sequence of
+/// off-tree "sidetrack" edges it takes, and costs `dist(s)` plus their
+/// detours `c + dist(b) - dist(a)`. The sidetracks available from `v` are
+/// those out of `v` or any vertex on its tree path to `t`, so `heap[v]`
+/// is the persistent meld of `heap[next(v)]` with the ones out of `v`.
+/// Walks then form a tree of successors: from the last sidetrack taken,
+/// either replace it by a child in its heap, or append the top of the heap
+/// of the vertex it leads to.
+pub fn k_shortest_walks(n: usize, edges: &[(usize, usize, i64)], s: usize, t: usize, k: usize) -> Vec<i64> {
+    let mut radj = vec![Vec::new(); n];
+    for (i, &(_, b, _)) in edges.iter().enumerate() {
+        radj[b].push(i);
+    }
+    // Dijkstra towards `t`, recording the tree edge of every vertex and the
+    // order in which vertices are settled.
+    let mut dist = vec![i64::MAX; n];
+    let mut tree_edge = vec![usize::MAX; n];
+    let mut order = Vec::new();
+    let mut pq = BinaryHeap::new();
+    dist[t] = 0;
+    pq.push(Reverse((0, t)));
+    while let Some(Reverse((d, v))) = pq.pop() {
+        if d > dist[v] {
+            continue;
+        }
+        order.push(v);
+        for &e in &radj[v] {
+            let (a, _, c) = edges[e];
+            if d + c < dist[a] {
+                dist[a] = d + c;
+                tree_edge[a] = e;
+                pq.push(Reverse((dist[a], a)));
+            }
+        }
+    }
+    if dist[s] == i64::MAX {
+        return Vec::new();
+    }
+
+    let mut out = vec![Vec::new(); n];
+    for (i, &(a, b, _)) in edges.iter().enumerate() {
+        if dist[a] != i64::MAX && dist[b] != i64::MAX && i != tree_edge[a] {
+            out[a].push(i);
+        }
+    }
+    let mut heaps = PersistentHeap::new();
+    let mut heap = vec![NONE; n];
+    for &v in &order {
+        let own = heaps.build(out[v].iter().map(|&e| {
+            let (a, b, c) = edges[e];
+            (c + dist[b] - dist[a], b)
+        }));
+        let inherited = if v == t { NONE } else { heap[edges[tree_edge[v]].1] };
+        heap[v] = heaps.meld(inherited, own);
+    }
+
+    let mut walks = vec![dist[s]];
+    let mut pq = BinaryHeap::new();
+    if let Some((key, _)) = heaps.top(heap[s]) {
+        pq.push(Reverse((dist[s] + key, heap[s])));
+    }
+    while walks.len() < k {
+        let Some(Reverse((length, v))) = pq.pop() else {
+            break;
+        };
+        walks.push(length);
+        let (key, b) = heaps.top(v).unwrap();
+        for child in heaps.children(v) {
+            if let Some((child_key, _)) = heaps.top(child) {
+                pq.push(Reverse((length - key + child_key, child)));
+            }
+        }
+        if let Some((next_key, _)) = heaps.top(heap[b]) {
+            pq.push(Reverse((length + next_key, heap[b])));
+        }
+    }
+    walks
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let s: usize = parts.next().unwrap().parse().expect("Failed to parse s");
+    let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+    let k: usize = parts.next().unwrap().parse().expect("Failed to parse k");
+    let edges: Vec<(usize, usize, i64)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            let c: i64 = parts.next().unwrap().parse().expect("Failed to parse c");
+            (a, b, c)
+        })
+        .collect();
+
+    // --- Output ---
+    // `-1` for every walk beyond the last one.
+    let walks = k_shortest_walks(n, &edges, s, t, k);
+    let output: Vec<String> = (0..k).map(|i| walks.get(i).map_or(-1, |&w| w).to_string()).collect();
+    println!("{}", output.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// Every walk from `s` in order of length, one queue entry per walk;
+    /// the ones ending at `t` are recorded. Walks are only extended through
+    /// vertices that can still reach `t`, so with positive costs every
+    /// expansion is a prefix of some walk to `t` and `k` of them come quickly.
+    fn brute_force(n: usize, edges: &[(usize, usize, i64)], s: usize, t: usize, k: usize) -> Vec<i64> {
+        let mut reaches = vec![false; n];
+        reaches[t] = true;
+        for _ in 0..n {
+            for &(a, b, _) in edges {
+                reaches[a] |= reaches[b];
+            }
+        }
+        let mut adj = vec![Vec::new(); n];
+        for &(a, b, c) in edges {
+            if reaches[b] {
+                adj[a].push((b, c));
+            }
+        }
+        let mut walks = Vec::new();
+        let mut pq = BinaryHeap::new();
+        if reaches[s] {
+            pq.push(Reverse((0, s)));
+        }
+        while let Some(Reverse((length, v))) = pq.pop() {
+            if v == t {
+                walks.push(length);
+                if walks.len() == k {
+                    break;
+                }
+            }
+            for &(b, c) in &adj[v] {
+                pq.push(Reverse((length + c, b)));
+            }
+        }
+        walks
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..500 {
+            let n = 1 + rng.below(5);
+            let (s, t, k) = (rng.below(n), rng.below(n), 1 + rng.below(12));
+            // Positive costs keep the brute force finite; self-loops and
+            // parallel edges are included.
+            let edges: Vec<(usize, usize, i64)> =
+                (0..rng.below(10)).map(|_| (rng.below(n), rng.below(n), 1 + rng.below(6) as i64)).collect();
+            assert_eq!(k_shortest_walks(n, &edges, s, t, k), brute_force(n, &edges, s, t, k), "{:?}", edges);
+        }
+    }
+}
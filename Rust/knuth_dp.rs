@@ -0,0 +1,133 @@
+use std::io::{self, BufRead};
+
+/// Interval DP over `0..=n`: `dp[i][i + 1] = 0` and, for `j - i >= 2`,
+/// `dp[i][j] = min_{i < m < j} dp[i][m] + dp[m][j] + cost(i, j)`, with the
+/// table returned as `dp[i][j]` for `i < j`.
+///
+/// Knuth–Yao speedup: when `cost` satisfies the quadrangle inequality
+/// `cost(a, c) + cost(b, d) <= cost(a, d) + cost(b, c)` for `a <= b <= c <= d`
+/// and is monotone on nested intervals, the leftmost best split obeys
+/// `opt[i][j - 1] <= opt[i][j] <= opt[i + 1][j]`. Scanning only that window
+/// telescopes along every diagonal, so the DP costs `O(n^2)` instead of
+/// `O(n^3)`.
+pub fn knuth_optimize<F: Fn(usize, usize) -> i64>(n: usize, cost: F) -> Vec<Vec<i64>> {
+    let mut dp = vec![vec![0; n + 1]; n + 1];
+    let mut opt = vec![vec![0; n + 1]; n + 1];
+    for i in 0..n {
+        opt[i][i + 1] = i + 1;
+    }
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len;
+            // `opt[i][i + 1]` stands for `i + 1`, the only split of length 2.
+            let lo = opt[i][j - 1].max(i + 1);
+            let hi = opt[i + 1][j].min(j - 1);
+            let (mut best, mut best_m) = (i64::MAX, lo);
+            for (m, row) in dp.iter().enumerate().take(hi + 1).skip(lo) {
+                let value = dp[i][m] + row[j];
+                if value < best {
+                    (best, best_m) = (value, m);
+                }
+            }
+            dp[i][j] = best + cost(i, j);
+            opt[i][j] = best_m;
+        }
+    }
+    dp
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n`, then `n` non-negative pile sizes; merging two adjacent piles
+    // costs their total size.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let a: Vec<i64> = if n > 0 {
+        let line = lines.next().unwrap().expect("Failed to read the values");
+        line.split_whitespace().map(|s| s.parse().expect("Failed to parse a value")).collect()
+    } else {
+        Vec::new()
+    };
+
+    // --- Solving and Output ---
+    // The cheapest way to merge every pile into one.
+    let mut prefix = vec![0; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = prefix[i] + a[i];
+    }
+    let dp = knuth_optimize(n, |i, j| prefix[j] - prefix[i]);
+    println!("{}", if n == 0 { 0 } else { dp[0][n] });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// The `O(n^3)` recurrence, checked on every interval `i < j`.
+    fn assert_matches_naive(n: usize, cost: &[Vec<i64>]) {
+        let dp = knuth_optimize(n, |i, j| cost[i][j]);
+        let mut naive = vec![vec![0; n + 1]; n + 1];
+        for len in 2..=n {
+            for i in 0..=n - len {
+                let j = i + len;
+                naive[i][j] = (i + 1..j).map(|m| naive[i][m] + naive[m][j]).min().unwrap() + cost[i][j];
+            }
+        }
+        for i in 0..n {
+            assert_eq!(dp[i][i + 1..], naive[i][i + 1..], "n = {} row {} of {:?}", n, i, cost);
+        }
+    }
+
+    #[test]
+    fn pile_merging_matches_naive() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..2000 {
+            let n = rng.below(9);
+            let mut prefix = vec![0; n + 1];
+            for i in 0..n {
+                prefix[i + 1] = prefix[i] + rng.below(10) as i64;
+            }
+            let cost: Vec<Vec<i64>> = (0..=n).map(|i| (0..=n).map(|j| prefix[j] - prefix[i]).collect()).collect();
+            assert_matches_naive(n, &cost);
+        }
+    }
+
+    #[test]
+    fn random_quadrangle_costs_match_naive() {
+        // `cost(i, j)` sums non-negative weights `w[a][b]` over
+        // `i <= a <= b < j`: monotone on nested intervals, and
+        // `cost(a, d) + cost(b, c) - cost(a, c) - cost(b, d)` is a sum of weights.
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..2000 {
+            let n = rng.below(9);
+            let w: Vec<Vec<i64>> = (0..n).map(|_| (0..n).map(|_| rng.below(6) as i64).collect()).collect();
+            let mut cost = vec![vec![0; n + 1]; n + 1];
+            for i in (0..n).rev() {
+                for j in i + 1..=n {
+                    cost[i][j] = cost[i + 1][j] + w[i][i..j].iter().sum::<i64>();
+                }
+            }
+            assert_matches_naive(n, &cost);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::io::{self, BufRead};
+
+/// `x^k`, or `None` if it doesn't fit in a `u128`.
+fn checked_pow(x: u128, k: u32) -> Option<u128> {
+    let mut result: u128 = 1;
+    for _ in 0..k {
+        result = result.checked_mul(x)?;
+    }
+    Some(result)
+}
+
+/// The largest `x` with `x^k <= a`, for `k >= 1`.
+///
+/// Integer Newton iteration `x <- ((k - 1) x + a / x^{k-1}) / k` decreases
+/// monotonically from any overestimate down to the root. The final loop
+/// corrects the estimate in case the saturated `x^{k-1}` skewed the last step.
+pub fn kth_root_u128(a: u128, k: u32) -> u128 {
+    assert!(k >= 1, "zeroth root");
+    if k == 1 || a < 2 {
+        return a;
+    }
+    if k >= 128 {
+        return 1;
+    }
+
+    // `2^ceil(bits / k)` is at least the root.
+    let bits = 128 - a.leading_zeros();
+    let mut x: u128 = 1 << bits.div_ceil(k);
+    loop {
+        let power = checked_pow(x, k - 1).unwrap_or(u128::MAX);
+        let y = ((k - 1) as u128 * x + a / power) / k as u128;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+
+    while checked_pow(x, k).is_none_or(|p| p > a) {
+        x -= 1;
+    }
+    while checked_pow(x + 1, k).is_some_and(|p| p <= a) {
+        x += 1;
+    }
+    x
+}
+
+/// The largest `x` with `x^k <= a`, for `k >= 1`.
+pub fn kth_root_u64(a: u64, k: u32) -> u64 {
+    kth_root_u128(a as u128, k) as u64
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let a: u64 = parts.next().unwrap().parse().expect("Failed to parse a");
+        let k: u32 = parts.next().unwrap().parse().expect("Failed to parse k");
+        output.push(kth_root_u64(a, k).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
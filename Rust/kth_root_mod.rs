@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// The inverse of `a` modulo `m`, for `gcd(a, m) = 1`, by the extended
+/// Euclidean algorithm.
+fn inv_mod(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i64 % m as i64, m as i64);
+    let (mut old_s, mut s) = (1i64, 0i64);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(m as i64) as u64
+}
+
+/// The smallest primitive root modulo the prime `p`: the first `g` with
+/// `g^{(p-1)/q} != 1` for every prime `q | p - 1`.
+fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let mut factors = Vec::new();
+    let mut m = p - 1;
+    let mut q = 2;
+    while q * q <= m {
+        if m.is_multiple_of(q) {
+            factors.push(q);
+            while m.is_multiple_of(q) {
+                m /= q;
+            }
+        }
+        q += 1;
+    }
+    if m > 1 {
+        factors.push(m);
+    }
+    (2..).find(|&g| factors.iter().all(|&q| pow_mod(g, (p - 1) / q, p) != 1)).unwrap()
+}
+
+/// The smallest `k >= 0` with `x^k = y (mod m)` for `x` coprime to `m`,
+/// by baby-step giant-step in `O(sqrt m)` expected time.
+fn discrete_log_coprime(x: u64, y: u64, m: u64) -> Option<u64> {
+    let n = (m as f64).sqrt() as u64 + 1;
+    // Baby steps: for every `y x^q`, remember the largest `q`.
+    let mut baby = HashMap::with_capacity(n as usize + 1);
+    let mut cur = y % m;
+    for q in 0..=n {
+        baby.insert(cur, q);
+        cur = cur * x % m;
+    }
+
+    let giant = pow_mod(x, n, m);
+    let mut cur = 1 % m;
+    for p in 1..=n {
+        cur = cur * giant % m;
+        if let Some(&q) = baby.get(&cur) {
+            return Some(n * p - q);
+        }
+    }
+    None
+}
+
+/// Some `x` with `x^k = y (mod p)` for a prime `p`, or `None` if there is none.
+///
+/// With a primitive root `r` and `y = r^a`, we need `k t = a (mod p - 1)`,
+/// which is solvable exactly when `g = gcd(k, p - 1)` divides `a`; then
+/// `t = (a / g) (k / g)^{-1} (mod (p - 1) / g)` and `x = r^t`.
+///
+/// Two cases never reach the discrete log. With `0^0 = 1`, `k = 0` maps
+/// every `x` to 1. Otherwise `y = 0` has the root 0 alone, and when
+/// `p - 1 | k` every nonzero `x` maps to 1 by Fermat.
+pub fn kth_root_mod(k: u64, y: u64, p: u64) -> Option<u64> {
+    let y = y % p;
+    if k == 0 {
+        return (y == 1 % p).then_some(1);
+    }
+    if y == 0 {
+        return Some(0);
+    }
+    let m = p - 1;
+    if k.is_multiple_of(m) {
+        return (y == 1).then_some(1);
+    }
+    let g = gcd(k, m);
+    // Cheap rejection: `y` is a `k`-th power iff it is a `g`-th power.
+    if pow_mod(y, m / g, p) != 1 {
+        return None;
+    }
+
+    let r = primitive_root(p);
+    let a = discrete_log_coprime(r, y, p)?;
+    let t = (a / g) % (m / g) * inv_mod(k / g % (m / g), m / g) % (m / g);
+    Some(pow_mod(r, t, p))
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let k: u64 = parts.next().unwrap().parse().expect("Failed to parse k");
+        let y: u64 = parts.next().unwrap().parse().expect("Failed to parse y");
+        let p: u64 = parts.next().unwrap().parse().expect("Failed to parse p");
+        output.push(match kth_root_mod(k, y, p) {
+            Some(x) => x.to_string(),
+            None => "-1".to_string(),
+        });
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,169 @@
+use std::io::{self, BufRead};
+
+/// `sum_{i=0}^{n-1} floor((a i + b) / m)` for `a, b < m`, in `O(log m)`.
+///
+/// After reducing `a` and `b` below `m`, the lattice points under the line
+/// are counted again with the roles of the axes swapped, which shrinks the
+/// problem like the Euclidean algorithm.
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut ans = 0;
+    loop {
+        if a >= m {
+            ans += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            ans += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    ans
+}
+
+/// `sum_{i=0}^{n-1} floor((a i + b) / m)` for any signed `a` and `b`, with
+/// `n >= 0` and `m >= 1`.
+///
+/// Negative (or large) `a` and `b` are first written as `q m + r` with
+/// `0 <= r < m`; the `q` parts contribute `q n (n - 1) / 2` and `q n` exactly.
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i128 {
+    assert!(n >= 0 && m >= 1, "floor_sum needs n >= 0 and m >= 1");
+    let (n, m, a, b) = (n as i128, m as i128, a as i128, b as i128);
+    let (qa, ra) = (a.div_euclid(m), a.rem_euclid(m));
+    let (qb, rb) = (b.div_euclid(m), b.rem_euclid(m));
+    let shifted = qa * (n * (n - 1) / 2) + qb * n;
+    shifted + floor_sum_unsigned(n as u128, m as u128, ra as u128, rb as u128) as i128
+}
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The orientation of `a -> b -> c`: positive for a left turn, negative for
+/// a right turn, zero when collinear.
+pub fn orient(a: Point, b: Point, c: Point) -> i128 {
+    (b - a).cross(c - a)
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// `sum_{x = p.x}^{q.x - 1} floor(y(x))` for the line `y` through `p` and
+/// `q`, with `p.x < q.x`: the signed number of lattice points `(x, y)` with
+/// `p.x <= x < q.x` and `0 < y <= y(x)`, counting negatively below the x
+/// axis. Coordinates up to `10^9` in absolute value stay exact.
+///
+/// With `dx = q.x - p.x`, `y(p.x + i) = (dy i + p.y dx) / dx`, which is
+/// exactly a `floor_sum` term.
+pub fn lattice_points_under(p: Point, q: Point) -> i128 {
+    assert!(p.x < q.x, "the segment must go strictly to the right");
+    let (dx, dy) = (q.x - p.x, q.y - p.y);
+    floor_sum(dx, dx, dy, p.y * dx)
+}
+
+/// Lattice points in the closed triangle `abc`, by columns: each integer
+/// `x` contributes `floor(upper(x)) - ceil(lower(x)) + 1`, where one side is
+/// the edge between the leftmost and rightmost vertices and the other the
+/// two-edge chain through the middle one. Every column sum is a
+/// `lattice_points_under`, and `ceil` is `floor` of the mirrored line.
+pub fn lattice_points_in_triangle(a: Point, b: Point, c: Point) -> i128 {
+    let mut v = [a, b, c];
+    v.sort_unstable();
+    let [a, b, c] = v;
+    if orient(a, b, c) == 0 {
+        // Degenerate: the points of the longest side, i.e. from `a` to `c`.
+        return gcd(c.x - a.x, c.y - a.y) as i128 + 1;
+    }
+
+    let mirror = |p: Point| Point::new(p.x, -p.y);
+    // `floor` (or, mirrored, `-ceil`) of a chain over the columns `[a.x, c.x]`.
+    // A vertical piece is skipped, so the last column takes the end of the
+    // last piece that is not.
+    let chain_floor = |ps: &[Point]| -> i128 {
+        let (mut sum, mut last) = (0, 0);
+        for w in ps.windows(2).filter(|w| w[0].x < w[1].x) {
+            sum += lattice_points_under(w[0], w[1]);
+            last = w[1].y;
+        }
+        sum + last as i128
+    };
+    let direct = chain_floor(&[a, c]);
+    let bent = chain_floor(&[a, b, c]);
+    let direct_mirrored = -chain_floor(&[mirror(a), mirror(c)]);
+    let bent_mirrored = -chain_floor(&[mirror(a), mirror(b), mirror(c)]);
+    let columns = (c.x - a.x + 1) as i128;
+    // `b` left of `a -> c` lies above the long edge.
+    if orient(a, c, b) > 0 {
+        bent - direct_mirrored + columns
+    } else {
+        direct - bent_mirrored + columns
+    }
+}
+
+/// Lattice points on the boundary of triangle `abc`: `gcd(dx, dy)` per side.
+pub fn boundary_points(a: Point, b: Point, c: Point) -> i128 {
+    [(a, b), (b, c), (c, a)].iter().map(|&(p, q)| gcd(q.x - p.x, q.y - p.y) as i128).sum()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    // Each query is a non-degenerate triangle `x1 y1 x2 y2 x3 y3`; print the
+    // number of interior and boundary lattice points.
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let v: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a coordinate")).collect();
+        let (a, b, c) = (Point::new(v[0], v[1]), Point::new(v[2], v[3]), Point::new(v[4], v[5]));
+        let boundary = boundary_points(a, b, c);
+        output.push(format!("{} {}", lattice_points_in_triangle(a, b, c) - boundary, boundary));
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,519 @@
+use std::env;
+use std::io::{self, BufRead};
+
+/// Common interface for lowest common ancestor backends.
+///
+/// Every backend is built once from a rooted tree and then answers queries
+/// without mutating itself, so callers can swap implementations freely.
+pub trait Lca {
+    /// Returns the lowest common ancestor of `u` and `v`.
+    fn lca(&self, u: usize, v: usize) -> usize;
+
+    /// Answers every `(u, v)` query at once. Offline backends override this
+    /// and answer the whole batch in one pass.
+    fn lca_batch(&self, queries: &[(usize, usize)]) -> Vec<usize> {
+        queries.iter().map(|&(u, v)| self.lca(u, v)).collect()
+    }
+}
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// Common interface for static range-minimum backends over the Euler tour.
+pub trait Rmq<T> {
+    fn build(values: Vec<T>) -> Self;
+    /// Returns the minimum in the non-empty half-open range `[start, end)`.
+    fn min(&self, start: usize, end: usize) -> T;
+}
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns the nodes in BFS order together with the depth of every node.
+    /// Iterative on purpose: judge trees can be paths of length 5 * 10^5.
+    pub fn bfs_order(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut order = Vec::with_capacity(self.size());
+        let mut depth = vec![0; self.size()];
+        order.push(self.root);
+
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &c in &self.children[u] {
+                depth[c] = depth[u] + 1;
+                order.push(c);
+            }
+        }
+
+        (order, depth)
+    }
+
+    /// Returns the Euler tour of the tree (every node is written when it is
+    /// entered and again after each child returns, `2n - 1` entries in total)
+    /// along with the index of the first occurrence of every node.
+    pub fn euler_tour(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut tour = Vec::with_capacity(2 * self.size());
+        let mut first = vec![0; self.size()];
+        tour.push(self.root);
+        // Each stack entry is `(node, index of the next child to visit)`.
+        let mut stack = vec![(self.root, 0)];
+
+        while let Some((u, next_child)) = stack.last_mut() {
+            if let Some(&c) = self.children[*u].get(*next_child) {
+                // Descend into the next child.
+                *next_child += 1;
+                first[c] = tour.len();
+                tour.push(c);
+                stack.push((c, 0));
+            } else {
+                // All children are done: return to the parent and write it again.
+                stack.pop();
+                if let Some(&(p, _)) = stack.last() {
+                    tour.push(p);
+                }
+            }
+        }
+
+        (tour, first)
+    }
+}
+
+/// LCA by binary lifting: `O(n log n)` preprocessing, `O(log n)` per query.
+#[derive(Debug)]
+pub struct BinaryLifting {
+    /// `up[k][v]` is the `2^k`-th ancestor of `v` (clamped at the root).
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl BinaryLifting {
+    fn new(tree: &Tree) -> Self {
+        let n = tree.size();
+        let (_, depth) = tree.bfs_order();
+
+        let mut levels = 1;
+        while (1 << levels) < n {
+            levels += 1;
+        }
+
+        let mut up = vec![tree.parent.clone()];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+
+        Self { up, depth }
+    }
+}
+
+impl Lca for BinaryLifting {
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] { (v, u) } else { (u, v) };
+
+        // Lift `u` up to the depth of `v`.
+        let diff = self.depth[u] - self.depth[v];
+        for (k, up) in self.up.iter().enumerate() {
+            if diff >> k & 1 == 1 {
+                u = up[u];
+            }
+        }
+        if u == v {
+            return u;
+        }
+
+        // Lift both as long as they stay below the common ancestor.
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+}
+
+/// Offline LCA by Tarjan's union-find method: `O((n + q) α(n))` for a whole
+/// batch, with no per-node tables beyond the union-find itself.
+///
+/// A DFS unions every finished child into its parent's set and labels the
+/// set with the parent, so while `v` is being finished, the set of any
+/// finished `w` is labelled with the deepest ancestor of `w` still on the
+/// stack, which is their LCA. A query is answered at its later endpoint.
+#[derive(Debug)]
+pub struct TarjanLca<'a> {
+    tree: &'a Tree,
+}
+
+impl<'a> TarjanLca<'a> {
+    fn new(tree: &'a Tree) -> Self {
+        Self { tree }
+    }
+}
+
+/// Union-find with path compression and union by size.
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self { parent: (0..n).collect(), size: vec![1; n] }
+    }
+
+    fn find(&mut self, mut u: usize) -> usize {
+        let mut root = u;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        while self.parent[u] != root {
+            (u, self.parent[u]) = (self.parent[u], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `u` and `v` and returns the new root.
+    fn union(&mut self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = (self.find(u), self.find(v));
+        if u != v {
+            if self.size[u] < self.size[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            self.parent[v] = u;
+            self.size[u] += self.size[v];
+        }
+        u
+    }
+}
+
+impl Lca for TarjanLca<'_> {
+    /// One query alone still costs a full pass over the tree.
+    fn lca(&self, u: usize, v: usize) -> usize {
+        self.lca_batch(&[(u, v)])[0]
+    }
+
+    fn lca_batch(&self, queries: &[(usize, usize)]) -> Vec<usize> {
+        let n = self.tree.size();
+        // The queries at every node, bucketed into one array.
+        let mut start = vec![0; n + 1];
+        for &(u, v) in queries {
+            start[u + 1] += 1;
+            start[v + 1] += 1;
+        }
+        for i in 0..n {
+            start[i + 1] += start[i];
+        }
+        let mut fill = start.clone();
+        let mut at = vec![(0, 0); 2 * queries.len()];
+        for (i, &(u, v)) in queries.iter().enumerate() {
+            at[fill[u]] = (v, i);
+            fill[u] += 1;
+            at[fill[v]] = (u, i);
+            fill[v] += 1;
+        }
+
+        let mut uf = UnionFind::new(n);
+        let mut label: Vec<usize> = (0..n).collect();
+        let mut finished = vec![false; n];
+        let mut answers = vec![0; queries.len()];
+        // Each stack entry is `(node, index of the next child to visit)`.
+        let mut stack = vec![(self.tree.root, 0)];
+        while let Some((u, next_child)) = stack.last_mut() {
+            let u = *u;
+            if let Some(&c) = self.tree.children[u].get(*next_child) {
+                *next_child += 1;
+                stack.push((c, 0));
+                continue;
+            }
+            stack.pop();
+            finished[u] = true;
+            for &(w, i) in &at[start[u]..start[u + 1]] {
+                if finished[w] {
+                    answers[i] = label[uf.find(w)];
+                }
+            }
+            if let Some(&(p, _)) = stack.last() {
+                let root = uf.union(u, p);
+                label[root] = p;
+            }
+        }
+        answers
+    }
+}
+
+/// A sparse table answering `op` over any range in `O(1)`.
+/// `op` must be idempotent (min, max, gcd, ...) since ranges are covered by
+/// two possibly overlapping power-of-two blocks.
+#[derive(Debug)]
+pub struct SparseTable<T: Monoid + Clone> {
+    /// `table[k][i]` is the fold of the `2^k` elements starting at `i`.
+    table: Vec<Vec<T>>,
+}
+
+impl<T: Monoid + Clone> SparseTable<T> {
+    pub fn new(values: Vec<T>) -> Self {
+        let n = values.len();
+        let mut table = vec![values];
+
+        let mut k = 1;
+        while (1 << k) <= n {
+            let prev = &table[k - 1];
+            let half = 1 << (k - 1);
+            let next = (0..=n - (1 << k))
+                .map(|i| T::op(&prev[i], &prev[i + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+
+        Self { table }
+    }
+
+    /// Returns the fold of values in the given half-open range `[start, end)`.
+    pub fn get(&self, start: usize, end: usize) -> T {
+        if start >= end {
+            return T::id();
+        }
+        let k = (usize::BITS - 1 - (end - start).leading_zeros()) as usize;
+        T::op(&self.table[k][start], &self.table[k][end - (1 << k)])
+    }
+}
+
+impl<T: Monoid + Clone> Rmq<T> for SparseTable<T> {
+    fn build(values: Vec<T>) -> Self {
+        SparseTable::new(values)
+    }
+
+    fn min(&self, start: usize, end: usize) -> T {
+        self.get(start, end)
+    }
+}
+
+/// A static range-minimum structure in `O(n)` space and `O(1)` per query.
+///
+/// The values are cut into blocks of 64. A sparse table over the block
+/// minima answers the whole blocks of a query, and inside a block, `masks[i]`
+/// is the monotonic stack of the block's prefix ending at `i` as a bitmask:
+/// the minimum of `[l, i]` within one block is at the lowest bit of
+/// `masks[i]` that is at least `l`. Ties go to the leftmost position.
+#[derive(Debug)]
+pub struct BlockRmq<T: Ord + Clone> {
+    values: Vec<T>,
+    masks: Vec<u64>,
+    /// `table[k][b]` is the position of the minimum of blocks `b..b + 2^k`.
+    table: Vec<Vec<usize>>,
+}
+
+impl<T: Ord + Clone> BlockRmq<T> {
+    const BLOCK: usize = 64;
+
+    pub fn new(values: Vec<T>) -> Self {
+        let n = values.len();
+        let mut masks = vec![0u64; n];
+        let mut stack: Vec<usize> = Vec::with_capacity(Self::BLOCK);
+        for i in 0..n {
+            if i % Self::BLOCK == 0 {
+                stack.clear();
+            }
+            let mut mask = if stack.is_empty() { 0 } else { masks[i - 1] };
+            while let Some(&top) = stack.last() {
+                if values[top] <= values[i] {
+                    break;
+                }
+                mask &= !(1 << (top % Self::BLOCK));
+                stack.pop();
+            }
+            stack.push(i);
+            masks[i] = mask | 1 << (i % Self::BLOCK);
+        }
+
+        let blocks = n.div_ceil(Self::BLOCK);
+        let block_min = (0..blocks)
+            .map(|b| {
+                let end = ((b + 1) * Self::BLOCK).min(n) - 1;
+                b * Self::BLOCK + masks[end].trailing_zeros() as usize
+            })
+            .collect();
+        let mut table: Vec<Vec<usize>> = vec![block_min];
+        let mut k = 1;
+        while (1 << k) <= blocks {
+            let prev = &table[k - 1];
+            let half = 1 << (k - 1);
+            let next = (0..=blocks - (1 << k))
+                .map(|b| Self::argmin(&values, prev[b], prev[b + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+
+        Self { values, masks, table }
+    }
+
+    fn argmin(values: &[T], i: usize, j: usize) -> usize {
+        if values[j] < values[i] { j } else { i }
+    }
+
+    /// The position of the minimum of `[l, r]`, both in one block.
+    fn in_block(&self, l: usize, r: usize) -> usize {
+        let mask = self.masks[r] & (!0u64 << (l % Self::BLOCK));
+        r - r % Self::BLOCK + mask.trailing_zeros() as usize
+    }
+
+    /// Returns the position of the minimum in the non-empty half-open range
+    /// `[start, end)`, the leftmost one on ties.
+    pub fn argmin_in(&self, start: usize, end: usize) -> usize {
+        assert!(start < end, "the range must not be empty");
+        let (l, r) = (start, end - 1);
+        let (bl, br) = (l / Self::BLOCK, r / Self::BLOCK);
+        if bl == br {
+            return self.in_block(l, r);
+        }
+        let mut best = self.in_block(l, (bl + 1) * Self::BLOCK - 1);
+        if bl + 1 < br {
+            let k = (usize::BITS - 1 - (br - bl - 1).leading_zeros()) as usize;
+            best = Self::argmin(&self.values, best, self.table[k][bl + 1]);
+            best = Self::argmin(&self.values, best, self.table[k][br - (1 << k)]);
+        }
+        Self::argmin(&self.values, best, self.in_block(br * Self::BLOCK, r))
+    }
+
+    /// Returns the minimum in the non-empty half-open range `[start, end)`.
+    pub fn get(&self, start: usize, end: usize) -> T {
+        self.values[self.argmin_in(start, end)].clone()
+    }
+}
+
+impl<T: Ord + Clone> Rmq<T> for BlockRmq<T> {
+    fn build(values: Vec<T>) -> Self {
+        BlockRmq::new(values)
+    }
+
+    fn min(&self, start: usize, end: usize) -> T {
+        self.get(start, end)
+    }
+}
+
+/// A node on the Euler tour, ordered by depth first so `min` picks the shallowest.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DepthNode {
+    depth: usize,
+    node: usize,
+}
+
+impl Monoid for DepthNode {
+    fn id() -> Self { DepthNode { depth: usize::MAX, node: usize::MAX } }
+    fn op(a: &Self, b: &Self) -> Self { if a.depth <= b.depth { a.clone() } else { b.clone() } }
+}
+
+/// LCA by Euler tour and RMQ: the LCA of `u` and `v` is the shallowest node
+/// visited between their first occurrences on the tour. Queries are `O(1)`
+/// with either backend; the sparse table takes `O(n log n)` preprocessing
+/// and memory, `BlockRmq` only `O(n)`.
+#[derive(Debug)]
+pub struct EulerTourLca<R: Rmq<DepthNode>> {
+    first: Vec<usize>,
+    rmq: R,
+}
+
+impl<R: Rmq<DepthNode>> EulerTourLca<R> {
+    fn new(tree: &Tree) -> Self {
+        let (_, depth) = tree.bfs_order();
+        let (tour, first) = tree.euler_tour();
+        let values = tour
+            .iter()
+            .map(|&node| DepthNode { depth: depth[node], node })
+            .collect();
+
+        Self { first, rmq: R::build(values) }
+    }
+}
+
+impl<R: Rmq<DepthNode>> Lca for EulerTourLca<R> {
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (l, r) = if self.first[u] <= self.first[v] {
+            (self.first[u], self.first[v])
+        } else {
+            (self.first[v], self.first[u])
+        };
+        self.rmq.min(l, r + 1).node
+    }
+}
+
+/// Answers every `(u, v)` query with the given backend.
+fn solve<L: Lca>(lca: &L, queries: &[(usize, usize)]) -> Vec<usize> {
+    lca.lca_batch(queries)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    // Node 0 is the root; `p_i` is given for i = 1..n.
+    let mut parent = vec![0; n];
+    if n > 1 {
+        let line = lines.next().unwrap().expect("Failed to read the parents");
+        for (i, p) in line.split_whitespace().enumerate() {
+            parent[i + 1] = p.parse().expect("Failed to parse p");
+        }
+    }
+
+    let queries: Vec<(usize, usize)> = (0..q)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read a query line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    // --- Solving and Output ---
+    // The backend can be picked on the command line for comparisons;
+    // the judge runs without arguments and gets the O(1) query backend.
+    let tree = Tree::new(parent, 0);
+    let answers = match env::args().nth(1).as_deref() {
+        Some("binary-lifting") => solve(&BinaryLifting::new(&tree), &queries),
+        Some("block-rmq") => solve(&EulerTourLca::<BlockRmq<DepthNode>>::new(&tree), &queries),
+        Some("tarjan") => solve(&TarjanLca::new(&tree), &queries),
+        _ => solve(&EulerTourLca::<SparseTable<DepthNode>>::new(&tree), &queries),
+    };
+
+    let output: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+    println!("{}", output.join("\n"));
+}
@@ -1,31 +1,62 @@
-use std::io;
+use std::io::{self, Read, Write};
 
-fn read_one_i64() -> i64 {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-
-    let mut iter = input.split_whitespace();
-    let a: i64 = iter.next().unwrap().parse().unwrap();
-
-    a
+/// The whole input read at once and split into whitespace-separated tokens.
+struct Tokenizer {
+    buf: Vec<u8>,
+    pos: usize,
 }
 
-fn read_two_i64() -> (i64, i64) {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+impl Tokenizer {
+    fn from_stdin() -> io::Result<Self> {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
 
-    let mut iter = input.split_whitespace();
-    let a: i64 = iter.next().unwrap().parse().unwrap();
-    let b: i64 = iter.next().unwrap().parse().unwrap();
+    /// Returns the next token as raw bytes.
+    fn next_token(&mut self) -> &[u8] {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        &self.buf[start..self.pos]
+    }
 
-    (a, b)
+    fn next_u64(&mut self) -> u64 {
+        self.next_token().iter().fold(0, |acc, &d| acc * 10 + (d - b'0') as u64)
+    }
 }
 
-fn main() {
-    let mut t = read_one_i64();
+/// Appends the decimal digits of `x` to `out`, written back to front into a
+/// small stack buffer first.
+fn write_u64(out: &mut Vec<u8>, mut x: u64) {
+    let mut digits = [0u8; 20];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (x % 10) as u8;
+        x /= 10;
+        if x == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(&digits[i..]);
+}
 
-    while { let tmp = t; t -= 1; tmp } > 0 {
-        let (a, b) = read_two_i64();
-        println!("{}", a + b);
+fn main() -> io::Result<()> {
+    let mut tokens = Tokenizer::from_stdin()?;
+    let t = tokens.next_u64() as usize;
+
+    // Every answer is at most 2 * 10^18: 19 digits and a newline.
+    let mut out = Vec::with_capacity(20 * t);
+    for _ in 0..t {
+        let a = tokens.next_u64();
+        let b = tokens.next_u64();
+        write_u64(&mut out, a + b);
+        out.push(b'\n');
     }
-}
\ No newline at end of file
+    io::stdout().lock().write_all(&out)
+}
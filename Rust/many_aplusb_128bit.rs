@@ -1,31 +1,73 @@
-use std::io;
+use std::io::{self, Read, Write};
 
-fn read_one_i128() -> i128 {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-
-    let mut iter = input.split_whitespace();
-    let a: i128 = iter.next().unwrap().parse().unwrap();
-
-    a
+/// The whole input read at once and split into whitespace-separated tokens.
+struct Tokenizer {
+    buf: Vec<u8>,
+    pos: usize,
 }
 
-fn read_two_i128() -> (i128, i128) {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+impl Tokenizer {
+    fn from_stdin() -> io::Result<Self> {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
+
+    /// Returns the next token as raw bytes.
+    fn next_token(&mut self) -> &[u8] {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        &self.buf[start..self.pos]
+    }
 
-    let mut iter = input.split_whitespace();
-    let a: i128 = iter.next().unwrap().parse().unwrap();
-    let b: i128 = iter.next().unwrap().parse().unwrap();
+    fn next_i128(&mut self) -> i128 {
+        let token = self.next_token();
+        let (negative, digits) = match token.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, token),
+        };
+        let value = digits.iter().fold(0i128, |acc, &d| acc * 10 + (d - b'0') as i128);
+        if negative { -value } else { value }
+    }
+}
 
-    (a, b)
+/// Appends the decimal digits of `x` to `out`, written back to front into a
+/// small stack buffer first.
+fn write_i128(out: &mut Vec<u8>, x: i128) {
+    if x < 0 {
+        out.push(b'-');
+    }
+    let mut x = x.unsigned_abs();
+    let mut digits = [0u8; 39];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (x % 10) as u8;
+        x /= 10;
+        if x == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(&digits[i..]);
 }
 
-fn main() {
-    let mut t = read_one_i128();
+fn main() -> io::Result<()> {
+    let mut tokens = Tokenizer::from_stdin()?;
+    let t = tokens.next_i128() as usize;
 
-    while { let tmp = t; t -= 1; tmp } > 0 {
-        let (a, b) = read_two_i128();
-        println!("{}", a + b);
+    // Every answer is below 2 * 10^37 in absolute value: a sign, 38 digits
+    // and a newline.
+    let mut out = Vec::with_capacity(40 * t);
+    for _ in 0..t {
+        let a = tokens.next_i128();
+        let b = tokens.next_i128();
+        write_i128(&mut out, a + b);
+        out.push(b'\n');
     }
-}
\ No newline at end of file
+    io::stdout().lock().write_all(&out)
+}
@@ -0,0 +1,229 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly prime with primitive root 3.
+const MOD: u64 = 998244353;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    base %= MOD;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MOD;
+        }
+        base = base * base % MOD;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `MOD` of length
+/// `a.len()` (a power of two).
+fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (MOD - 1) / len as u64);
+        if invert {
+            w_len = pow_mod(w_len, MOD - 2);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % MOD;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % MOD;
+                *x = if u + v >= MOD { u + v - MOD } else { u + v };
+                *y = if u >= v { u - v } else { u + MOD - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, MOD - 2);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % MOD;
+        }
+    }
+}
+
+/// The product of the polynomials `a` and `b` modulo `MOD`. Small inputs use
+/// the schoolbook product, which beats the transform below a few dozen terms.
+fn convolve(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 32 {
+        let mut result = vec![0; result_len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] = (result[i + j] + x * y) % MOD;
+            }
+        }
+        return result;
+    }
+
+    let size = result_len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(size, 0);
+    fb.resize(size, 0);
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y % MOD;
+    }
+    ntt(&mut fa, true);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// Inverses of every element of `a`, which must all be non-zero, with a
+/// single exponentiation: invert the total product, then peel it apart.
+fn batch_inverse(a: &[u64]) -> Vec<u64> {
+    let mut prefix = Vec::with_capacity(a.len() + 1);
+    prefix.push(1);
+    for &x in a {
+        prefix.push(prefix[prefix.len() - 1] * x % MOD);
+    }
+    let mut inv = pow_mod(prefix[a.len()], MOD - 2);
+    let mut result = vec![0; a.len()];
+    for i in (0..a.len()).rev() {
+        result[i] = inv * prefix[i] % MOD;
+        inv = inv * a[i] % MOD;
+    }
+    result
+}
+
+/// Given the values `h(0), ..., h(d)` of a polynomial of degree at most `d`,
+/// returns `h(m), ..., h(m + d)` for `d < m` and `m + d < MOD`, in
+/// `O(d log d)`.
+///
+/// By Lagrange interpolation, `h(m + k)` is the window product
+/// `prod_{j=0}^{d} (m + k - j)` times `sum_i a_i / (m + k - i)` with
+/// `a_i = h(i) / (i! (d - i)! (-1)^{d-i})`, and the sum is one convolution
+/// of `a` with the inverses of `m - d, ..., m + d`.
+pub fn shift_samples(h: &[u64], m: u64) -> Vec<u64> {
+    let d = h.len() - 1;
+    let mut fact = vec![1u64; d + 1];
+    for i in 1..=d {
+        fact[i] = fact[i - 1] * i as u64 % MOD;
+    }
+    let inv_fact = batch_inverse(&fact);
+    let a: Vec<u64> = (0..=d)
+        .map(|i| {
+            let x = h[i] * inv_fact[i] % MOD * inv_fact[d - i] % MOD;
+            if (d - i) % 2 == 1 { (MOD - x) % MOD } else { x }
+        })
+        .collect();
+
+    // `inv[t]` is the inverse of `m - d + t`, all non-zero since `d < m`.
+    let values: Vec<u64> = (0..=2 * d as u64).map(|t| m - d as u64 + t).collect();
+    let inv = batch_inverse(&values);
+    let conv = convolve(&a, &inv);
+
+    // The product over the window `m + k - d ..= m + k`, slid one step at a time.
+    let mut window = values[..=d].iter().fold(1, |acc, &x| acc * x % MOD);
+    let mut result = Vec::with_capacity(d + 1);
+    for k in 0..=d {
+        if k > 0 {
+            window = window * values[k + d] % MOD * inv[k - 1] % MOD;
+        }
+        result.push(window * conv[k + d] % MOD);
+    }
+    result
+}
+
+/// Answers `n!` modulo `MOD` for arbitrary `n` after an `O(sqrt(MOD) log MOD)`
+/// precomputation, in `O(BLOCK)` per query.
+pub struct FactorialTable {
+    /// `blocks[x] = (x BLOCK)!` for every `x BLOCK < MOD`.
+    blocks: Vec<u64>,
+}
+
+/// Width of the stored blocks. With `g(x) = prod_{i=1}^{BLOCK} (BLOCK x + i)`,
+/// the blocks are prefix products of `g(0), g(1), ...`.
+const BLOCK: usize = 1024;
+
+impl FactorialTable {
+    /// Samples `g` directly at `0..=BLOCK`, which determines it as it has
+    /// degree `BLOCK`, then extends the samples by `shift_samples` until
+    /// every block below `MOD` is covered.
+    pub fn new() -> Self {
+        let count = MOD as usize / BLOCK + 1;
+        let mut g: Vec<u64> = (0..=BLOCK as u64)
+            .map(|x| (1..=BLOCK as u64).fold(1, |acc, i| acc * ((BLOCK as u64 * x + i) % MOD) % MOD))
+            .collect();
+        let base = g.clone();
+        while g.len() < count {
+            let next = shift_samples(&base, g.len() as u64);
+            g.extend(next);
+        }
+
+        let mut blocks = Vec::with_capacity(count);
+        let mut acc = 1;
+        for &value in &g[..count] {
+            blocks.push(acc);
+            acc = acc * value % MOD;
+        }
+        Self { blocks }
+    }
+
+    /// `n!` modulo `MOD`; zero once `n >= MOD`.
+    pub fn factorial(&self, n: u64) -> u64 {
+        if n >= MOD {
+            return 0;
+        }
+        let x = n as usize / BLOCK;
+        (x as u64 * BLOCK as u64 + 1..=n).fold(self.blocks[x], |acc, i| acc * i % MOD)
+    }
+}
+
+impl Default for FactorialTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let table = FactorialTable::new();
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let n: u64 = line.trim().parse().expect("Failed to parse n");
+        output.push(table.factorial(n).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,468 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// An integer modulo the judge's prime `MOD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModInt {
+    val: u64,
+}
+
+impl ModInt {
+    pub fn new(val: u64) -> Self {
+        ModInt { val: val % MOD }
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat's little theorem; `self` must be non-zero.
+    pub fn inv(self) -> Self {
+        self.pow(MOD - 2)
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = ModInt;
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + rhs.val)
+    }
+}
+
+impl std::ops::Sub for ModInt {
+    type Output = ModInt;
+    fn sub(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + MOD - rhs.val)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = ModInt;
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val * rhs.val)
+    }
+}
+
+/// A dense matrix over `ModInt`, stored row-major in one flat vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<ModInt>,
+}
+
+impl Matrix {
+    /// Creates a `rows x cols` zero matrix.
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![ModInt::new(0); rows * cols] }
+    }
+
+    /// Creates the `size x size` identity matrix.
+    pub fn identity(size: usize) -> Self {
+        let mut m = Self::zero(size, size);
+        for i in 0..size {
+            m.data[i * size + i] = ModInt::new(1);
+        }
+        m
+    }
+
+    /// Creates a matrix from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<ModInt>>) -> Self {
+        let cols = rows.first().map_or(0, |r| r.len());
+        let n = rows.len();
+        let data: Vec<ModInt> = rows.into_iter().flatten().collect();
+        assert_eq!(data.len(), n * cols, "rows must have equal length");
+        Self { rows: n, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[ModInt] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t.data[j * self.rows + i] = self.data[i * self.cols + j];
+            }
+        }
+        t
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Matrix {
+    type Output = ModInt;
+    fn index(&self, (i, j): (usize, usize)) -> &ModInt {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut ModInt {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl std::ops::Add for &Matrix {
+    type Output = Matrix;
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimension mismatch");
+        let data = self.data.iter().zip(&rhs.data).map(|(&a, &b)| a + b).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+impl std::ops::Sub for &Matrix {
+    type Output = Matrix;
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimension mismatch");
+        let data = self.data.iter().zip(&rhs.data).map(|(&a, &b)| a - b).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+/// Number of products that can be summed in a `u64` before reducing:
+/// each is below `MOD^2 < 2^60`, so 16 of them stay below `2^64`.
+const REDUCE_EVERY: usize = 16;
+
+impl std::ops::Mul for &Matrix {
+    type Output = Matrix;
+
+    /// Multiplies in `i-k-j` order so the inner loop streams over contiguous
+    /// rows of both `rhs` and the accumulator. The `k` loop is cut into
+    /// blocks of `REDUCE_EVERY` rows of `rhs`, which both bounds the raw
+    /// `u64` sums and keeps the rows being reused hot in cache.
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(self.cols, rhs.rows, "dimension mismatch");
+        let (n, m, k) = (self.rows, self.cols, rhs.cols);
+        let b: Vec<u32> = rhs.data.iter().map(|x| x.val as u32).collect();
+        let mut out = Matrix::zero(n, k);
+        let mut acc = vec![0u64; k];
+
+        for i in 0..n {
+            acc.iter_mut().for_each(|x| *x = 0);
+            let a_row = self.row(i);
+            for block in (0..m).step_by(REDUCE_EVERY) {
+                for l in block..m.min(block + REDUCE_EVERY) {
+                    let a = a_row[l].val;
+                    for (x, &y) in acc.iter_mut().zip(&b[l * k..(l + 1) * k]) {
+                        *x += a * y as u64;
+                    }
+                }
+                acc.iter_mut().for_each(|x| *x %= MOD);
+            }
+            for (o, &x) in out.data[i * k..(i + 1) * k].iter_mut().zip(&acc) {
+                *o = ModInt { val: x };
+            }
+        }
+
+        out
+    }
+}
+
+impl Matrix {
+    /// Swaps rows `a` and `b`.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for j in 0..self.cols {
+                self.data.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+    }
+
+    /// Returns `[self | other]`; both must have the same number of rows.
+    pub fn augment(&self, other: &Matrix) -> Matrix {
+        assert_eq!(self.rows, other.rows, "dimension mismatch");
+        let cols = self.cols + other.cols;
+        let mut data = Vec::with_capacity(self.rows * cols);
+        for i in 0..self.rows {
+            data.extend_from_slice(self.row(i));
+            data.extend_from_slice(other.row(i));
+        }
+        Matrix { rows: self.rows, cols, data }
+    }
+
+    /// Gauss-Jordan elimination choosing pivots among the first `pivot_cols`
+    /// columns only; the remaining columns (an augmented right-hand side) are
+    /// carried along. Afterwards the leading part is in reduced row echelon
+    /// form with the non-zero rows first.
+    ///
+    /// Returns the pivot column of every non-zero row, and the product of the
+    /// pivots with the sign of the row swaps, which is the determinant when
+    /// the leading block is square and of full rank.
+    fn eliminate(&mut self, pivot_cols: usize) -> (Vec<usize>, ModInt) {
+        let width = self.cols;
+        let mut pivots = Vec::new();
+        let mut det = ModInt::new(1);
+
+        for col in 0..pivot_cols {
+            let r = pivots.len();
+            let Some(p) = (r..self.rows).find(|&i| self[(i, col)].val != 0) else {
+                continue;
+            };
+            if p != r {
+                self.swap_rows(p, r);
+                det = ModInt::new(0) - det;
+            }
+            det = det * self[(r, col)];
+
+            // Scale the pivot row to a leading one, then clear the column
+            // everywhere else. Entries left of `col` are already zero.
+            let inv = self[(r, col)].inv();
+            let pivot_row: Vec<ModInt> = self.data[r * width + col..(r + 1) * width].iter().map(|&x| x * inv).collect();
+            self.data[r * width + col..(r + 1) * width].copy_from_slice(&pivot_row);
+            for (i, row) in self.data.chunks_exact_mut(width).enumerate() {
+                let factor = row[col];
+                if i == r || factor.val == 0 {
+                    continue;
+                }
+                for (x, &p) in row[col..].iter_mut().zip(&pivot_row) {
+                    *x = *x - factor * p;
+                }
+            }
+
+            pivots.push(col);
+            if pivots.len() == self.rows {
+                break;
+            }
+        }
+
+        (pivots, det)
+    }
+
+    /// Computes the determinant in `O(n^3)`; zero for singular matrices.
+    pub fn determinant(&self) -> ModInt {
+        assert_eq!(self.rows, self.cols, "determinant of a non-square matrix");
+        let (pivots, det) = self.clone().eliminate(self.cols);
+        if pivots.len() == self.rows { det } else { ModInt::new(0) }
+    }
+
+    /// Returns the reduced row echelon form together with the pivot column
+    /// of each non-zero row; the non-zero rows come first.
+    pub fn rref(&self) -> (Matrix, Vec<usize>) {
+        let mut a = self.clone();
+        let (pivots, _) = a.eliminate(self.cols);
+        (a, pivots)
+    }
+
+    /// Returns the rank, i.e. the dimension of the row (and column) space.
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(self.cols).0.len()
+    }
+
+    /// Returns the inverse of a square matrix, or `None` if it is singular.
+    /// Runs Gauss-Jordan on `[A | I]`, which leaves `[I | A^-1]`.
+    pub fn inverse(&self) -> Option<Matrix> {
+        assert_eq!(self.rows, self.cols, "inverse of a non-square matrix");
+        let n = self.rows;
+        let mut aug = self.augment(&Matrix::identity(n));
+        let (pivots, _) = aug.eliminate(n);
+        if pivots.len() < n {
+            return None;
+        }
+
+        let mut inv = Matrix::zero(n, n);
+        for i in 0..n {
+            inv.data[i * n..(i + 1) * n].copy_from_slice(&aug.row(i)[n..]);
+        }
+        Some(inv)
+    }
+
+    /// Solves `A x = b`. Returns one solution together with a basis of the
+    /// kernel of `A`, so every solution is the particular one plus a linear
+    /// combination of the basis; `None` if the system is inconsistent.
+    pub fn solve(&self, b: &[ModInt]) -> Option<(Vec<ModInt>, Vec<Vec<ModInt>>)> {
+        assert_eq!(self.rows, b.len(), "dimension mismatch");
+        let m = self.cols;
+        let rhs = Matrix { rows: b.len(), cols: 1, data: b.to_vec() };
+        let mut aug = self.augment(&rhs);
+        let (pivots, _) = aug.eliminate(m);
+
+        // A non-zero right-hand side below the pivot rows reads `0 = c`.
+        if (pivots.len()..self.rows).any(|i| aug[(i, m)].val != 0) {
+            return None;
+        }
+
+        // Free variables are zero in the particular solution.
+        let mut solution = vec![ModInt::new(0); m];
+        for (i, &p) in pivots.iter().enumerate() {
+            solution[p] = aug[(i, m)];
+        }
+
+        // Setting one free variable to one determines every pivot variable.
+        let mut is_pivot = vec![false; m];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let kernel = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![ModInt::new(0); m];
+                v[f] = ModInt::new(1);
+                for (i, &p) in pivots.iter().enumerate() {
+                    v[p] = ModInt::new(0) - aug[(i, f)];
+                }
+                v
+            })
+            .collect();
+
+        Some((solution, kernel))
+    }
+}
+
+/// Reads `rows` lines of whitespace-separated integers into a Matrix.
+fn read_matrix<I: Iterator<Item = io::Result<String>>>(lines: &mut I, rows: usize) -> Matrix {
+    let data = (0..rows)
+        .map(|_| {
+            lines
+                .next()
+                .unwrap()
+                .expect("Failed to read a matrix row")
+                .split_whitespace()
+                .map(|s| ModInt::new(s.parse().expect("Failed to parse a matrix entry")))
+                .collect()
+        })
+        .collect();
+    Matrix::from_rows(data)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let a = read_matrix(&mut lines, n);
+
+    // --- Output ---
+    println!("{}", a.determinant().val);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// The determinant by cofactor expansion along the first row.
+    fn cofactor(rows: &[Vec<ModInt>]) -> ModInt {
+        let n = rows.len();
+        if n == 0 {
+            return ModInt::new(1);
+        }
+        let mut det = ModInt::new(0);
+        for j in 0..n {
+            let minor: Vec<Vec<ModInt>> = rows[1..]
+                .iter()
+                .map(|row| row.iter().enumerate().filter(|&(c, _)| c != j).map(|(_, &x)| x).collect())
+                .collect();
+            let term = rows[0][j] * cofactor(&minor);
+            det = if j % 2 == 0 { det + term } else { det - term };
+        }
+        det
+    }
+
+    fn random_rows(rng: &mut Rng, n: usize) -> Vec<Vec<ModInt>> {
+        // Small entries give many zeros and ties; large ones exercise the reduction.
+        let range = if rng.below(2) == 0 { 3 } else { MOD as usize };
+        (0..n).map(|_| (0..n).map(|_| ModInt::new(rng.below(range) as u64)).collect()).collect()
+    }
+
+    #[test]
+    fn random_matrices_match_cofactor_expansion() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..2000 {
+            let n = rng.below(7);
+            let rows = random_rows(&mut rng, n);
+            assert_eq!(Matrix::from_rows(rows.clone()).determinant(), cofactor(&rows), "{:?}", rows);
+        }
+    }
+
+    #[test]
+    fn singular_matrices_have_zero_determinant() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..500 {
+            let n = 2 + rng.below(5);
+            let mut rows = random_rows(&mut rng, n);
+            // Make one row a combination of two others (possibly itself twice).
+            let (a, b, target) = (rng.below(n), rng.below(n), rng.below(n));
+            let (x, y) = (ModInt::new(rng.next()), ModInt::new(rng.next()));
+            let combination: Vec<ModInt> = (0..n).map(|j| x * rows[a][j] + y * rows[b][j]).collect();
+            rows[target] = if a == target || b == target {
+                vec![ModInt::new(0); n]
+            } else {
+                combination
+            };
+            assert_eq!(cofactor(&rows), ModInt::new(0));
+            assert_eq!(Matrix::from_rows(rows).determinant(), ModInt::new(0));
+        }
+    }
+
+    #[test]
+    fn row_swaps_flip_the_sign() {
+        let one = ModInt::new(1);
+        let zero = ModInt::new(0);
+        let minus_one = zero - one;
+        // One swap each: the pivot of column 0 sits in the last row.
+        let swap2 = vec![vec![zero, one], vec![one, zero]];
+        assert_eq!(Matrix::from_rows(swap2).determinant(), minus_one);
+        let anti3 = vec![vec![zero, zero, one], vec![zero, one, zero], vec![one, zero, zero]];
+        assert_eq!(Matrix::from_rows(anti3).determinant(), minus_one);
+
+        // Scaled permutation matrices: the sign is the permutation's parity.
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        for _ in 0..500 {
+            let n = 1 + rng.below(6);
+            let mut perm: Vec<usize> = (0..n).collect();
+            for i in (1..n).rev() {
+                perm.swap(i, rng.below(i + 1));
+            }
+            let scale: Vec<ModInt> = (0..n).map(|_| ModInt::new(1 + rng.below(10) as u64)).collect();
+            let rows: Vec<Vec<ModInt>> = (0..n)
+                .map(|i| (0..n).map(|j| if j == perm[i] { scale[i] } else { zero }).collect())
+                .collect();
+            let inversions = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| perm[i] > perm[j])
+                .count();
+            let product = scale.iter().fold(one, |acc, &x| acc * x);
+            let expected = if inversions % 2 == 0 { product } else { zero - product };
+            assert_eq!(cofactor(&rows), expected);
+            assert_eq!(Matrix::from_rows(rows).determinant(), expected);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::{self, BufRead};
+
+/// Represents an undirected graph on at most 64 nodes with bitmask adjacency.
+#[derive(Debug)]
+struct Graph {
+    /// Bit `j` of `adj[i]` is set when nodes `i` and `j` are adjacent.
+    adj: Vec<u64>,
+}
+
+impl Graph {
+    /// Creates a new Graph from a given size and a list of edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        assert!(size <= 64, "bitmask adjacency supports at most 64 nodes");
+        let mut adj = vec![0u64; size];
+        for &(u, v) in edges {
+            if u != v {
+                adj[u] |= 1 << v;
+                adj[v] |= 1 << u;
+            }
+        }
+
+        Self { adj }
+    }
+
+    /// Returns a maximum independent set as a bitmask.
+    pub fn maximum_independent_set(&self) -> u64 {
+        let all = if self.adj.len() == 64 { u64::MAX } else { (1u64 << self.adj.len()) - 1 };
+        self.mis_recursive(all)
+    }
+
+    /// Branch and bound over the nodes still available in `mask`.
+    ///
+    /// Nodes of degree at most one can always be taken greedily. Otherwise we
+    /// branch on a node of maximum degree `d`: either it is excluded, or it is
+    /// taken and all its neighbours go away. With `d >= 3` this gives the
+    /// `T(n) = T(n - 1) + T(n - 4)` recurrence, i.e. about `1.38^n` calls.
+    fn mis_recursive(&self, mut mask: u64) -> u64 {
+        let mut taken = 0;
+
+        // Greedily take nodes with at most one available neighbour.
+        loop {
+            let low = Self::nodes(mask).find(|&v| (self.adj[v] & mask).count_ones() <= 1);
+            match low {
+                Some(v) => {
+                    taken |= 1 << v;
+                    mask &= !(self.adj[v] | 1 << v);
+                }
+                None => break,
+            }
+        }
+        if mask == 0 {
+            return taken;
+        }
+
+        let v = Self::nodes(mask)
+            .max_by_key(|&v| (self.adj[v] & mask).count_ones())
+            .unwrap();
+
+        let with_v = self.mis_recursive(mask & !(self.adj[v] | 1 << v)) | 1 << v;
+        // Excluding `v` only helps if the remaining nodes can beat the other branch.
+        let without_v = if (mask & !(1 << v)).count_ones() > with_v.count_ones() {
+            self.mis_recursive(mask & !(1 << v))
+        } else {
+            0
+        };
+
+        taken | if with_v.count_ones() >= without_v.count_ones() { with_v } else { without_v }
+    }
+
+    /// Iterates over the nodes whose bits are set in `mask`.
+    fn nodes(mut mask: u64) -> impl Iterator<Item = usize> {
+        std::iter::from_fn(move || {
+            if mask == 0 {
+                return None;
+            }
+            let v = mask.trailing_zeros() as usize;
+            mask &= mask - 1;
+            Some(v)
+        })
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+
+    let edges: Vec<(usize, usize)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    // --- Output ---
+    let set = Graph::new(n, &edges).maximum_independent_set();
+    let nodes: Vec<String> = Graph::nodes(set).map(|v| v.to_string()).collect();
+    println!("{}", nodes.len());
+    println!("{}", nodes.join(" "));
+}
@@ -0,0 +1,418 @@
+use std::io::{self, BufRead};
+
+const NONE: u32 = u32::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct SkewNode {
+    key: i64,
+    value: usize,
+    /// Pending addition for both children's subtrees; `key` is already up to date.
+    lazy: i64,
+    left: u32,
+    right: u32,
+}
+
+/// Meldable min-heaps of `(key, value)` pairs by skew heaps, every node in
+/// one arena. A heap is named by its root, `NONE` when empty; `meld`, `push`
+/// and `pop` consume their heaps and return the root of the result. Adding
+/// a constant to every key of a heap is lazy and `O(1)`.
+///
+/// Skew heaps keep no balance information: a meld walks both right spines
+/// and swaps the children of every node it passes, which is `O(log n)`
+/// amortized. The spines can be long, so the meld is iterative.
+#[derive(Clone, Debug, Default)]
+pub struct SkewHeap {
+    nodes: Vec<SkewNode>,
+}
+
+impl SkewHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// Adds `delta` to every key of the heap at `root`.
+    pub fn add(&mut self, root: u32, delta: i64) {
+        if root != NONE {
+            let node = &mut self.nodes[root as usize];
+            node.key += delta;
+            node.lazy += delta;
+        }
+    }
+
+    fn push_down(&mut self, v: u32) {
+        let SkewNode { lazy, left, right, .. } = self.nodes[v as usize];
+        if lazy != 0 {
+            self.add(left, lazy);
+            self.add(right, lazy);
+            self.nodes[v as usize].lazy = 0;
+        }
+    }
+
+    pub fn meld(&mut self, mut a: u32, mut b: u32) -> u32 {
+        // Merge the right spines top-down, then swap children bottom-up.
+        let mut spine = Vec::new();
+        while a != NONE && b != NONE {
+            if self.nodes[a as usize].key > self.nodes[b as usize].key {
+                std::mem::swap(&mut a, &mut b);
+            }
+            self.push_down(a);
+            spine.push(a);
+            a = self.nodes[a as usize].right;
+        }
+        let mut root = if a == NONE { b } else { a };
+        while let Some(v) = spine.pop() {
+            let node = &mut self.nodes[v as usize];
+            node.right = node.left;
+            node.left = root;
+            root = v;
+        }
+        root
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(SkewNode { key, value, lazy: 0, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        self.push_down(root);
+        let SkewNode { left, right, .. } = self.nodes[root as usize];
+        self.meld(left, right)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LeftistNode {
+    key: i64,
+    value: usize,
+    /// The length of the right spine; never more than the left child's.
+    rank: u32,
+    left: u32,
+    right: u32,
+}
+
+/// Persistent meldable min-heaps of `(key, value)` pairs by leftist heaps,
+/// every node in one arena. A heap is named by its root, `NONE` when empty,
+/// and is never modified: `meld`, `push` and `pop` copy the nodes they
+/// change and return a new root, so every earlier version stays valid and
+/// shares the rest of its nodes.
+///
+/// The right spine of a leftist heap has `O(log n)` nodes, and a meld only
+/// walks the two right spines, so every operation copies `O(log n)` nodes
+/// in the worst case. That bound survives persistence, where the amortized
+/// bounds of skew or pairing heaps do not.
+#[derive(Clone, Debug, Default)]
+pub struct PersistentHeap {
+    nodes: Vec<LeftistNode>,
+}
+
+impl PersistentHeap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The minimum `(key, value)` of the heap at `root`.
+    pub fn top(&self, root: u32) -> Option<(i64, usize)> {
+        self.nodes.get(root as usize).map(|node| (node.key, node.value))
+    }
+
+    /// The two subheaps under `root`, which together hold everything but its top.
+    pub fn children(&self, root: u32) -> [u32; 2] {
+        let node = &self.nodes[root as usize];
+        [node.left, node.right]
+    }
+
+    fn rank(&self, v: u32) -> u32 {
+        self.nodes.get(v as usize).map_or(0, |node| node.rank)
+    }
+
+    pub fn meld(&mut self, a: u32, b: u32) -> u32 {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        let (a, b) = if self.nodes[a as usize].key <= self.nodes[b as usize].key { (a, b) } else { (b, a) };
+        let mut node = self.nodes[a as usize];
+        node.right = self.meld(node.right, b);
+        if self.rank(node.left) < self.rank(node.right) {
+            std::mem::swap(&mut node.left, &mut node.right);
+        }
+        node.rank = self.rank(node.right) + 1;
+        self.nodes.push(node);
+        (self.nodes.len() - 1) as u32
+    }
+
+    pub fn push(&mut self, root: u32, key: i64, value: usize) -> u32 {
+        self.nodes.push(LeftistNode { key, value, rank: 1, left: NONE, right: NONE });
+        let single = (self.nodes.len() - 1) as u32;
+        self.meld(root, single)
+    }
+
+    /// Removes the minimum of the non-empty heap at `root`.
+    pub fn pop(&mut self, root: u32) -> u32 {
+        let [left, right] = self.children(root);
+        self.meld(left, right)
+    }
+
+    /// A heap of `items` in `O(n)` by melding them pairwise in rounds.
+    pub fn build(&mut self, items: impl IntoIterator<Item = (i64, usize)>) -> u32 {
+        let mut queue: std::collections::VecDeque<u32> =
+            items.into_iter().map(|(key, value)| self.push(NONE, key, value)).collect();
+        while queue.len() > 1 {
+            let (a, b) = (queue.pop_front().unwrap(), queue.pop_front().unwrap());
+            let melded = self.meld(a, b);
+            queue.push_back(melded);
+        }
+        queue.pop_front().unwrap_or(NONE)
+    }
+}
+
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `Q`, then `Q` queries on versions of a heap, version 0 being empty;
+    // query `i` creates version `i`:
+    // `0 v x` pushes `x` onto version `v`, `1 v` pops the minimum of version
+    // `v`, and `2 v u` melds versions `v` and `u`.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let q: usize = first_line.trim().parse().expect("Failed to parse q");
+
+    // --- Query Processing and Output ---
+    // The minimum popped by every `1` query, `-1` if the version was empty.
+    let mut heaps = PersistentHeap::new();
+    let mut versions = vec![NONE];
+    let mut output = Vec::new();
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let kind: u8 = parts.next().unwrap().parse().expect("Failed to parse the query type");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        let root = versions[v];
+        let next = match kind {
+            0 => {
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                heaps.push(root, x, versions.len())
+            }
+            1 => match heaps.top(root) {
+                Some((x, _)) => {
+                    output.push(x.to_string());
+                    heaps.pop(root)
+                }
+                None => {
+                    output.push("-1".to_string());
+                    root
+                }
+            },
+            _ => {
+                let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+                heaps.meld(root, versions[u])
+            }
+        };
+        versions.push(next);
+    }
+    println!("{}", output.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// Checks the top of a heap against a model multiset of `(key, value)`
+    /// pairs: the key must be the minimum and the pair must be present.
+    /// Returns the index of that pair in the model.
+    fn check_top(top: Option<(i64, usize)>, model: &[(i64, usize)]) -> Option<usize> {
+        let min = model.iter().map(|&(key, _)| key).min();
+        assert_eq!(top.map(|(key, _)| key), min);
+        top.map(|pair| model.iter().position(|&p| p == pair).expect("top is not in the heap"))
+    }
+
+    /// Every `(key, value)` of the persistent heap at `root`, popped in order.
+    fn drain(heaps: &mut PersistentHeap, mut root: u32) -> Vec<(i64, usize)> {
+        let mut out = Vec::new();
+        while let Some(top) = heaps.top(root) {
+            out.push(top);
+            root = heaps.pop(root);
+        }
+        out
+    }
+
+    fn sorted_keys(items: &[(i64, usize)]) -> Vec<i64> {
+        let mut keys: Vec<i64> = items.iter().map(|&(key, _)| key).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    #[test]
+    fn skew_heap_matches_model() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let mut heaps = SkewHeap::new();
+            let mut roots = [NONE; 5];
+            let mut models: Vec<Vec<(i64, usize)>> = vec![Vec::new(); 5];
+            for step in 0..200 {
+                let i = rng.below(5);
+                match rng.below(4) {
+                    0 => {
+                        // Few distinct keys, so ties are common.
+                        let key = rng.below(10) as i64;
+                        roots[i] = heaps.push(roots[i], key, step);
+                        models[i].push((key, step));
+                    }
+                    1 => {
+                        let j = rng.below(5);
+                        if i != j {
+                            roots[i] = heaps.meld(roots[i], roots[j]);
+                            roots[j] = NONE;
+                            let moved = std::mem::take(&mut models[j]);
+                            models[i].extend(moved);
+                        }
+                    }
+                    2 => {
+                        if let Some(at) = check_top(heaps.top(roots[i]), &models[i]) {
+                            roots[i] = heaps.pop(roots[i]);
+                            models[i].swap_remove(at);
+                        }
+                    }
+                    _ => {
+                        let delta = rng.below(11) as i64 - 5;
+                        heaps.add(roots[i], delta);
+                        models[i].iter_mut().for_each(|(key, _)| *key += delta);
+                    }
+                }
+                for (&root, model) in roots.iter().zip(&models) {
+                    check_top(heaps.top(root), model);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn skew_heap_long_spines() {
+        // Sorted pushes build long right spines, which the iterative meld
+        // must walk without recursing.
+        let mut heaps = SkewHeap::new();
+        let mut root = NONE;
+        for key in (0..200_000).rev().chain(200_000..400_000) {
+            root = heaps.push(root, key, key as usize);
+        }
+        heaps.add(root, 7);
+        for key in 0..400_000 {
+            assert_eq!(heaps.top(root), Some((key + 7, key as usize)));
+            root = heaps.pop(root);
+        }
+        assert_eq!(root, NONE);
+    }
+
+    #[test]
+    fn persistent_heap_versions_stay_valid() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..100 {
+            let mut heaps = PersistentHeap::new();
+            let mut roots = vec![NONE];
+            let mut models: Vec<Vec<(i64, usize)>> = vec![Vec::new()];
+            for step in 0..150 {
+                // Every operation works on an arbitrary old version.
+                let v = rng.below(roots.len());
+                let (root, mut model) = (roots[v], models[v].clone());
+                let next = match rng.below(3) {
+                    0 => {
+                        let key = rng.below(10) as i64;
+                        model.push((key, step));
+                        heaps.push(root, key, step)
+                    }
+                    1 => match check_top(heaps.top(root), &model) {
+                        Some(at) => {
+                            model.swap_remove(at);
+                            heaps.pop(root)
+                        }
+                        None => root,
+                    },
+                    _ => {
+                        let u = rng.below(roots.len());
+                        model.extend(&models[u]);
+                        heaps.meld(root, roots[u])
+                    }
+                };
+                roots.push(next);
+                models.push(model);
+            }
+            for (&root, model) in roots.iter().zip(&models) {
+                let drained = drain(&mut heaps, root);
+                assert_eq!(sorted_keys(&drained), sorted_keys(model));
+            }
+        }
+    }
+
+    #[test]
+    fn old_roots_survive_pop() {
+        let mut heaps = PersistentHeap::new();
+        let mut root = NONE;
+        for (i, key) in [5, 1, 4, 1, 3].into_iter().enumerate() {
+            root = heaps.push(root, key, i);
+        }
+        let popped = heaps.pop(root);
+        let popped_twice = heaps.pop(popped);
+        assert_eq!(sorted_keys(&drain(&mut heaps, root)), vec![1, 1, 3, 4, 5]);
+        assert_eq!(sorted_keys(&drain(&mut heaps, popped)), vec![1, 3, 4, 5]);
+        assert_eq!(sorted_keys(&drain(&mut heaps, popped_twice)), vec![3, 4, 5]);
+        // Draining pops again; the originals are still intact.
+        assert_eq!(drain(&mut heaps, root).len(), 5);
+        assert_eq!(heaps.top(root).map(|(key, _)| key), Some(1));
+    }
+
+    #[test]
+    fn persistent_build_is_leftist() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        for _ in 0..200 {
+            let items: Vec<(i64, usize)> = (0..rng.below(100)).map(|i| (rng.below(50) as i64, i)).collect();
+            let mut heaps = PersistentHeap::new();
+            let root = heaps.build(items.iter().copied());
+            // Heap order, and a right spine no longer than the left one, everywhere.
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                if v == NONE {
+                    continue;
+                }
+                let [left, right] = heaps.children(v);
+                assert!(heaps.rank(left) >= heaps.rank(right));
+                assert_eq!(heaps.rank(v), heaps.rank(right) + 1);
+                for child in [left, right] {
+                    if let Some((key, _)) = heaps.top(child) {
+                        assert!(heaps.top(v).unwrap().0 <= key);
+                    }
+                }
+                stack.extend([left, right]);
+            }
+            assert_eq!(sorted_keys(&drain(&mut heaps, root)), sorted_keys(&items));
+        }
+    }
+}
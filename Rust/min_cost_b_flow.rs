@@ -0,0 +1,217 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+#[derive(Clone, Copy, Debug)]
+struct Arc {
+    from: usize,
+    to: usize,
+    /// Residual capacity.
+    cap: i64,
+    cost: i64,
+}
+
+/// Minimum cost b-flow: every vertex `v` must send out `b_v` more units than
+/// it receives (a demand when negative), and every edge carries a flow
+/// between its lower and upper bounds. Costs may be negative.
+///
+/// Edge `i` is arc `2i` of the residual graph and its reverse is `2i + 1`,
+/// so `arcs[e ^ 1]` undoes `arcs[e]`.
+#[derive(Clone, Debug, Default)]
+pub struct MinCostFlow {
+    arcs: Vec<Arc>,
+    lower: Vec<i64>,
+    /// `adj[v]` lists the residual arcs out of `v`.
+    adj: Vec<Vec<usize>>,
+    /// Units `v` still has to send out; all zero once the flow is feasible.
+    excess: Vec<i64>,
+    potential: Vec<i64>,
+}
+
+impl MinCostFlow {
+    pub fn new(n: usize) -> Self {
+        Self { adj: vec![Vec::new(); n], excess: vec![0; n], potential: vec![0; n], ..Self::default() }
+    }
+
+    /// Adds `b` to the supply of `v`.
+    pub fn add_supply(&mut self, v: usize, b: i64) {
+        self.excess[v] += b;
+    }
+
+    /// Adds an edge whose flow must lie in `[lower, upper]` and returns its index.
+    pub fn add_edge(&mut self, from: usize, to: usize, lower: i64, upper: i64, cost: i64) -> usize {
+        assert!(lower <= upper, "Empty capacity range");
+        let i = self.lower.len();
+        // The flow starts at the lower bound, so only the slack is residual.
+        self.arcs.push(Arc { from, to, cap: upper - lower, cost });
+        self.arcs.push(Arc { from: to, to: from, cap: 0, cost: -cost });
+        self.adj[from].push(2 * i);
+        self.adj[to].push(2 * i + 1);
+        self.lower.push(lower);
+        self.excess[from] -= lower;
+        self.excess[to] += lower;
+        i
+    }
+
+    /// The flow on edge `i`.
+    pub fn flow(&self, i: usize) -> i64 {
+        self.lower[i] + self.arcs[2 * i + 1].cap
+    }
+
+    fn push(&mut self, e: usize, amount: i64) {
+        let Arc { from, to, .. } = self.arcs[e];
+        self.arcs[e].cap -= amount;
+        self.arcs[e ^ 1].cap += amount;
+        self.excess[from] -= amount;
+        self.excess[to] += amount;
+    }
+
+    fn reduced_cost(&self, e: usize) -> i64 {
+        let Arc { from, to, cost, .. } = self.arcs[e];
+        cost + self.potential[from] - self.potential[to]
+    }
+
+    /// Finds a minimum cost b-flow and returns its cost, or `None` if no
+    /// feasible flow exists.
+    ///
+    /// Successive shortest paths with capacity scaling, in
+    /// `O(m^2 log n log U)`. Phase `delta` only uses arcs with at least
+    /// `delta` residual capacity: it first saturates those of negative
+    /// reduced cost, which also disposes of negative edge costs, then sends
+    /// flow from vertices with excess at least `delta` to vertices with
+    /// deficit at least `delta` along shortest paths found by Dijkstra.
+    /// The potentials keep every reduced cost non-negative.
+    pub fn solve(&mut self) -> Option<i128> {
+        let n = self.adj.len();
+        if self.excess.iter().map(|&b| b as i128).sum::<i128>() != 0 {
+            return None;
+        }
+        let max = self.excess.iter().map(|b| b.abs()).chain(self.arcs.iter().map(|a| a.cap)).max().unwrap_or(0);
+        let mut delta = if max == 0 { 0 } else { 1 << (63 - max.leading_zeros()) };
+        let mut dist = vec![i64::MAX; n];
+        let mut prev = vec![usize::MAX; n];
+        let mut settled = vec![false; n];
+        while delta > 0 {
+            for e in 0..self.arcs.len() {
+                let cap = self.arcs[e].cap;
+                if cap >= delta && self.reduced_cost(e) < 0 {
+                    self.push(e, cap);
+                }
+            }
+            loop {
+                dist.fill(i64::MAX);
+                settled.fill(false);
+                let mut pq = BinaryHeap::new();
+                for (v, &b) in self.excess.iter().enumerate() {
+                    if b >= delta {
+                        dist[v] = 0;
+                        pq.push(Reverse((0, v)));
+                    }
+                }
+                let mut target = None;
+                while let Some(Reverse((d, v))) = pq.pop() {
+                    if settled[v] {
+                        continue;
+                    }
+                    settled[v] = true;
+                    if self.excess[v] <= -delta {
+                        target = Some(v);
+                        break;
+                    }
+                    for &e in &self.adj[v] {
+                        let to = self.arcs[e].to;
+                        if self.arcs[e].cap >= delta && !settled[to] {
+                            let candidate = d + self.reduced_cost(e);
+                            if candidate < dist[to] {
+                                dist[to] = candidate;
+                                prev[to] = e;
+                                pq.push(Reverse((candidate, to)));
+                            }
+                        }
+                    }
+                }
+                let Some(t) = target else {
+                    break;
+                };
+
+                // Vertices not settled are at least as far as `t`.
+                for (p, (&d, &done)) in self.potential.iter_mut().zip(dist.iter().zip(&settled)) {
+                    *p += if done { d } else { dist[t] };
+                }
+                // Walk back to the first vertex with excess at least `delta`.
+                let mut path = Vec::new();
+                let mut v = t;
+                while dist[v] != 0 || self.excess[v] < delta {
+                    path.push(prev[v]);
+                    v = self.arcs[prev[v]].from;
+                }
+                let amount = path.iter().map(|&e| self.arcs[e].cap).fold(self.excess[v].min(-self.excess[t]), i64::min);
+                for e in path {
+                    self.push(e, amount);
+                }
+            }
+            delta >>= 1;
+        }
+        if self.excess.iter().any(|&b| b != 0) {
+            return None;
+        }
+        Some((0..self.lower.len()).map(|i| self.flow(i) as i128 * self.arcs[2 * i].cost as i128).sum())
+    }
+
+    /// Dual potentials certifying the flow found by `solve` optimal:
+    /// `cost + p[from] - p[to]` is non-negative on every edge whose flow is
+    /// below its upper bound and non-positive on every edge whose flow is
+    /// above its lower bound. These are shortest distances in the residual
+    /// graph from a virtual source, by Bellman-Ford, so `|p| <= n max|cost|`.
+    pub fn potentials(&self) -> Vec<i64> {
+        let mut p = vec![0; self.adj.len()];
+        loop {
+            let mut changed = false;
+            for arc in &self.arcs {
+                if arc.cap > 0 && p[arc.from] + arc.cost < p[arc.to] {
+                    p[arc.to] = p[arc.from] + arc.cost;
+                    changed = true;
+                }
+            }
+            if !changed {
+                return p;
+            }
+        }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let mut flow = MinCostFlow::new(n);
+    for v in 0..n {
+        let line = lines.next().unwrap().expect("Failed to read a supply");
+        flow.add_supply(v, line.trim().parse().expect("Failed to parse b"));
+    }
+    for _ in 0..m {
+        let line = lines.next().unwrap().expect("Failed to read an edge line");
+        let mut parts = line.split_whitespace();
+        let mut next = || -> i64 { parts.next().unwrap().parse().expect("Failed to parse an edge") };
+        let (s, t, l, u, c) = (next() as usize, next() as usize, next(), next(), next());
+        flow.add_edge(s, t, l, u, c);
+    }
+
+    // --- Output ---
+    // The cost, then a potential per vertex, then the flow on every edge.
+    match flow.solve() {
+        None => println!("infeasible"),
+        Some(cost) => {
+            let mut output = vec![cost.to_string()];
+            output.extend(flow.potentials().iter().map(|p| p.to_string()));
+            output.extend((0..m).map(|i| flow.flow(i).to_string()));
+            println!("{}", output.join("\n"));
+        }
+    }
+}
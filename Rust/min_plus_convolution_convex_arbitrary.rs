@@ -0,0 +1,239 @@
+use std::io::{self, BufRead};
+
+/// The leftmost minimum column of every row of an `h x w` totally monotone
+/// matrix, by divide and conquer in `O((h + w) log h)` comparisons.
+///
+/// The matrix is given through `select(i, j, k)` for `j < k`: whether
+/// column `k` is strictly better than column `j` in row `i`. This lets the
+/// caller encode infinite entries without materializing them. Monotone
+/// means the argmin never moves left from one row to the next: the argmin of
+/// the middle row splits the columns between the upper and lower halves.
+pub fn monotone_minima<F: Fn(usize, usize, usize) -> bool>(h: usize, w: usize, select: F) -> Vec<usize> {
+    fn rec<F: Fn(usize, usize, usize) -> bool>(rows: std::ops::Range<usize>, left: usize, right: usize, select: &F, ans: &mut [usize]) {
+        if rows.is_empty() {
+            return;
+        }
+        let mid = rows.start + rows.len() / 2;
+        let mut best = left;
+        for k in left + 1..=right {
+            if select(mid, best, k) {
+                best = k;
+            }
+        }
+        ans[mid] = best;
+        rec(rows.start..mid, left, best, select, ans);
+        rec(mid + 1..rows.end, best, right, select, ans);
+    }
+
+    let mut ans = vec![0; h];
+    if w > 0 {
+        rec(0..h, 0, w - 1, &select, &mut ans);
+    }
+    ans
+}
+
+/// The leftmost minimum column of every row of an `h x w` totally monotone
+/// matrix by the SMAWK algorithm, in `O(h + w)` calls of `select` (see
+/// `monotone_minima`).
+///
+/// REDUCE keeps at most one candidate column per row, dropping any column
+/// that total monotonicity proves useless; the odd rows are then solved
+/// recursively on the survivors, and the minimum of each even row lies
+/// between the answers of its neighbours, so one left-to-right pass fills
+/// them in.
+pub fn smawk<F: Fn(usize, usize, usize) -> bool>(h: usize, w: usize, select: F) -> Vec<usize> {
+    fn solve<F: Fn(usize, usize, usize) -> bool>(rows: &[usize], cols: &[usize], select: &F) -> Vec<usize> {
+        let n = rows.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // REDUCE: the `t`-th surviving column is still a candidate for row `t`.
+        let mut survivors: Vec<usize> = Vec::with_capacity(n);
+        for &c in cols {
+            while let Some(&top) = survivors.last() {
+                if !select(rows[survivors.len() - 1], top, c) {
+                    break;
+                }
+                survivors.pop();
+            }
+            if survivors.len() < n {
+                survivors.push(c);
+            }
+        }
+
+        let odd_rows: Vec<usize> = rows.iter().skip(1).step_by(2).copied().collect();
+        let odd_ans = solve(&odd_rows, &survivors, select);
+
+        let mut ans = vec![0; n];
+        for (i, &c) in odd_ans.iter().enumerate() {
+            ans[2 * i + 1] = c;
+        }
+        // INTERPOLATE: scan the survivors up to the answer of the next row.
+        let mut j = 0;
+        for i in (0..n).step_by(2) {
+            ans[i] = survivors[j];
+            let end = if i + 1 == n { survivors[survivors.len() - 1] } else { ans[i + 1] };
+            while survivors[j] != end {
+                j += 1;
+                if select(rows[i], ans[i], survivors[j]) {
+                    ans[i] = survivors[j];
+                }
+            }
+        }
+        ans
+    }
+
+    if w == 0 {
+        return vec![0; h];
+    }
+    let rows: Vec<usize> = (0..h).collect();
+    let cols: Vec<usize> = (0..w).collect();
+    solve(&rows, &cols, &select)
+}
+
+/// `c_k = min_{i + j = k} a_i + b_j` for convex `a` and arbitrary `b`.
+///
+/// In the matrix `M[k][j] = a_{k - j} + b_j`, entries with `k - j` outside
+/// `a` are infinite, and the convexity of `a` makes it totally monotone, so
+/// SMAWK finds every row minimum in `O(n + m)`.
+pub fn min_plus_convolution_convex_arbitrary(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return Vec::new();
+    }
+    let value = |k: usize, j: usize| a[k - j] + b[j];
+    // For `j < k`: column `k` is out of range above the band, column `j`
+    // below it, and otherwise the finite values decide.
+    let select = |i: usize, j: usize, k: usize| {
+        if i < k {
+            return false;
+        }
+        if i - j >= n {
+            return true;
+        }
+        value(i, j) > value(i, k)
+    };
+    let argmin = smawk(n + m - 1, m, select);
+    argmin.iter().enumerate().map(|(k, &j)| value(k, j)).collect()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let mut read_values = |len: usize| -> Vec<i64> {
+        let line = lines.next().unwrap().expect("Failed to read a sequence");
+        let values: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a value")).collect();
+        assert_eq!(values.len(), len);
+        values
+    };
+    let a = read_values(n);
+    let b = read_values(m);
+
+    // --- Output ---
+    let c = min_plus_convolution_convex_arbitrary(&a, &b);
+    let output: Vec<String> = c.iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// A random `h x w` Monge matrix: minus the 2D prefix sums of a
+    /// non-negative density, plus arbitrary row and column terms. Small
+    /// values make ties common, which checks that minima are leftmost.
+    fn random_monge(rng: &mut Rng, h: usize, w: usize) -> Vec<Vec<i64>> {
+        let mut m = vec![vec![0i64; w]; h];
+        for i in 0..h {
+            for j in 0..w {
+                let density = if rng.below(3) == 0 { rng.below(4) as i64 } else { 0 };
+                let up = if i > 0 { m[i - 1][j] } else { 0 };
+                let left = if j > 0 { m[i][j - 1] } else { 0 };
+                let corner = if i > 0 && j > 0 { m[i - 1][j - 1] } else { 0 };
+                m[i][j] = up + left - corner - density;
+            }
+        }
+        let col: Vec<i64> = (0..w).map(|_| rng.below(7) as i64 - 3).collect();
+        for row in m.iter_mut() {
+            let shift = rng.below(7) as i64 - 3;
+            for (x, c) in row.iter_mut().zip(&col) {
+                *x += shift + c;
+            }
+        }
+        m
+    }
+
+    fn leftmost_argmins(m: &[Vec<i64>], w: usize) -> Vec<usize> {
+        m.iter().map(|row| (0..w).min_by_key(|&j| (row[j], j)).unwrap_or(0)).collect()
+    }
+
+    #[test]
+    fn random_monge_matrices_match_brute_force() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..3000 {
+            let (h, w) = (rng.below(20), rng.below(20));
+            let m = random_monge(&mut rng, h, w);
+            for i in 0..h.saturating_sub(1) {
+                for j in 0..w.saturating_sub(1) {
+                    assert!(m[i][j] + m[i + 1][j + 1] <= m[i][j + 1] + m[i + 1][j]);
+                }
+            }
+            let select = |i: usize, j: usize, k: usize| m[i][k] < m[i][j];
+            let expected = leftmost_argmins(&m, w);
+            assert_eq!(monotone_minima(h, w, select), expected, "{:?}", m);
+            assert_eq!(smawk(h, w, select), expected, "{:?}", m);
+        }
+    }
+
+    #[test]
+    fn convolution_matches_brute_force() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let (n, m) = (rng.below(15), rng.below(15));
+            // Convex: non-decreasing differences from a random start.
+            let mut diffs: Vec<i64> = (0..n).map(|_| rng.below(21) as i64 - 10).collect();
+            diffs.sort_unstable();
+            let start = rng.below(100) as i64;
+            let a: Vec<i64> = diffs
+                .iter()
+                .scan(start, |acc, &d| {
+                    *acc += d;
+                    Some(*acc)
+                })
+                .collect();
+            let b: Vec<i64> = (0..m).map(|_| rng.below(41) as i64 - 20).collect();
+            let len = if n == 0 || m == 0 { 0 } else { n + m - 1 };
+            let mut expected = vec![i64::MAX; len];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    expected[i + j] = expected[i + j].min(x + y);
+                }
+            }
+            assert_eq!(min_plus_convolution_convex_arbitrary(&a, &b), expected, "{:?} {:?}", a, b);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly prime with primitive root 3.
+const MOD: u64 = 998244353;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    base %= MOD;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MOD;
+        }
+        base = base * base % MOD;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `MOD` of length
+/// `a.len()` (a power of two).
+fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (MOD - 1) / len as u64);
+        if invert {
+            w_len = pow_mod(w_len, MOD - 2);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % MOD;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % MOD;
+                *x = if u + v >= MOD { u + v - MOD } else { u + v };
+                *y = if u >= v { u - v } else { u + MOD - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, MOD - 2);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % MOD;
+        }
+    }
+}
+
+/// The product of `f` and `g` as polynomials in `k = dims.len()` variables,
+/// where variable `j` is truncated below degree `dims[j]`. Both inputs and
+/// the output are flattened with the first variable varying fastest, so
+/// index `i` has digits `i_j` in the mixed radix `dims`.
+///
+/// A flat sum `i + i' < N` is a genuine term exactly when no digit carries.
+/// Let `chi(i) = sum_j floor(i / P_j) mod k` over the place values `P_j`
+/// of the digits after the first. Each `floor(i / P_j)` is additive except
+/// that a carry into digit `j` adds one, so `chi(i + i') - chi(i) - chi(i')`
+/// counts the carries, which are fewer than `k`. Splitting `f` and `g` by
+/// `chi` and convolving over `t` modulo `t^k - 1` therefore keeps exactly
+/// the terms without carries, in `O(k N log N + k^2 N)`.
+pub fn multivariate_convolution(f: &[u64], g: &[u64], dims: &[usize]) -> Vec<u64> {
+    let n: usize = dims.iter().product();
+    let k = dims.len();
+    if k == 0 {
+        return vec![f[0] * g[0] % MOD];
+    }
+    let chi: Vec<usize> = (0..n)
+        .map(|i| {
+            let (mut rest, mut sum) = (i, 0);
+            for &d in dims {
+                rest /= d;
+                sum += rest;
+            }
+            sum % k
+        })
+        .collect();
+
+    // Forward transforms of every `chi` class of both inputs.
+    let size = (2 * n - 1).next_power_of_two();
+    let split = |a: &[u64]| -> Vec<Vec<u64>> {
+        (0..k)
+            .map(|c| {
+                let mut part = vec![0; size];
+                for i in (0..n).filter(|&i| chi[i] == c) {
+                    part[i] = a[i] % MOD;
+                }
+                ntt(&mut part, false);
+                part
+            })
+            .collect()
+    };
+    let (fs, gs) = (split(f), split(g));
+
+    // Cyclic convolution over the `chi` classes at every evaluation point.
+    // Products are below `MOD^2`, and sums are kept below `17 MOD^2 < 2^64`
+    // by subtracting `16 MOD^2` instead of reducing every product.
+    const CAP: u64 = 16 * MOD * MOD;
+    let mut hs = vec![vec![0; size]; k];
+    let (mut x, mut y, mut acc) = (vec![0u64; k], vec![0u64; k], vec![0u64; k]);
+    for p in 0..size {
+        for c in 0..k {
+            (x[c], y[c]) = (fs[c][p], gs[c][p]);
+        }
+        acc.iter_mut().for_each(|v| *v = 0);
+        for (a, &xa) in x.iter().enumerate() {
+            let (head, tail) = y.split_at(k - a);
+            let (wrapped, shifted) = acc.split_at_mut(a);
+            for (v, &yb) in shifted.iter_mut().zip(head).chain(wrapped.iter_mut().zip(tail)) {
+                *v += xa * yb;
+                if *v >= CAP {
+                    *v -= CAP;
+                }
+            }
+        }
+        for c in 0..k {
+            hs[c][p] = acc[c] % MOD;
+        }
+    }
+    for h in hs.iter_mut() {
+        ntt(h, true);
+    }
+    (0..n).map(|i| hs[chi[i]][i]).collect()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let k: usize = first_line.trim().parse().expect("Failed to parse k");
+    let dims_line = lines.next().unwrap().expect("Failed to read the dimensions");
+    let dims: Vec<usize> = dims_line.split_whitespace().map(|s| s.parse().expect("Failed to parse a dimension")).collect();
+    assert_eq!(dims.len(), k);
+    let mut read_values = || -> Vec<u64> {
+        let line = lines.next().unwrap().expect("Failed to read a coefficient line");
+        line.split_whitespace().map(|s| s.parse().expect("Failed to parse a coefficient")).collect()
+    };
+    let f = read_values();
+    let g = read_values();
+
+    // --- Output ---
+    let output: Vec<String> = multivariate_convolution(&f, &g, &dims).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
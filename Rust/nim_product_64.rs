@@ -0,0 +1,86 @@
+use std::io::{self, BufRead};
+
+/// One level of the nimber multiplication recursion.
+///
+/// Nimbers below `2^{2h}` (with `h` a power of two) are pairs `a1 F + a0` of
+/// nimbers below `F = 2^h`, and `F^2 = F + F / 2` in nim arithmetic. So
+/// `(a1 F + a0)(b1 F + b0) = (a0 b1 + a1 b0 + a1 b1) F + a0 b0 + a1 b1 (F / 2)`,
+/// where the cross term comes Karatsuba-style from a third product
+/// `(a0 + a1)(b0 + b1)`, and nim addition is xor.
+fn split_mul(a: u64, b: u64, h: u32, mul: impl Fn(u64, u64) -> u64) -> u64 {
+    let mask = (1 << h) - 1;
+    let (a1, a0) = (a >> h, a & mask);
+    let (b1, b0) = (b >> h, b & mask);
+    let lo = mul(a0, b0);
+    let hi = mul(a1, b1);
+    let cross = mul(a0 ^ a1, b0 ^ b1) ^ lo ^ hi;
+    (cross ^ hi) << h | lo ^ mul(hi, 1 << (h - 1))
+}
+
+/// Nim product of nimbers below `2^bits`, straight from the recursion.
+fn nim_mul_slow(a: u64, b: u64, bits: u32) -> u64 {
+    if bits == 1 {
+        return a & b;
+    }
+    split_mul(a, b, bits / 2, |x, y| nim_mul_slow(x, y, bits / 2))
+}
+
+/// Nimber multiplication on 64-bit values.
+///
+/// Products of bytes come from a precomputed 256 x 256 table, so a 64-bit
+/// product bottoms out in table lookups after three levels of `split_mul`.
+#[derive(Debug)]
+pub struct NimProduct {
+    table: Vec<u8>,
+}
+
+impl NimProduct {
+    pub fn new() -> Self {
+        let mut table = vec![0; 256 * 256];
+        for a in 0..256 {
+            for b in 0..256 {
+                table[a << 8 | b] = nim_mul_slow(a as u64, b as u64, 8) as u8;
+            }
+        }
+        Self { table }
+    }
+
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.mul_bits(a, b, 64)
+    }
+
+    fn mul_bits(&self, a: u64, b: u64, bits: u32) -> u64 {
+        if bits == 8 {
+            return self.table[(a << 8 | b) as usize] as u64;
+        }
+        split_mul(a, b, bits / 2, |x, y| self.mul_bits(x, y, bits / 2))
+    }
+}
+
+impl Default for NimProduct {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+    let nim = NimProduct::new();
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let a: u64 = parts.next().unwrap().parse().expect("Failed to parse a");
+        let b: u64 = parts.next().unwrap().parse().expect("Failed to parse b");
+        output.push(nim.mul(a, b).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
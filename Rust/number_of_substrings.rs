@@ -0,0 +1,187 @@
+use std::io::{self, BufRead};
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// A sparse table answering `op` over any range in `O(1)`.
+/// `op` must be idempotent (min, max, gcd, ...) since ranges are covered by
+/// two possibly overlapping power-of-two blocks.
+#[derive(Debug)]
+pub struct SparseTable<T: Monoid + Clone> {
+    /// `table[k][i]` is the fold of the `2^k` elements starting at `i`.
+    table: Vec<Vec<T>>,
+}
+
+impl<T: Monoid + Clone> SparseTable<T> {
+    pub fn new(values: Vec<T>) -> Self {
+        let n = values.len();
+        let mut table = vec![values];
+
+        let mut k = 1;
+        while (1 << k) <= n {
+            let prev = &table[k - 1];
+            let half = 1 << (k - 1);
+            let next = (0..=n - (1 << k))
+                .map(|i| T::op(&prev[i], &prev[i + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+
+        Self { table }
+    }
+
+    /// Returns the fold of values in the given half-open range `[start, end)`.
+    pub fn get(&self, start: usize, end: usize) -> T {
+        if start >= end {
+            return T::id();
+        }
+        let k = (usize::BITS - 1 - (end - start).leading_zeros()) as usize;
+        T::op(&self.table[k][start], &self.table[k][end - (1 << k)])
+    }
+}
+
+/// LCP values under `min`, the fold the sparse table needs for range queries.
+#[derive(Clone, Debug)]
+struct MinLcp(usize);
+
+impl Monoid for MinLcp {
+    fn id() -> Self { MinLcp(usize::MAX) }
+    fn op(a: &Self, b: &Self) -> Self { MinLcp(a.0.min(b.0)) }
+}
+
+/// The suffix array of a byte string together with its LCP array.
+///
+/// `sa[i]` is the start of the `i`-th smallest suffix, and `lcp[i]` is the
+/// length of the longest common prefix of the suffixes at `sa[i]` and
+/// `sa[i + 1]`, so `lcp` has one entry fewer than `sa`. A sparse table over
+/// `lcp` answers the common prefix of any two suffixes in `O(1)`.
+#[derive(Debug)]
+pub struct SuffixArray {
+    sa: Vec<usize>,
+    /// `rank[i]` is the position of the suffix at `i` in `sa`.
+    rank: Vec<usize>,
+    lcp: Vec<usize>,
+    rmq: SparseTable<MinLcp>,
+}
+
+impl SuffixArray {
+    /// Builds all arrays in `O(n log n)`.
+    pub fn new(s: &[u8]) -> Self {
+        let sa = suffix_array(s);
+        let mut rank = vec![0; s.len()];
+        for (r, &i) in sa.iter().enumerate() {
+            rank[i] = r;
+        }
+        let lcp = lcp_array(s, &sa, &rank);
+        let rmq = SparseTable::new(lcp.iter().map(|&h| MinLcp(h)).collect());
+        Self { sa, rank, lcp, rmq }
+    }
+
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    pub fn rank(&self) -> &[usize] {
+        &self.rank
+    }
+
+    pub fn lcp_array(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// The longest common prefix of the suffixes starting at `i` and `j`:
+    /// the minimum of `lcp` between their ranks.
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.sa.len() - i;
+        }
+        let (a, b) = (self.rank[i].min(self.rank[j]), self.rank[i].max(self.rank[j]));
+        self.rmq.get(a, b).0
+    }
+}
+
+/// Prefix doubling: after the round for `k`, suffixes are ranked by their
+/// first `2k` characters. Each round sorts the pairs `(rank[i], rank[i + k])`
+/// with two stable counting sorts, the second key first.
+fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    sa.sort_by_key(|&i| s[i]);
+    let mut rank = vec![0; n];
+    for w in 1..n {
+        rank[sa[w]] = rank[sa[w - 1]] + usize::from(s[sa[w]] != s[sa[w - 1]]);
+    }
+
+    let mut k = 1;
+    let mut tmp = vec![0; n];
+    let mut count = vec![0; n + 1];
+    while k < n && rank[sa[n - 1]] < n - 1 {
+        // Suffixes shorter than `k` have an empty second key and come first.
+        let mut by_second: Vec<usize> = (n - k..n).collect();
+        by_second.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+
+        count.iter_mut().for_each(|c| *c = 0);
+        for &i in &by_second {
+            count[rank[i] + 1] += 1;
+        }
+        for r in 1..=n {
+            count[r] += count[r - 1];
+        }
+        for &i in &by_second {
+            sa[count[rank[i]]] = i;
+            count[rank[i]] += 1;
+        }
+
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        tmp[sa[0]] = 0;
+        for w in 1..n {
+            tmp[sa[w]] = tmp[sa[w - 1]] + usize::from(key(sa[w]) != key(sa[w - 1]));
+        }
+        std::mem::swap(&mut rank, &mut tmp);
+        k *= 2;
+    }
+    sa
+}
+
+/// Kasai's algorithm in `O(n)`: walking suffixes in text order, the common
+/// prefix with the suffix ranked just below can shrink by at most one per
+/// step, so the total work telescopes.
+fn lcp_array(s: &[u8], sa: &[usize], rank: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut lcp = vec![0; n.saturating_sub(1)];
+    let mut h: usize = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+    let s = s.trim().as_bytes();
+
+    // --- Output ---
+    // Every suffix contributes its prefixes that are not shared with the
+    // suffix ranked just below it.
+    let n = s.len() as u64;
+    let shared: u64 = SuffixArray::new(s).lcp_array().iter().map(|&h| h as u64).sum();
+    println!("{}", n * (n + 1) / 2 - shared);
+}
@@ -0,0 +1,218 @@
+use std::env;
+use std::io::{self, BufWriter, Read, Write};
+
+/// The whole input read at once and split into whitespace-separated tokens.
+/// With up to 10^6 queries, one `read_line` per query is too slow.
+struct Tokenizer {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Tokenizer {
+    fn from_stdin() -> io::Result<Self> {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
+
+    /// Returns the next token as raw bytes.
+    fn next_token(&mut self) -> &[u8] {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        &self.buf[start..self.pos]
+    }
+
+    fn next_i64(&mut self) -> i64 {
+        let token = self.next_token();
+        let (negative, digits) = match token.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, token),
+        };
+        let value = digits.iter().fold(0i64, |acc, &d| acc * 10 + (d - b'0') as i64);
+        if negative { -value } else { value }
+    }
+}
+
+/// A multiset over a universe of values fixed up front, as a Fenwick tree of
+/// counts over the compressed values. Every operation is `O(log u)`,
+/// including the order statistics a `BTreeSet` cannot answer quickly.
+///
+/// Only values of the universe may be inserted; every query accepts any value.
+#[derive(Clone, Debug)]
+pub struct OrderedMultiset {
+    /// The universe, sorted and deduplicated.
+    values: Vec<i64>,
+    /// `tree[i]` counts the elements with index in `(i - lowbit(i), i]`, 1-based.
+    tree: Vec<usize>,
+    counts: Vec<usize>,
+    len: usize,
+}
+
+impl OrderedMultiset {
+    /// An empty multiset over the values of `universe`.
+    pub fn new(universe: &[i64]) -> Self {
+        let mut values = universe.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        let u = values.len();
+        Self { values, tree: vec![0; u + 1], counts: vec![0; u], len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn index(&self, x: i64) -> usize {
+        self.values.binary_search(&x).expect("Value outside the universe")
+    }
+
+    fn add(&mut self, i: usize, delta: isize) {
+        self.counts[i] = self.counts[i].wrapping_add_signed(delta);
+        self.len = self.len.wrapping_add_signed(delta);
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i].wrapping_add_signed(delta);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Adds one copy of `x`.
+    pub fn insert(&mut self, x: i64) {
+        let i = self.index(x);
+        self.add(i, 1);
+    }
+
+    /// Removes one copy of `x`; returns whether there was one.
+    pub fn erase_one(&mut self, x: i64) -> bool {
+        match self.values.binary_search(&x) {
+            Ok(i) if self.counts[i] > 0 => {
+                self.add(i, -1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Copies of `x` stored.
+    pub fn count(&self, x: i64) -> usize {
+        self.values.binary_search(&x).map_or(0, |i| self.counts[i])
+    }
+
+    /// Elements smaller than `x`, counting multiplicity.
+    pub fn rank(&self, x: i64) -> usize {
+        let mut i = self.values.partition_point(|&v| v < x);
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// The `k`-th smallest element, 0-based and counting multiplicity, by
+    /// descending the implicit tree of the Fenwick array.
+    pub fn kth(&self, mut k: usize) -> Option<i64> {
+        if k >= self.len {
+            return None;
+        }
+        let mut pos = 0;
+        let mut step = (self.tree.len() - 1).next_power_of_two();
+        while step > 0 {
+            if pos + step < self.tree.len() && self.tree[pos + step] <= k {
+                pos += step;
+                k -= self.tree[pos];
+            }
+            step >>= 1;
+        }
+        Some(self.values[pos])
+    }
+
+    /// The largest element at most `x`.
+    pub fn prev(&self, x: i64) -> Option<i64> {
+        self.rank(x.checked_add(1)?).checked_sub(1).and_then(|k| self.kth(k))
+    }
+
+    /// The smallest element at least `x`.
+    pub fn next(&self, x: i64) -> Option<i64> {
+        self.kth(self.rank(x))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Query {
+    Insert { x: i64 },
+    Erase { x: i64 },
+    Kth { k: usize },
+    CountAtMost { x: i64 },
+    Prev { x: i64 },
+    Next { x: i64 },
+}
+
+fn main() -> io::Result<()> {
+    let mut tokens = Tokenizer::from_stdin()?;
+    let n = tokens.next_i64() as usize;
+    let q = tokens.next_i64() as usize;
+    let initial: Vec<i64> = (0..n).map(|_| tokens.next_i64()).collect();
+    let queries: Vec<Query> = (0..q)
+        .map(|_| {
+            let t = tokens.next_i64();
+            let x = tokens.next_i64();
+            match t {
+                0 => Query::Insert { x },
+                1 => Query::Erase { x },
+                2 => Query::Kth { k: x as usize },
+                3 => Query::CountAtMost { x },
+                4 => Query::Prev { x },
+                5 => Query::Next { x },
+                _ => unreachable!(),
+            }
+        })
+        .collect();
+
+    // The judge keeps a set, where inserting a present value does nothing;
+    // `multi` keeps every copy instead, and erasing removes one.
+    let multi = env::args().nth(1).as_deref() == Some("multi");
+
+    // Every value that can ever be inserted is known before the queries run.
+    let mut universe = initial.clone();
+    universe.extend(queries.iter().filter_map(|query| match *query {
+        Query::Insert { x } => Some(x),
+        _ => None,
+    }));
+    let mut set = OrderedMultiset::new(&universe);
+    for &x in &initial {
+        if multi || set.count(x) == 0 {
+            set.insert(x);
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for query in queries {
+        match query {
+            Query::Insert { x } => {
+                if multi || set.count(x) == 0 {
+                    set.insert(x);
+                }
+            }
+            Query::Erase { x } => {
+                set.erase_one(x);
+            }
+            // `k` is 1-based.
+            Query::Kth { k } => writeln!(out, "{}", k.checked_sub(1).and_then(|k| set.kth(k)).unwrap_or(-1))?,
+            Query::CountAtMost { x } => writeln!(out, "{}", set.rank(x + 1))?,
+            Query::Prev { x } => writeln!(out, "{}", set.prev(x).unwrap_or(-1))?,
+            Query::Next { x } => writeln!(out, "{}", set.next(x).unwrap_or(-1))?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,404 @@
+// Path and subtree updates/queries on a tree with vertex weights.
+//
+// Input: `N Q`, the initial values `a_0 .. a_{N-1}`, `N - 1` edges `u v`, then
+// `Q` queries of the forms
+//   0 u v x   add x to every vertex on the u-v path
+//   1 u v     print the sum of the vertices on the u-v path
+//   2 u x     add x to every vertex in the subtree of u (rooted at 0)
+//   3 u       print the sum of the vertices in the subtree of u
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// A monoid of maps acting on the values `T` of a lazy segment tree.
+pub trait Action<T> {
+    // Required methods
+    fn id() -> Self;
+    /// Returns the map that applies `f` first and then `g`.
+    fn compose(f: &Self, g: &Self) -> Self;
+    fn apply(f: &Self, x: &T) -> T;
+}
+
+/// Marks a missing child in the node arena.
+const NONE: u32 = u32::MAX;
+
+/// Represents a single node in the lazy segment tree.
+/// `lazy` is a map that has been applied to `value` but not yet to the children.
+/// Children are indices into the tree's node arena, or `NONE` for a leaf.
+#[derive(Debug)]
+struct LazyNode<T, F> {
+    value: T,
+    lazy: F,
+    left: u32,
+    right: u32,
+}
+
+impl<T: Monoid + Clone, F: Action<T> + Clone> LazyNode<T, F> {
+    /// Applies `f` to this whole node and remembers it for the children.
+    fn apply(&mut self, f: &F) {
+        self.value = F::apply(f, &self.value);
+        self.lazy = F::compose(&self.lazy, f);
+    }
+}
+
+/// A segment tree supporting range folds and applying a map to a whole range.
+///
+/// Nodes are stored in preorder in one `Vec`, the root first, and refer to their
+/// children by index; a node's [start, end) interval is recomputed on the way down.
+#[derive(Debug)]
+pub struct LazySegmentTree<T, F> {
+    nodes: Vec<LazyNode<T, F>>,
+    size: usize,
+}
+
+impl<T, F> LazySegmentTree<T, F>
+where
+    T: Monoid + Clone,
+    F: Action<T> + Clone,
+{
+    /// Creates a new LazySegmentTree for a sequence of `size` elements.
+    pub fn new(size: usize) -> Self {
+        let mut tree = Self { nodes: Vec::with_capacity(2 * size), size };
+        // An empty range results in no node.
+        if size > 0 {
+            tree.build(0..size);
+        }
+        tree
+    }
+
+    /// Creates the nodes covering the given range and returns the index of the topmost one.
+    fn build(&mut self, range: Range<usize>) -> u32 {
+        let index = self.nodes.len() as u32;
+        self.nodes.push(LazyNode {
+            value: T::id(),
+            lazy: F::id(),
+            left: NONE,
+            right: NONE,
+        });
+
+        // If the range represents more than one element, it's an internal node, so create children.
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            let left = self.build(range.start..mid);
+            let right = self.build(mid..range.end);
+            let node = &mut self.nodes[index as usize];
+            node.left = left;
+            node.right = right;
+        }
+
+        index
+    }
+
+    /// Hands the pending map of an internal node down to its children.
+    fn push_down(&mut self, index: u32) {
+        let node = &mut self.nodes[index as usize];
+        let lazy = std::mem::replace(&mut node.lazy, F::id());
+        let (left, right) = (node.left, node.right);
+        self.nodes[left as usize].apply(&lazy);
+        self.nodes[right as usize].apply(&lazy);
+    }
+
+    /// Recalculates an internal node's value based on its children's values.
+    fn update_value(&mut self, index: u32) {
+        let node = &self.nodes[index as usize];
+        let value = T::op(&self.nodes[node.left as usize].value, &self.nodes[node.right as usize].value);
+        self.nodes[index as usize].value = value;
+    }
+
+    /// Sets the value at a specific index.
+    pub fn set(&mut self, index: usize, val: T) {
+        if index < self.size {
+            self.set_recursive(0, 0..self.size, index, val);
+        }
+    }
+
+    fn set_recursive(&mut self, node: u32, range: Range<usize>, index: usize, val: T) {
+        // Base case: we have reached the leaf node corresponding to the index.
+        if range.len() == 1 {
+            self.nodes[node as usize].value = val;
+            return;
+        }
+
+        self.push_down(node);
+        // Non-leaf nodes always have both children.
+        let mid = range.start + range.len() / 2;
+        let LazyNode { left, right, .. } = self.nodes[node as usize];
+        if index < mid {
+            self.set_recursive(left, range.start..mid, index, val);
+        } else {
+            self.set_recursive(right, mid..range.end, index, val);
+        }
+        self.update_value(node);
+    }
+
+    /// Applies `f` to every value in the given half-open range `[start, end)`.
+    pub fn apply(&mut self, query_range: Range<usize>, f: &F) {
+        if self.size > 0 {
+            self.apply_recursive(0, 0..self.size, &query_range, f);
+        }
+    }
+
+    fn apply_recursive(&mut self, node: u32, range: Range<usize>, query_range: &Range<usize>, f: &F) {
+        // Case 1: The node's range has no overlap with the query range.
+        if query_range.end <= range.start || query_range.start >= range.end {
+            return;
+        }
+
+        // Case 2: The node's range is completely contained within the query range.
+        if query_range.start <= range.start && query_range.end >= range.end {
+            self.nodes[node as usize].apply(f);
+            return;
+        }
+
+        // Case 3: Partial overlap. Push pending work down and recurse into children.
+        self.push_down(node);
+        let mid = range.start + range.len() / 2;
+        let LazyNode { left, right, .. } = self.nodes[node as usize];
+        self.apply_recursive(left, range.start..mid, query_range, f);
+        self.apply_recursive(right, mid..range.end, query_range, f);
+        self.update_value(node);
+    }
+
+    /// Returns the fold of values in the given half-open range `[start, end)`.
+    pub fn get(&mut self, query_range: Range<usize>) -> T {
+        if self.size == 0 {
+            return T::id();
+        }
+        self.get_recursive(0, 0..self.size, &query_range)
+    }
+
+    fn get_recursive(&mut self, node: u32, range: Range<usize>, query_range: &Range<usize>) -> T {
+        // Case 1: The node's range has no overlap with the query range.
+        if query_range.end <= range.start || query_range.start >= range.end {
+            return T::id();
+        }
+
+        // Case 2: The node's range is completely contained within the query range.
+        if query_range.start <= range.start && query_range.end >= range.end {
+            return self.nodes[node as usize].value.clone();
+        }
+
+        // Case 3: Partial overlap. Push pending work down and combine the children.
+        self.push_down(node);
+        let mid = range.start + range.len() / 2;
+        let LazyNode { left, right, .. } = self.nodes[node as usize];
+        let left_sum = self.get_recursive(left, range.start..mid, query_range);
+        let right_sum = self.get_recursive(right, mid..range.end, query_range);
+
+        T::op(&left_sum, &right_sum)
+    }
+}
+
+/// Heavy-light decomposition of a rooted tree.
+///
+/// Nodes are laid out so that every heavy path occupies a contiguous block of
+/// positions, which lets any `u`-`v` path be split into `O(log n)` ranges
+/// over a sequence data structure indexed by `pos`. The layout is also a
+/// preorder, so every subtree is a single contiguous range.
+#[derive(Debug)]
+pub struct HeavyLightDecomposition {
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    /// `head[i]` is the topmost node of the heavy path containing `i`.
+    head: Vec<usize>,
+    /// `pos[i]` is the base position of node `i` in the linear layout.
+    pos: Vec<usize>,
+    subtree_size: Vec<usize>,
+}
+
+impl HeavyLightDecomposition {
+    /// Creates the decomposition from a given size and a list of undirected edges.
+    /// Everything is computed iteratively so path-shaped trees don't overflow the stack.
+    pub fn new(size: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+
+        // BFS to find parents, depths and an order where parents come first.
+        let mut parent = vec![usize::MAX; size];
+        let mut depth = vec![0; size];
+        let mut order = vec![root];
+        parent[root] = root;
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    order.push(v);
+                }
+            }
+        }
+
+        // Subtree sizes in reverse BFS order, then the heaviest child of each node.
+        let mut subtree_size = vec![1; size];
+        let mut heavy = vec![usize::MAX; size];
+        for &u in order.iter().rev() {
+            if u != root {
+                subtree_size[parent[u]] += subtree_size[u];
+            }
+        }
+        for &u in &order {
+            if u != root {
+                let p = parent[u];
+                if heavy[p] == usize::MAX || subtree_size[u] > subtree_size[heavy[p]] {
+                    heavy[p] = u;
+                }
+            }
+        }
+
+        // DFS that always visits the heavy child right after its parent.
+        let mut head = vec![root; size];
+        let mut pos = vec![0; size];
+        let mut stack = vec![root];
+        let mut next_pos = 0;
+        while let Some(u) = stack.pop() {
+            pos[u] = next_pos;
+            next_pos += 1;
+            for &v in &adj[u] {
+                if v != parent[u] && v != heavy[u] {
+                    head[v] = v;
+                    stack.push(v);
+                }
+            }
+            // Pushed last so it is popped next, continuing the heavy path.
+            if heavy[u] != usize::MAX {
+                head[heavy[u]] = head[u];
+                stack.push(heavy[u]);
+            }
+        }
+
+        Self { parent, depth, head, pos, subtree_size }
+    }
+
+    /// Returns the base position of node `v`.
+    pub fn pos(&self, v: usize) -> usize {
+        self.pos[v]
+    }
+
+    /// Returns the half-open range of base positions covered by the subtree of `v`.
+    pub fn subtree_range(&self, v: usize) -> Range<usize> {
+        self.pos[v]..self.pos[v] + self.subtree_size[v]
+    }
+
+    /// Splits the path between `u` and `v` (both inclusive) into
+    /// `O(log n)` half-open ranges of base positions.
+    pub fn path_ranges(&self, mut u: usize, mut v: usize) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        while self.head[u] != self.head[v] {
+            // Always climb from the node whose heavy path starts deeper.
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            ranges.push(self.pos[self.head[u]]..self.pos[u] + 1);
+            u = self.parent[self.head[u]];
+        }
+
+        // `u` and `v` are now on the same heavy path.
+        let (l, r) = if self.pos[u] < self.pos[v] { (self.pos[u], self.pos[v]) } else { (self.pos[v], self.pos[u]) };
+        ranges.push(l..r + 1);
+        ranges
+    }
+}
+
+
+/// A range sum together with the number of elements it covers, which is
+/// what adding a constant to every element needs to know.
+#[derive(Clone, Debug)]
+struct S {
+    sum: i64,
+    len: i64,
+}
+impl Monoid for S {
+    fn id() -> Self { S { sum: 0, len: 0 } }
+    fn op(a: &Self, b: &Self) -> Self { S { sum: a.sum + b.sum, len: a.len + b.len } }
+}
+
+/// Adds `add` to every element.
+#[derive(Clone, Debug)]
+struct Add {
+    add: i64,
+}
+impl Action<S> for Add {
+    fn id() -> Self { Add { add: 0 } }
+    fn compose(f: &Self, g: &Self) -> Self { Add { add: f.add + g.add } }
+    fn apply(f: &Self, x: &S) -> S { S { sum: x.sum + f.add * x.len, len: x.len } }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let initial_values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the initial values")
+        .split_whitespace()
+        .map(|s| s.parse().expect("Failed to parse initial value"))
+        .collect();
+
+    let edges: Vec<(usize, usize)> = (0..n - 1)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    let hld = HeavyLightDecomposition::new(n, &edges, 0);
+    let mut st = LazySegmentTree::<S, Add>::new(n);
+    for (v, &a) in initial_values.iter().enumerate() {
+        st.set(hld.pos(v), S { sum: a, len: 1 });
+    }
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+
+        match t {
+            0 => {
+                let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                for range in hld.path_ranges(u, v) {
+                    st.apply(range, &Add { add: x });
+                }
+            }
+            1 => {
+                let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+                let sum = hld
+                    .path_ranges(u, v)
+                    .into_iter()
+                    .fold(S::id(), |acc, range| S::op(&acc, &st.get(range)));
+                println!("{}", sum.sum);
+            }
+            2 => {
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                st.apply(hld.subtree_range(u), &Add { add: x });
+            }
+            3 => println!("{}", st.get(hld.subtree_range(u)).sum),
+            _ => unreachable!()
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::io::{self, BufRead};
+
+/// Common interface for lowest common ancestor backends.
+pub trait Lca {
+    /// Returns the lowest common ancestor of `u` and `v`.
+    fn lca(&self, u: usize, v: usize) -> usize;
+}
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    /// Roots the tree given by `n - 1` undirected edges at `root`.
+    pub fn from_edges(n: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        let mut parent = vec![usize::MAX; n];
+        parent[root] = root;
+        let mut order = vec![root];
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+        Self::new(parent, root)
+    }
+
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns the nodes in BFS order together with the depth of every node.
+    /// Iterative on purpose: judge trees can be paths of length 5 * 10^5.
+    pub fn bfs_order(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut order = Vec::with_capacity(self.size());
+        let mut depth = vec![0; self.size()];
+        order.push(self.root);
+
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &c in &self.children[u] {
+                depth[c] = depth[u] + 1;
+                order.push(c);
+            }
+        }
+
+        (order, depth)
+    }
+}
+
+/// LCA by binary lifting: `O(n log n)` preprocessing, `O(log n)` per query.
+#[derive(Debug)]
+pub struct BinaryLifting {
+    /// `up[k][v]` is the `2^k`-th ancestor of `v` (clamped at the root).
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl BinaryLifting {
+    fn new(tree: &Tree) -> Self {
+        let n = tree.size();
+        let (_, depth) = tree.bfs_order();
+
+        let mut levels = 1;
+        while (1 << levels) < n {
+            levels += 1;
+        }
+
+        let mut up = vec![tree.parent.clone()];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+
+        Self { up, depth }
+    }
+}
+
+impl Lca for BinaryLifting {
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] { (v, u) } else { (u, v) };
+
+        // Lift `u` up to the depth of `v`.
+        let diff = self.depth[u] - self.depth[v];
+        for (k, up) in self.up.iter().enumerate() {
+            if diff >> k & 1 == 1 {
+                u = up[u];
+            }
+        }
+        if u == v {
+            return u;
+        }
+
+        // Lift both as long as they stay below the common ancestor.
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+}
+
+/// A node of the persistent segment tree: how many values of its range the
+/// version holds.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    count: u32,
+    left: u32,
+    right: u32,
+}
+
+/// A persistent segment tree of counts over the positions `0..size`.
+///
+/// Every version is named by its root. Adding a value copies the
+/// `O(log size)` nodes on its path and returns a new root, sharing the rest
+/// with the old version, which stays valid. Node 0 is the empty tree: its
+/// count is zero and both children are itself, so any version can be walked
+/// to the bottom.
+#[derive(Clone, Debug)]
+pub struct PersistentSegmentTree {
+    nodes: Vec<Node>,
+    size: usize,
+}
+
+impl PersistentSegmentTree {
+    /// The root of the empty version.
+    pub const EMPTY: u32 = 0;
+
+    pub fn new(size: usize) -> Self {
+        Self { nodes: vec![Node { count: 0, left: 0, right: 0 }], size }
+    }
+
+    /// The version `root` with one more copy of `pos`.
+    pub fn add(&mut self, root: u32, pos: usize) -> u32 {
+        self.add_in(root, 0, self.size, pos)
+    }
+
+    fn add_in(&mut self, v: u32, lo: usize, hi: usize, pos: usize) -> u32 {
+        let mut node = self.nodes[v as usize];
+        node.count += 1;
+        if hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if pos < mid {
+                node.left = self.add_in(node.left, lo, mid, pos);
+            } else {
+                node.right = self.add_in(node.right, mid, hi, pos);
+            }
+        }
+        self.nodes.push(node);
+        (self.nodes.len() - 1) as u32
+    }
+
+    /// The `k`-th smallest position (0-based) of the signed combination of
+    /// versions `sum sign_i * roots_i`, which must count every position a
+    /// non-negative number of times, or `None` if it holds at most `k`.
+    pub fn kth<const N: usize>(&self, roots: [(u32, i64); N], mut k: u32) -> Option<usize> {
+        let count = |vs: &[(u32, i64); N]| vs.iter().map(|&(v, sign)| sign * self.nodes[v as usize].count as i64).sum::<i64>();
+        if count(&roots) <= k as i64 {
+            return None;
+        }
+        let (mut vs, mut lo, mut hi) = (roots, 0, self.size);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            let left = vs.map(|(v, sign)| (self.nodes[v as usize].left, sign));
+            let in_left = count(&left);
+            if (k as i64) < in_left {
+                (vs, hi) = (left, mid);
+            } else {
+                k -= in_left as u32;
+                (vs, lo) = (vs.map(|(v, sign)| (self.nodes[v as usize].right, sign)), mid);
+            }
+        }
+        Some(lo)
+    }
+}
+
+/// `k`-th smallest weights on tree paths. Vertex `v`'s version holds the
+/// weights on its path to the root, built from its parent's in `O(log n)`,
+/// so the path `u - v` is `ver(u) + ver(v) - ver(w) - ver(parent(w))` for
+/// `w = lca(u, v)`, and one descent through the four versions finds the
+/// answer in `O(log n)`.
+struct PathKth<L: Lca> {
+    lca: L,
+    parent: Vec<usize>,
+    root: usize,
+    /// The distinct weights, sorted; positions in the tree index into it.
+    values: Vec<i64>,
+    versions: Vec<u32>,
+    seg: PersistentSegmentTree,
+}
+
+impl<L: Lca> PathKth<L> {
+    fn new(tree: &Tree, lca: L, weights: &[i64]) -> Self {
+        let mut values = weights.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        let mut seg = PersistentSegmentTree::new(values.len());
+        let mut versions = vec![PersistentSegmentTree::EMPTY; tree.size()];
+        let (order, _) = tree.bfs_order();
+        for v in order {
+            let base = if v == tree.root { PersistentSegmentTree::EMPTY } else { versions[tree.parent[v]] };
+            versions[v] = seg.add(base, values.binary_search(&weights[v]).unwrap());
+        }
+        Self { lca, parent: tree.parent.clone(), root: tree.root, values, versions, seg }
+    }
+
+    /// The `k`-th smallest weight (0-based) on the path `u - v`.
+    fn kth(&self, u: usize, v: usize, k: u32) -> Option<i64> {
+        let w = self.lca.lca(u, v);
+        let above = if w == self.root { PersistentSegmentTree::EMPTY } else { self.versions[self.parent[w]] };
+        let roots = [(self.versions[u], 1), (self.versions[v], 1), (self.versions[w], -1), (above, -1)];
+        self.seg.kth(roots, k).map(|i| self.values[i])
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n q`, the non-negative weight of every vertex, `n - 1` edges, then `q`
+    // queries `u v k`.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+    let line = lines.next().unwrap().expect("Failed to read the weights");
+    let weights: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a weight")).collect();
+    let edges: Vec<(usize, usize)> = (1..n)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    let tree = Tree::from_edges(n, &edges, 0);
+    let lca = BinaryLifting::new(&tree);
+    let solver = PathKth::new(&tree, lca, &weights);
+
+    // --- Query Processing and Output ---
+    // The `k`-th smallest (0-based) weight on the path, `-1` if it is shorter.
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        let k: u32 = parts.next().unwrap().parse().expect("Failed to parse k");
+        output.push(solver.kth(u, v, k).map_or(-1, |x| x).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
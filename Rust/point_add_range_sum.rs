@@ -7,57 +7,33 @@ pub trait Monoid {
     fn op(a: &Self, b: &Self) -> Self;
 }
 
+/// Marks a missing child in the node arena.
+const NONE: u32 = u32::MAX;
+
 /// Represents a single node in the segment tree.
-/// Using std::ops::Range makes the [start, end) interval explicit and provides useful methods.
+/// Children are indices into the tree's node arena, or `NONE` for a leaf; a node's
+/// [start, end) interval is not stored but recomputed on the way down from the root.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug)]
 struct Node<T: Monoid + Clone> {
     value: T,
-    range: Range<usize>,
-    left: Option<Box<Node<T>>>,
-    right: Option<Box<Node<T>>>,
-}
-
-impl<T: Monoid + Clone> Node<T> {
-    /// Creates a new node and recursively builds its children to cover the given range.
-    fn new(range: Range<usize>) -> Option<Box<Node<T>>> {
-        // An empty range results in no node.
-        if range.is_empty() {
-            return None;
-        }
-
-        let mut node = Box::new(Node {
-            value: T::id(),
-            range: range.clone(),
-            left: None,
-            right: None,
-        });
-
-        // If the range represents more than one element, it's an internal node, so create children.
-        if range.len() > 1 {
-            let mid = range.start + range.len() / 2;
-            node.left = Node::new(range.start..mid);
-            node.right = Node::new(mid..range.end);
-        }
-
-        Some(node)
-    }
-
-    /// Recalculates this node's value based on its children's values.
-    /// This is called after a child's value has been updated.
-    fn update_value(&mut self) {
-        let left_val = self.left.as_ref().map_or(T::id(), |n| n.value.clone());
-        let right_val = self.right.as_ref().map_or(T::id(), |n| n.value.clone());
-        self.value = T::op(&left_val, &right_val);
-    }
+    left: u32,
+    right: u32,
 }
 
 /// A segment tree implementation for sum queries on a range.
+///
+/// Every node lives in one `Vec` and refers to its children by `u32` index instead
+/// of owning them through a `Box`, so building the tree is a single allocation and
+/// the whole structure can be inspected as a flat array.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug)]
 pub struct SegmentTree<T>
 where
     T: Monoid + Clone
 {
-    root: Option<Box<Node<T>>>,
+    /// The root, if any, is `nodes[0]`; nodes are stored in preorder.
+    nodes: Vec<Node<T>>,
     size: usize,
 }
 
@@ -67,10 +43,45 @@ where
 {
     /// Creates a new SegmentTree for a sequence of `size` elements.
     pub fn new(size: usize) -> Self {
-        Self {
-            root: Node::new(0..size),
+        let mut tree = Self {
+            nodes: Vec::with_capacity(2 * size),
             size,
+        };
+        // An empty range results in no node.
+        if size > 0 {
+            tree.build(0..size);
         }
+        tree
+    }
+
+    /// Creates the nodes covering the given range and returns the index of the topmost one.
+    fn build(&mut self, range: Range<usize>) -> u32 {
+        let index = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            value: T::id(),
+            left: NONE,
+            right: NONE,
+        });
+
+        // If the range represents more than one element, it's an internal node, so create children.
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            let left = self.build(range.start..mid);
+            let right = self.build(mid..range.end);
+            let node = &mut self.nodes[index as usize];
+            node.left = left;
+            node.right = right;
+        }
+
+        index
+    }
+
+    /// Recalculates a node's value based on its children's values.
+    /// This is called after a child's value has been updated.
+    fn update_value(&mut self, index: u32) {
+        let node = &self.nodes[index as usize];
+        let value = T::op(&self.nodes[node.left as usize].value, &self.nodes[node.right as usize].value);
+        self.nodes[index as usize].value = value;
     }
 
     /// Sets the value at a specific index.
@@ -79,67 +90,98 @@ where
         if index >= self.size {
             return;
         }
-        if let Some(root) = self.root.as_mut() {
-            Self::set_recursive(root, index, val);
-        }
+        self.set_recursive(0, 0..self.size, index, val);
     }
 
     /// Helper function to recursively find the correct leaf node and update values up the tree.
-    fn set_recursive(node: &mut Node<T>, index: usize, val: T) {
+    fn set_recursive(&mut self, node: u32, range: Range<usize>, index: usize, val: T) {
         // Base case: we have reached the leaf node corresponding to the index.
-        if node.range.len() == 1 {
-            node.value = val;
+        if range.len() == 1 {
+            self.nodes[node as usize].value = val;
             return;
         }
 
         // Recursive step: determine whether to go left or right.
-        let mid = node.range.start + node.range.len() / 2;
-        // The `unwrap`s here are safe due to the invariant that non-leaf nodes always have children.
+        // Non-leaf nodes always have both children.
+        let mid = range.start + range.len() / 2;
+        let Node { left, right, .. } = self.nodes[node as usize];
         if index < mid {
-            Self::set_recursive(node.left.as_mut().unwrap(), index, val);
+            self.set_recursive(left, range.start..mid, index, val);
         } else {
-            Self::set_recursive(node.right.as_mut().unwrap(), index, val);
+            self.set_recursive(right, mid..range.end, index, val);
         }
 
         // After recursion, update the current node's value based on its children.
-        node.update_value();
+        self.update_value(node);
     }
 
     /// Returns the sum of values in the given half-open range `[start, end)`.
     pub fn get(&self, query_range: Range<usize>) -> T {
-        self.root
-            .as_ref()
-            .map_or(T::id(), |root| Self::get_recursive(root, &query_range))
+        if self.size == 0 {
+            return T::id();
+        }
+        self.get_recursive(0, 0..self.size, &query_range)
     }
 
     /// Helper function to recursively calculate the sum over a given query range.
-    fn get_recursive(node: &Node<T>, query_range: &Range<usize>) -> T {
+    fn get_recursive(&self, node: u32, range: Range<usize>, query_range: &Range<usize>) -> T {
         // Case 1: The node's range has no overlap with the query range.
-        if query_range.end <= node.range.start || query_range.start >= node.range.end {
+        if query_range.end <= range.start || query_range.start >= range.end {
             return T::id();
         }
 
+        let node = &self.nodes[node as usize];
         // Case 2: The node's range is completely contained within the query range.
-        if query_range.start <= node.range.start && query_range.end >= node.range.end {
+        if query_range.start <= range.start && query_range.end >= range.end {
             return node.value.clone();
         }
 
         // Case 3: Partial overlap. Recurse into children and sum their results.
-        let left_sum = node
-            .left
-            .as_ref()
-            .map_or(T::id(), |n| Self::get_recursive(n, query_range));
-        let right_sum = node
-            .right
-            .as_ref()
-            .map_or(T::id(), |n| Self::get_recursive(n, query_range));
+        let mid = range.start + range.len() / 2;
+        let left_sum = self.get_recursive(node.left, range.start..mid, query_range);
+        let right_sum = self.get_recursive(node.right, mid..range.end, query_range);
 
         T::op(&left_sum, &right_sum)
     }
 }
 
+/// Graphviz rendering for debugging, e.g. to dump the state of a failing
+/// stress test. Nodes are numbered by their arena index, so the output only
+/// depends on the tree's contents.
+#[cfg(feature = "debug-viz")]
+impl<T> SegmentTree<T>
+where
+    T: Monoid + Clone + std::fmt::Debug,
+{
+    /// Returns the tree in DOT format, every node labelled with its range and value.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph SegmentTree {\n    node [shape=box];\n");
+        if self.size > 0 {
+            self.dot_recursive(0, 0..self.size, &mut out);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn dot_recursive(&self, node: u32, range: Range<usize>, out: &mut String) {
+        use std::fmt::Write;
+
+        let n = &self.nodes[node as usize];
+        let label = format!("[{}, {}): {:?}", range.start, range.end, n.value).replace('"', "\\\"");
+        writeln!(out, "    {} [label=\"{}\"];", node, label).unwrap();
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            for (child, child_range) in [(n.left, range.start..mid), (n.right, mid..range.end)] {
+                writeln!(out, "    {} -> {};", node, child).unwrap();
+                self.dot_recursive(child, child_range, out);
+            }
+        }
+    }
+}
+
 
-#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
 struct S {
     val: i64
 }
@@ -0,0 +1,181 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+/// CDQ divide and conquer over a sequence of operations, for offline
+/// dominance problems in three dimensions.
+///
+/// The order of `items` is the first dimension (usually time). Both halves
+/// of every split are solved recursively and come back sorted by `key`, the
+/// second dimension; `cross(left, right)` then accounts for the effect of
+/// every item in `left` on every item in `right`, typically by a sweep
+/// over both in `key` order with a Fenwick tree over the third dimension.
+/// Finally the halves are merged by `key`, stably, for the parent. Each item
+/// takes part in `O(log n)` calls of `cross`.
+pub fn cdq<T, K, F, C>(items: &mut [T], key: &F, cross: &mut C)
+where
+    T: Clone,
+    K: Ord,
+    F: Fn(&T) -> K,
+    C: FnMut(&[T], &[T]),
+{
+    if items.len() <= 1 {
+        return;
+    }
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at_mut(mid);
+    cdq(left, key, cross);
+    cdq(right, key, cross);
+    cross(left, right);
+
+    let mut merged = Vec::with_capacity(items.len());
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < items.len() {
+        if key(&items[j]) < key(&items[i]) {
+            merged.push(items[j].clone());
+            j += 1;
+        } else {
+            merged.push(items[i].clone());
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&items[i..mid]);
+    merged.extend_from_slice(&items[j..]);
+    items.clone_from_slice(&merged);
+}
+
+/// A Fenwick tree (binary indexed tree) for prefix sums with point additions.
+#[derive(Debug)]
+pub struct FenwickTree {
+    /// 1-indexed internally: `data[i]` covers the range `(i - lowbit(i), i]`.
+    data: Vec<i64>,
+}
+impl FenwickTree {
+    /// Creates a new FenwickTree for a sequence of `size` zeros.
+    pub fn new(size: usize) -> Self {
+        Self { data: vec![0; size + 1] }
+    }
+
+    /// Adds `val` to the element at `index`.
+    pub fn add(&mut self, index: usize, val: i64) {
+        let mut i = index + 1;
+        while i < self.data.len() {
+            self.data[i] += val;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the first `end` elements.
+    fn prefix_sum(&self, end: usize) -> i64 {
+        let mut i = end;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.data[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Returns the sum of values in the given half-open range `[start, end)`.
+    pub fn sum(&self, range: Range<usize>) -> i64 {
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+}
+
+/// An operation of Point Add Rectangle Sum, in time order. A rectangle
+/// `[l, r) x [d, u)` is the difference of its two `x < X` prefixes, each
+/// asking for the weight with `d <= y < u`.
+#[derive(Clone, Copy, Debug)]
+enum Event {
+    Add { x: i64, y: i64, w: i64 },
+    Prefix { x: i64, d: i64, u: i64, sign: i64, id: usize },
+}
+
+impl Event {
+    /// The sweep order: by `x`, and at equal `x` a prefix query first, since
+    /// it only counts points strictly to its left.
+    fn key(&self) -> (i64, bool) {
+        match *self {
+            Event::Add { x, .. } => (x, true),
+            Event::Prefix { x, .. } => (x, false),
+        }
+    }
+}
+
+/// Answers every rectangle query given after the points it should count.
+fn rectangle_sums(events: &mut [Event], query_count: usize) -> Vec<i64> {
+    let mut ys: Vec<i64> = events
+        .iter()
+        .filter_map(|e| match *e {
+            Event::Add { y, .. } => Some(y),
+            Event::Prefix { .. } => None,
+        })
+        .collect();
+    ys.sort_unstable();
+    ys.dedup();
+    let rank = |y: i64| ys.partition_point(|&v| v < y);
+
+    let mut fenwick = FenwickTree::new(ys.len());
+    let mut answers = vec![0; query_count];
+    cdq(events, &Event::key, &mut |left: &[Event], right: &[Event]| {
+        // Additions of the left half sweep past the queries of the right one.
+        let mut added = Vec::new();
+        let mut i = 0;
+        for e in right {
+            let Event::Prefix { d, u, sign, id, .. } = *e else {
+                continue;
+            };
+            while i < left.len() && left[i].key() < e.key() {
+                if let Event::Add { y, w, .. } = left[i] {
+                    fenwick.add(rank(y), w);
+                    added.push((rank(y), w));
+                }
+                i += 1;
+            }
+            answers[id] += sign * fenwick.sum(rank(d)..rank(u));
+        }
+        for (index, w) in added {
+            fenwick.add(index, -w);
+        }
+    });
+    answers
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let mut events = Vec::with_capacity(n + 2 * q);
+    for _ in 0..n {
+        let line = lines.next().unwrap().expect("Failed to read a point");
+        let v: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a point")).collect();
+        events.push(Event::Add { x: v[0], y: v[1], w: v[2] });
+    }
+    let mut query_count = 0;
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let v: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a query")).collect();
+        match v[0] {
+            0 => events.push(Event::Add { x: v[1], y: v[2], w: v[3] }),
+            1 => {
+                let (l, d, r, u) = (v[1], v[2], v[3], v[4]);
+                let id = query_count;
+                query_count += 1;
+                events.push(Event::Prefix { x: r, d, u, sign: 1, id });
+                events.push(Event::Prefix { x: l, d, u, sign: -1, id });
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // --- Solving and Output ---
+    let answers = rectangle_sums(&mut events, query_count);
+    let output: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+    println!("{}", output.join("\n"));
+}
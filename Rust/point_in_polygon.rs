@@ -0,0 +1,154 @@
+use std::io::{self, BufRead};
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The orientation of `a -> b -> c`: positive for a left turn, negative for
+/// a right turn, zero when collinear.
+pub fn orient(a: Point, b: Point, c: Point) -> i128 {
+    (b - a).cross(c - a)
+}
+
+/// Whether `p` lies on the closed segment `ab`.
+pub fn on_segment(p: Point, a: Point, b: Point) -> bool {
+    orient(a, b, p) == 0 && (a - p).dot(b - p) <= 0
+}
+
+/// Twice the signed area of the polygon by the shoelace formula: positive
+/// for counterclockwise vertex order. Doubling keeps the result an integer.
+pub fn doubled_signed_area(poly: &[Point]) -> i128 {
+    (0..poly.len()).map(|i| poly[i].cross(poly[(i + 1) % poly.len()])).sum()
+}
+
+/// Whether the polygon is convex: no two turns go opposite ways, no edge
+/// doubles back, and the edge direction sweeps around only once, which rules
+/// out self-intersecting stars whose turns all agree.
+pub fn is_convex(poly: &[Point]) -> bool {
+    let n = poly.len();
+    // The upper half-plane of directions, closed on the positive x axis.
+    let upper = |e: Point| e.y > 0 || (e.y == 0 && e.x > 0);
+    let (mut left, mut right) = (false, false);
+    let mut windings = 0;
+    for i in 0..n {
+        let e1 = poly[(i + 1) % n] - poly[i];
+        let e2 = poly[(i + 2) % n] - poly[(i + 1) % n];
+        let turn = e1.cross(e2);
+        if turn == 0 && e1.dot(e2) < 0 {
+            return false;
+        }
+        left |= turn > 0;
+        right |= turn < 0;
+        if !upper(e1) && upper(e2) {
+            windings += 1;
+        }
+    }
+    !(left && right) && windings <= 1
+}
+
+/// Where a point lies relative to a polygon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+/// Locates `p` against a simple polygon by the winding number in `O(n)`.
+///
+/// Each edge crossing the horizontal ray from `p` upwards with `p` on its
+/// left adds one, downwards with `p` on its right subtracts one; the
+/// half-open rule on `y` counts a vertex on the ray exactly once. Only exact
+/// orientations are used, so the answer is exact.
+pub fn contains(poly: &[Point], p: Point) -> Containment {
+    let n = poly.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (poly[i], poly[(i + 1) % n]);
+        if on_segment(p, a, b) {
+            return Containment::OnBoundary;
+        }
+        if a.y <= p.y && p.y < b.y && orient(a, b, p) > 0 {
+            winding += 1;
+        } else if b.y <= p.y && p.y < a.y && orient(a, b, p) < 0 {
+            winding -= 1;
+        }
+    }
+    if winding != 0 { Containment::Inside } else { Containment::Outside }
+}
+
+/// Reads one point per line.
+fn read_points<I: Iterator<Item = io::Result<String>>>(lines: &mut I, n: usize) -> Vec<Point> {
+    (0..n)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read a point");
+            let mut parts = line.split_whitespace();
+            let x = parts.next().unwrap().parse().expect("Failed to parse x");
+            let y = parts.next().unwrap().parse().expect("Failed to parse y");
+            Point::new(x, y)
+        })
+        .collect()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n` and the polygon's vertices in order, then `q` query points.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let poly = read_points(&mut lines, n);
+    let line = lines.next().unwrap().expect("Failed to read q");
+    let q: usize = line.trim().parse().expect("Failed to parse q");
+    let queries = read_points(&mut lines, q);
+
+    // --- Output ---
+    // Twice the area, whether the polygon is convex, then each query's location.
+    let mut output = vec![doubled_signed_area(&poly).abs().to_string(), (if is_convex(&poly) { "convex" } else { "concave" }).to_string()];
+    for p in queries {
+        output.push(
+            match contains(&poly, p) {
+                Containment::Inside => "IN",
+                Containment::OnBoundary => "ON",
+                Containment::Outside => "OUT",
+            }
+            .to_string(),
+        );
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,235 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// An integer modulo the judge's prime `MOD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModInt {
+    val: u64,
+}
+
+impl ModInt {
+    pub fn new(val: u64) -> Self {
+        ModInt { val: val % MOD }
+    }
+
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat's little theorem; `self` must be non-zero.
+    pub fn inv(self) -> Self {
+        self.pow(MOD - 2)
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = ModInt;
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + rhs.val)
+    }
+}
+
+impl std::ops::Sub for ModInt {
+    type Output = ModInt;
+    fn sub(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + MOD - rhs.val)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = ModInt;
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val * rhs.val)
+    }
+}
+
+/// A dense matrix over `ModInt`, stored row-major in one flat vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<ModInt>,
+}
+
+impl Matrix {
+    /// Creates a `rows x cols` zero matrix.
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![ModInt::new(0); rows * cols] }
+    }
+
+    /// Creates the `size x size` identity matrix.
+    pub fn identity(size: usize) -> Self {
+        let mut m = Self::zero(size, size);
+        for i in 0..size {
+            m.data[i * size + i] = ModInt::new(1);
+        }
+        m
+    }
+
+    /// Creates a matrix from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<ModInt>>) -> Self {
+        let cols = rows.first().map_or(0, |r| r.len());
+        let n = rows.len();
+        let data: Vec<ModInt> = rows.into_iter().flatten().collect();
+        assert_eq!(data.len(), n * cols, "rows must have equal length");
+        Self { rows: n, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[ModInt] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t.data[j * self.rows + i] = self.data[i * self.cols + j];
+            }
+        }
+        t
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Matrix {
+    type Output = ModInt;
+    fn index(&self, (i, j): (usize, usize)) -> &ModInt {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut ModInt {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl std::ops::Add for &Matrix {
+    type Output = Matrix;
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimension mismatch");
+        let data = self.data.iter().zip(&rhs.data).map(|(&a, &b)| a + b).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+impl std::ops::Sub for &Matrix {
+    type Output = Matrix;
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "dimension mismatch");
+        let data = self.data.iter().zip(&rhs.data).map(|(&a, &b)| a - b).collect();
+        Matrix { rows: self.rows, cols: self.cols, data }
+    }
+}
+
+/// Number of products that can be summed in a `u64` before reducing:
+/// each is below `MOD^2 < 2^60`, so 16 of them stay below `2^64`.
+const REDUCE_EVERY: usize = 16;
+
+impl std::ops::Mul for &Matrix {
+    type Output = Matrix;
+
+    /// Multiplies in `i-k-j` order so the inner loop streams over contiguous
+    /// rows of both `rhs` and the accumulator. The `k` loop is cut into
+    /// blocks of `REDUCE_EVERY` rows of `rhs`, which both bounds the raw
+    /// `u64` sums and keeps the rows being reused hot in cache.
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(self.cols, rhs.rows, "dimension mismatch");
+        let (n, m, k) = (self.rows, self.cols, rhs.cols);
+        let b: Vec<u32> = rhs.data.iter().map(|x| x.val as u32).collect();
+        let mut out = Matrix::zero(n, k);
+        let mut acc = vec![0u64; k];
+
+        for i in 0..n {
+            acc.iter_mut().for_each(|x| *x = 0);
+            let a_row = self.row(i);
+            for block in (0..m).step_by(REDUCE_EVERY) {
+                for l in block..m.min(block + REDUCE_EVERY) {
+                    let a = a_row[l].val;
+                    for (x, &y) in acc.iter_mut().zip(&b[l * k..(l + 1) * k]) {
+                        *x += a * y as u64;
+                    }
+                }
+                acc.iter_mut().for_each(|x| *x %= MOD);
+            }
+            for (o, &x) in out.data[i * k..(i + 1) * k].iter_mut().zip(&acc) {
+                *o = ModInt { val: x };
+            }
+        }
+
+        out
+    }
+}
+
+impl Matrix {
+    /// Raises a square matrix to the `k`-th power by binary exponentiation,
+    /// using `O(log k)` multiplications.
+    pub fn pow(&self, mut k: u64) -> Matrix {
+        assert_eq!(self.rows, self.cols, "power of a non-square matrix");
+        let mut base = self.clone();
+        let mut result = Matrix::identity(self.rows);
+        while k > 0 {
+            if k & 1 == 1 {
+                result = &result * &base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+/// Reads `rows` lines of whitespace-separated integers into a Matrix.
+fn read_matrix<I: Iterator<Item = io::Result<String>>>(lines: &mut I, rows: usize) -> Matrix {
+    let data = (0..rows)
+        .map(|_| {
+            lines
+                .next()
+                .unwrap()
+                .expect("Failed to read a matrix row")
+                .split_whitespace()
+                .map(|s| ModInt::new(s.parse().expect("Failed to parse a matrix entry")))
+                .collect()
+        })
+        .collect();
+    Matrix::from_rows(data)
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let k: u64 = parts.next().unwrap().parse().expect("Failed to parse k");
+    let a = read_matrix(&mut lines, n);
+
+    // --- Output ---
+    let b = a.pow(k);
+    let output: Vec<String> = (0..n)
+        .map(|i| b.row(i).iter().map(|x| x.val.to_string()).collect::<Vec<_>>().join(" "))
+        .collect();
+    println!("{}", output.join("\n"));
+}
@@ -1,7 +1,7 @@
 // This problem can be solved better once btree_cursors are implemented.
 // https://github.com/rust-lang/rust/issues/107540 
 // Having an object point to a gap between two items is just next level and makes the implementation for this problem way easier.
-use std::io;
+use std::io::{self, BufWriter, Read, Write};
 use std::collections::BTreeSet;
 
 #[derive(Debug)]
@@ -13,24 +13,46 @@ enum Query {
     Previous {k: i64}
 }
 
-fn read_two_i64() -> (i64, i64) {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+/// The whole input read at once and split into whitespace-separated tokens.
+/// With up to 10^6 queries, one `read_line` per query is too slow.
+struct Tokenizer {
+    buf: Vec<u8>,
+    pos: usize,
+}
 
-    let mut iter = input.split_whitespace();
-    let a: i64 = iter.next().unwrap().parse().unwrap();
-    let b: i64 = iter.next().unwrap().parse().unwrap();
+impl Tokenizer {
+    fn from_stdin() -> io::Result<Self> {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf)?;
+        Ok(Self { buf, pos: 0 })
+    }
 
-    (a, b)
-}
+    /// Returns the next token as raw bytes.
+    fn next_token(&mut self) -> &[u8] {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        &self.buf[start..self.pos]
+    }
 
-fn read_query() -> Query {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+    fn next_i64(&mut self) -> i64 {
+        let token = self.next_token();
+        let (negative, digits) = match token.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, token),
+        };
+        let value = digits.iter().fold(0i64, |acc, &d| acc * 10 + (d - b'0') as i64);
+        if negative { -value } else { value }
+    }
+}
 
-    let mut iter = input.split_whitespace();
-    let t: i64 = iter.next().unwrap().parse().unwrap();
-    let k: i64 = iter.next().unwrap().parse().unwrap();
+fn read_query(tokens: &mut Tokenizer) -> Query {
+    let t = tokens.next_i64();
+    let k = tokens.next_i64();
 
     match t {
         0 => Query::Insert {k},
@@ -43,43 +65,32 @@ fn read_query() -> Query {
 }
 
 fn main() -> io::Result<()> {
-    let (_n, mut q) = read_two_i64();
-
-    let mut initial_state = String::new();
-     io::stdin().read_line(&mut initial_state)?;
-    // dbg!(&initial_state);
+    let mut tokens = Tokenizer::from_stdin()?;
+    let _n = tokens.next_i64();
+    let q = tokens.next_i64();
 
     let mut set = BTreeSet::<i64>::new();
 
-    for (i, c) in initial_state.trim().chars().enumerate() {
+    // The initial state is a bitstring; its bytes are read directly.
+    for (i, &c) in tokens.next_token().iter().enumerate() {
         match c {
-            '0' => (),
-            '1' => { set.insert(i as i64); }
+            b'0' => (),
+            b'1' => { set.insert(i as i64); }
             _ => unreachable!(),
         }
     }
 
-    while { let tmp = q; q -= 1; tmp } > 0 {
-        let query = read_query();
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for _ in 0..q {
+        let query = read_query(&mut tokens);
         match query {
             Query::Insert {k} => {set.insert(k);},
             Query::Remove {k} => {set.remove(&k);},
-            Query::Exists {k} => {println!("{}", set.contains(&k) as i32);},
-            Query::Next {k} => {
-                println!("{}", 
-                match set.range(..=k).next_back() {
-                    Some(k) => k,
-                    None => &-1
-                });
-            },
-            Query::Previous {k} => {
-                println!("{}", 
-                match set.range(k..).next() {
-                    Some(k) => k,
-                    None => &-1
-                });
-            }
+            Query::Exists {k} => writeln!(out, "{}", set.contains(&k) as i32)?,
+            Query::Next {k} => writeln!(out, "{}", set.range(..=k).next_back().unwrap_or(&-1))?,
+            Query::Previous {k} => writeln!(out, "{}", set.range(k..).next().unwrap_or(&-1))?,
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,157 @@
+use std::io::{self, BufRead};
+
+/// Montgomery arithmetic modulo an odd `n < 2^64`.
+///
+/// Values are kept in Montgomery form `x R mod n` with `R = 2^64`, so a
+/// modular product needs two 64x64 multiplications and a shift instead of
+/// a `u128` division.
+#[derive(Clone, Copy, Debug)]
+pub struct Montgomery {
+    n: u64,
+    /// `n^{-1} mod 2^64`.
+    n_inv: u64,
+    /// `R^2 mod n`, used to convert into Montgomery form.
+    r2: u64,
+}
+
+impl Montgomery {
+    pub fn new(n: u64) -> Self {
+        assert!(n % 2 == 1, "Montgomery form needs an odd modulus");
+        // Newton's iteration doubles the number of correct low bits each step.
+        let mut n_inv = n;
+        for _ in 0..5 {
+            n_inv = n_inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(n_inv)));
+        }
+        let r = ((1u128 << 64) % n as u128) as u64;
+        let r2 = (r as u128 * r as u128 % n as u128) as u64;
+        Self { n, n_inv, r2 }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.n
+    }
+
+    /// `t R^{-1} mod n` for `t < n R`: subtracting `m n` with
+    /// `m = t n^{-1} mod R` clears the low word exactly.
+    fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.n_inv);
+        let mn = m as u128 * self.n as u128;
+        let (t_hi, mn_hi) = ((t >> 64) as u64, (mn >> 64) as u64);
+        if t_hi >= mn_hi { t_hi - mn_hi } else { t_hi.wrapping_sub(mn_hi).wrapping_add(self.n) }
+    }
+
+    pub fn to_mont(&self, x: u64) -> u64 {
+        self.reduce((x % self.n) as u128 * self.r2 as u128)
+    }
+
+    pub fn from_mont(&self, x: u64) -> u64 {
+        self.reduce(x as u128)
+    }
+
+    /// Product of two values in Montgomery form.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let (s, overflow) = a.overflowing_add(b);
+        if overflow || s >= self.n { s.wrapping_sub(self.n) } else { s }
+    }
+
+    /// `base^exp` for `base` in Montgomery form; the result is too.
+    pub fn pow(&self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = self.to_mont(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+/// `a b mod m` for any `m >= 1`. Moduli up to `2^32` keep the product in a
+/// `u64`; larger ones widen to `u128`.
+pub fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    if m <= 1 << 32 {
+        (a % m) * (b % m) % m
+    } else {
+        (a as u128 * b as u128 % m as u128) as u64
+    }
+}
+
+/// `base^exp mod m` for any `m >= 1`.
+///
+/// Large odd moduli go through Montgomery form, which replaces the `u128`
+/// division of every step by multiplications; the one-off setup is cheap
+/// next to the ~128 products of an exponentiation. Everything else uses
+/// `mul_mod_u64`.
+pub fn pow_mod_u64(base: u64, mut exp: u64, m: u64) -> u64 {
+    if m > 1 << 32 && m % 2 == 1 {
+        let mont = Montgomery::new(m);
+        return mont.from_mont(mont.pow(mont.to_mont(base), exp));
+    }
+    let mut base = base % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u64(result, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller-Rabin for all `n < 2^64`, using a base set known
+/// to have no strong pseudoprimes in that range.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022].iter().all(|&a| {
+        if a % n == 0 {
+            return true;
+        }
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let q: usize = first_line.trim().parse().expect("Failed to parse q");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(q);
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let n: u64 = line.trim().parse().expect("Failed to parse n");
+        output.push(if is_prime(n) { "Yes" } else { "No" });
+    }
+    println!("{}", output.join("\n"));
+}
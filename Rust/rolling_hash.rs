@@ -0,0 +1,136 @@
+use std::io::{self, BufRead};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The Mersenne prime `2^61 - 1`. Reduction needs only shifts and masks,
+/// and with a random base two distinct strings of length `n` collide with
+/// probability about `n / 2^61`.
+const MOD: u64 = (1 << 61) - 1;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    let t = a as u128 * b as u128;
+    // `2^61 = 1`, so the high part folds back onto the low one.
+    let x = (t >> 61) as u64 + (t as u64 & MOD);
+    if x >= MOD { x - MOD } else { x }
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let x = a + b;
+    if x >= MOD { x - MOD } else { x }
+}
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// A random base in `[2^32, MOD)`, seeded from the clock so that an
+/// anti-hash test cannot be prepared against it.
+pub fn random_base() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+    // splitmix64 spreads the low-entropy seed over every bit.
+    let mut z = nanos.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    (1 << 32) + z % (MOD - (1 << 32))
+}
+
+/// The hash of a string together with its length, which is what
+/// concatenation needs: `h(ab) = h(a) base^{|b|} + h(b)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Hash {
+    pub value: u64,
+    pub len: usize,
+}
+
+/// Polynomial hashes of every substring of a fixed string in `O(1)` each,
+/// after `O(n)` preprocessing.
+#[derive(Clone, Debug)]
+pub struct RollingHash {
+    base: u64,
+    /// `prefix[i]` is the hash of `s[..i]`.
+    prefix: Vec<u64>,
+    /// `power[i] = base^i`.
+    power: Vec<u64>,
+}
+
+impl RollingHash {
+    /// Hashes `s` with `base`; strings are only comparable under the same base.
+    pub fn new<T: Copy + Into<u64>>(s: &[T], base: u64) -> Self {
+        let mut prefix = Vec::with_capacity(s.len() + 1);
+        let mut power = Vec::with_capacity(s.len() + 1);
+        prefix.push(0);
+        power.push(1);
+        for &c in s {
+            // Offset by one so that a zero character still changes the hash.
+            prefix.push(add_mod(mul_mod(prefix[prefix.len() - 1], base), c.into() + 1));
+            power.push(mul_mod(power[power.len() - 1], base));
+        }
+        Self { base, prefix, power }
+    }
+
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The hash of `s[l..r]`.
+    pub fn substring(&self, l: usize, r: usize) -> Hash {
+        let value = add_mod(self.prefix[r], MOD - mul_mod(self.prefix[l], self.power[r - l]));
+        Hash { value, len: r - l }
+    }
+
+    /// The hash of the concatenation `a b`.
+    pub fn concat(&self, a: Hash, b: Hash) -> Hash {
+        let shift = self.power.get(b.len).copied().unwrap_or_else(|| pow_mod(self.base, b.len as u64));
+        Hash { value: add_mod(mul_mod(a.value, shift), b.value), len: a.len + b.len }
+    }
+
+    /// The length of the longest common prefix of `s[i..]` and `s[j..]`, by
+    /// binary search over substring hashes in `O(log n)`.
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        let (mut lo, mut hi) = (0, self.len() - i.max(j));
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if self.substring(i, i + mid) == self.substring(j, j + mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// Compares `s[i..]` and `s[j..]` lexicographically through their common
+    /// prefix; `s` is needed for the first differing character.
+    pub fn compare_suffixes<T: Ord>(&self, s: &[T], i: usize, j: usize) -> std::cmp::Ordering {
+        let k = self.lcp(i, j);
+        s.get(i + k).cmp(&s.get(j + k))
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+    let s = s.trim().as_bytes();
+
+    // --- Output ---
+    // Z Algorithm through hashing: `z[i]` is the common prefix of `s` and `s[i..]`.
+    let rh = RollingHash::new(s, random_base());
+    let output: Vec<String> = (0..s.len()).map(|i| rh.lcp(0, i).to_string()).collect();
+    println!("{}", output.join(" "));
+}
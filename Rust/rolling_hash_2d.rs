@@ -0,0 +1,238 @@
+use std::io::{self, BufRead};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The Mersenne prime `2^61 - 1`. Reduction needs only shifts and masks,
+/// and with a random base two distinct strings of length `n` collide with
+/// probability about `n / 2^61`.
+const MOD: u64 = (1 << 61) - 1;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    let t = a as u128 * b as u128;
+    // `2^61 = 1`, so the high part folds back onto the low one.
+    let x = (t >> 61) as u64 + (t as u64 & MOD);
+    if x >= MOD { x - MOD } else { x }
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let x = a + b;
+    if x >= MOD { x - MOD } else { x }
+}
+
+/// A random base in `[2^32, MOD)`, seeded from the clock so that an
+/// anti-hash test cannot be prepared against it. Different `stream`s give
+/// independent bases from the same clock reading.
+pub fn random_base(stream: u64) -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+    // splitmix64 spreads the low-entropy seed over every bit.
+    let mut z = nanos.wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E3779B97F4A7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    (1 << 32) + z % (MOD - (1 << 32))
+}
+
+/// Polynomial hashes of every axis-aligned submatrix in `O(1)` each, after
+/// `O(HW)` preprocessing.
+///
+/// The matrix is read as the bivariate polynomial
+/// `sum a[i][j] x^{H-1-i} y^{W-1-j}` at independent random bases `x`, `y`,
+/// so two different `h x w` blocks collide with probability about
+/// `(h + w) / 2^61`.
+#[derive(Clone, Debug)]
+pub struct RollingHash2d {
+    cols: usize,
+    /// `prefix[i * (cols + 1) + j]` is the hash of the block `[0, i) x [0, j)`.
+    prefix: Vec<u64>,
+    row_power: Vec<u64>,
+    col_power: Vec<u64>,
+}
+
+impl RollingHash2d {
+    /// Hashes a non-empty rectangular `grid` with the bases `(x, y)`;
+    /// matrices are only comparable under the same bases.
+    pub fn new<T: Copy + Into<u64>>(grid: &[Vec<T>], (x, y): (u64, u64)) -> Self {
+        let (rows, cols) = (grid.len(), grid[0].len());
+        let w = cols + 1;
+        let mut prefix = vec![0; (rows + 1) * w];
+        for i in 0..rows {
+            for j in 0..cols {
+                // Inclusion-exclusion of the block above, the block to the
+                // left and their overlap, each shifted into place.
+                let up = mul_mod(prefix[i * w + j + 1], x);
+                let left = mul_mod(prefix[(i + 1) * w + j], y);
+                let both = mul_mod(mul_mod(prefix[i * w + j], x), y);
+                // Offset by one so that a zero entry still changes the hash.
+                let cell = grid[i][j].into() + 1;
+                prefix[(i + 1) * w + j + 1] = add_mod(add_mod(up, left), add_mod(MOD - both, cell));
+            }
+        }
+        let powers = |base: u64, len: usize| {
+            let mut p = vec![1; len + 1];
+            for k in 1..=len {
+                p[k] = mul_mod(p[k - 1], base);
+            }
+            p
+        };
+        Self { cols, prefix, row_power: powers(x, rows), col_power: powers(y, cols) }
+    }
+
+    /// The hash of the block of rows `[r1, r2)` and columns `[c1, c2)`.
+    pub fn block(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> u64 {
+        let w = self.cols + 1;
+        let (px, py) = (self.row_power[r2 - r1], self.col_power[c2 - c1]);
+        let whole = self.prefix[r2 * w + c2];
+        let above = mul_mod(self.prefix[r1 * w + c2], px);
+        let left = mul_mod(self.prefix[r2 * w + c1], py);
+        let corner = mul_mod(mul_mod(self.prefix[r1 * w + c1], px), py);
+        add_mod(add_mod(whole, corner), MOD - add_mod(above, left))
+    }
+}
+
+/// Reads `rows` lines of bytes into a grid.
+fn read_grid<I: Iterator<Item = io::Result<String>>>(lines: &mut I, rows: usize) -> Vec<Vec<u8>> {
+    (0..rows).map(|_| lines.next().unwrap().expect("Failed to read a grid row").trim().as_bytes().to_vec()).collect()
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `H W` and the text grid, then `h w` and the pattern grid.
+    let line = lines.next().unwrap().expect("Failed to read the dimensions");
+    let mut parts = line.split_whitespace();
+    let big_h: usize = parts.next().unwrap().parse().expect("Failed to parse H");
+    let big_w: usize = parts.next().unwrap().parse().expect("Failed to parse W");
+    let text = read_grid(&mut lines, big_h);
+    let line = lines.next().unwrap().expect("Failed to read the dimensions");
+    let mut parts = line.split_whitespace();
+    let h: usize = parts.next().unwrap().parse().expect("Failed to parse h");
+    let w: usize = parts.next().unwrap().parse().expect("Failed to parse w");
+    let pattern = read_grid(&mut lines, h);
+
+    // --- Output ---
+    // Every top-left corner where the pattern occurs, in row-major order.
+    let bases = (random_base(0), random_base(1));
+    let target = RollingHash2d::new(&pattern, bases).block(0, 0, h, w);
+    let hashed = RollingHash2d::new(&text, bases);
+    let mut output = Vec::new();
+    for i in (0..big_h + 1).take_while(|&i| i + h <= big_h) {
+        for j in (0..big_w + 1).take_while(|&j| j + w <= big_w) {
+            if hashed.block(i, j, i + h, j + w) == target {
+                output.push(format!("{} {}", i, j));
+            }
+        }
+    }
+    println!("{}", output.len());
+    if !output.is_empty() {
+        println!("{}", output.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        /// A base drawn like `random_base`, but reproducible.
+        fn base(&mut self) -> u64 {
+            (1 << 32) + self.next() % (MOD - (1 << 32))
+        }
+    }
+
+    fn random_grid(rng: &mut Rng, rows: usize, cols: usize, sigma: usize) -> Vec<Vec<u8>> {
+        (0..rows).map(|_| (0..cols).map(|_| rng.below(sigma) as u8).collect()).collect()
+    }
+
+    fn sub(grid: &[Vec<u8>], r: usize, c: usize, h: usize, w: usize) -> Vec<Vec<u8>> {
+        grid[r..r + h].iter().map(|row| row[c..c + w].to_vec()).collect()
+    }
+
+    #[test]
+    fn equal_blocks_hash_equal() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let (rows, cols) = (1 + rng.below(6), 1 + rng.below(6));
+            // Two letters (zero included) so that equal blocks are common.
+            let grid = random_grid(&mut rng, rows, cols, 2);
+            let bases = (rng.base(), rng.base());
+            let hashed = RollingHash2d::new(&grid, bases);
+            let (h, w) = (1 + rng.below(rows), 1 + rng.below(cols));
+            for r in 0..=rows - h {
+                for c in 0..=cols - w {
+                    let block = sub(&grid, r, c, h, w);
+                    // The same block hashed on its own, away from any offset.
+                    let alone = RollingHash2d::new(&block, bases).block(0, 0, h, w);
+                    assert_eq!(hashed.block(r, c, r + h, c + w), alone);
+                    for r2 in 0..=rows - h {
+                        for c2 in 0..=cols - w {
+                            if block == sub(&grid, r2, c2, h, w) {
+                                assert_eq!(alone, hashed.block(r2, c2, r2 + h, c2 + w));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distinct_blocks_do_not_collide() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        let grid = random_grid(&mut rng, 8, 8, 3);
+        for round in 0..100 {
+            // A few clock-seeded bases too, as the binary draws them.
+            let bases = if round < 4 {
+                (random_base(2 * round), random_base(2 * round + 1))
+            } else {
+                (rng.base(), rng.base())
+            };
+            let hashed = RollingHash2d::new(&grid, bases);
+            for (h, w) in [(1, 1), (2, 3), (3, 2), (4, 4)] {
+                let mut seen = std::collections::HashMap::new();
+                for r in 0..=8 - h {
+                    for c in 0..=8 - w {
+                        let block = sub(&grid, r, c, h, w);
+                        let previous = seen.insert(hashed.block(r, c, r + h, c + w), block.clone());
+                        assert!(previous.is_none_or(|p| p == block), "collision for {:?}", bases);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transposed_patterns_differ() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        for _ in 0..500 {
+            let n = 2 + rng.below(4);
+            let pattern = random_grid(&mut rng, n, n, 2);
+            let transposed: Vec<Vec<u8>> = (0..n).map(|i| (0..n).map(|j| pattern[j][i]).collect()).collect();
+            if pattern == transposed {
+                continue;
+            }
+            let (x, y) = (rng.base(), rng.base());
+            let hash = |grid: &[Vec<u8>], bases| RollingHash2d::new(grid, bases).block(0, 0, n, n);
+            assert_ne!(hash(&pattern, (x, y)), hash(&transposed, (x, y)));
+            // Swapping the bases is exactly transposing, which is why they
+            // must be drawn independently.
+            assert_eq!(hash(&pattern, (x, y)), hash(&transposed, (y, x)));
+            assert_eq!(hash(&pattern, (x, x)), hash(&transposed, (x, x)));
+        }
+    }
+}
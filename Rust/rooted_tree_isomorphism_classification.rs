@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+/// A tree given by an undirected edge list.
+#[derive(Debug)]
+pub struct Tree {
+    adj: Vec<Vec<usize>>,
+}
+
+impl Tree {
+    /// Creates a new Tree from a given size and a list of undirected edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        Self { adj }
+    }
+
+    /// Returns the parent of every node (the root is its own parent) and an
+    /// order in which every parent comes before its children.
+    fn bfs(&self, root: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut parent = vec![usize::MAX; self.adj.len()];
+        let mut order = vec![root];
+        parent[root] = root;
+
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in &self.adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+
+        (parent, order)
+    }
+
+    /// Returns the one or two centroids of the tree.
+    pub fn centroids(&self) -> Vec<usize> {
+        let n = self.adj.len();
+        let (parent, order) = self.bfs(0);
+        let mut subtree_size = vec![1; n];
+        let mut largest_child = vec![0; n];
+        for &u in order.iter().rev().take(n - 1) {
+            subtree_size[parent[u]] += subtree_size[u];
+            largest_child[parent[u]] = largest_child[parent[u]].max(subtree_size[u]);
+        }
+
+        // A centroid leaves no component larger than half the tree once removed.
+        (0..n)
+            .filter(|&u| largest_child[u].max(n - subtree_size[u]) * 2 <= n)
+            .collect()
+    }
+}
+
+/// Assigns canonical ids to rooted trees (AHU algorithm).
+///
+/// Two rooted subtrees get the same id exactly when they are isomorphic:
+/// a subtree is identified by the sorted ids of its children, and each
+/// distinct tuple is interned to the next unused id. Ids are stable across
+/// calls on the same `TreeHasher`, so different trees can be compared.
+#[derive(Debug, Default)]
+pub struct TreeHasher {
+    ids: HashMap<Vec<usize>, usize>,
+}
+
+impl TreeHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct subtree classes seen so far.
+    pub fn num_classes(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns the class id of every subtree of `tree` rooted at `root`.
+    pub fn rooted(&mut self, tree: &Tree, root: usize) -> Vec<usize> {
+        let (parent, order) = tree.bfs(root);
+        let mut children_ids = vec![Vec::new(); tree.adj.len()];
+        let mut id = vec![0; tree.adj.len()];
+
+        // Children are finished before their parents in reverse BFS order.
+        for &u in order.iter().rev() {
+            let mut key = std::mem::take(&mut children_ids[u]);
+            key.sort_unstable();
+            let next_id = self.ids.len();
+            id[u] = *self.ids.entry(key).or_insert(next_id);
+            if u != root {
+                children_ids[parent[u]].push(id[u]);
+            }
+        }
+
+        id
+    }
+
+    /// Returns a class id for the unrooted `tree`, which is the same for two
+    /// trees exactly when they are isomorphic.
+    ///
+    /// The tree is rooted at its centroid; when there are two centroids the
+    /// smaller of the two resulting root ids is taken.
+    pub fn unrooted(&mut self, tree: &Tree) -> usize {
+        tree.centroids()
+            .into_iter()
+            .map(|c| self.rooted(tree, c)[c])
+            .min()
+            .unwrap()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    // Node 0 is the root; `p_i` is given for i = 1..n.
+    let mut edges = Vec::with_capacity(n - 1);
+    if n > 1 {
+        let line = lines.next().unwrap().expect("Failed to read the parents");
+        for (i, p) in line.split_whitespace().enumerate() {
+            let p: usize = p.parse().expect("Failed to parse p");
+            edges.push((p, i + 1));
+        }
+    }
+
+    // --- Classification and Output ---
+    let mut hasher = TreeHasher::new();
+    let ids = hasher.rooted(&Tree::new(n, &edges), 0);
+
+    println!("{}", hasher.num_classes());
+    let output: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+    println!("{}", output.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// A random tree on `n` nodes with shuffled labels.
+    fn random_tree(rng: &mut Rng, n: usize) -> Tree {
+        let mut label: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            label.swap(i, rng.below(i + 1));
+        }
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (label[rng.below(v)], label[v])).collect();
+        Tree::new(n, &edges)
+    }
+
+    /// The parenthesised form of the subtree of `u` away from `parent`,
+    /// children sorted.
+    fn encode(tree: &Tree, u: usize, parent: usize) -> String {
+        let mut children: Vec<String> = tree.adj[u].iter().filter(|&&v| v != parent).map(|&v| encode(tree, v, u)).collect();
+        children.sort_unstable();
+        format!("({})", children.concat())
+    }
+
+    /// The smallest encoding over every choice of root.
+    fn canonical(tree: &Tree) -> String {
+        (0..tree.adj.len()).map(|r| encode(tree, r, usize::MAX)).min().unwrap()
+    }
+
+    #[test]
+    fn unrooted_agrees_with_the_minimum_over_all_roots() {
+        let mut rng = Rng(0x9E3779B97F4A7C15);
+        let mut hasher = TreeHasher::new();
+        // Small trees, so that many of them are isomorphic.
+        let trees: Vec<Tree> = (0..400)
+            .map(|_| {
+                let n = 1 + rng.below(9);
+                random_tree(&mut rng, n)
+            })
+            .collect();
+        let classes: Vec<usize> = trees.iter().map(|t| hasher.unrooted(t)).collect();
+        let forms: Vec<String> = trees.iter().map(canonical).collect();
+        for i in 0..trees.len() {
+            for j in 0..i {
+                assert_eq!(classes[i] == classes[j], forms[i] == forms[j], "trees {} and {}", i, j);
+            }
+        }
+    }
+}
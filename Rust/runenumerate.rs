@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The Mersenne prime `2^61 - 1`, as in the rolling hash.
+const MOD: u64 = (1 << 61) - 1;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    let t = a as u128 * b as u128;
+    // `2^61 = 1`, so the high part folds back onto the low one.
+    let x = (t >> 61) as u64 + (t as u64 & MOD);
+    if x >= MOD { x - MOD } else { x }
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    let x = a + b;
+    if x >= MOD { x - MOD } else { x }
+}
+
+/// A random base in `[2^32, MOD)`, seeded from the clock so that an
+/// anti-hash test cannot be prepared against it.
+fn random_base() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+    // splitmix64 spreads the low-entropy seed over every bit.
+    let mut z = nanos.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^= z >> 31;
+    (1 << 32) + z % (MOD - (1 << 32))
+}
+
+/// Substring hashes of a fixed byte string, answering common prefix and
+/// suffix lengths by binary search in `O(log n)`.
+struct LcpOracle<'a> {
+    s: &'a [u8],
+    prefix: Vec<u64>,
+    power: Vec<u64>,
+}
+
+impl<'a> LcpOracle<'a> {
+    fn new(s: &'a [u8]) -> Self {
+        let base = random_base();
+        let mut prefix = vec![0];
+        let mut power = vec![1];
+        for &c in s {
+            prefix.push(add_mod(mul_mod(prefix[prefix.len() - 1], base), c as u64 + 1));
+            power.push(mul_mod(power[power.len() - 1], base));
+        }
+        Self { s, prefix, power }
+    }
+
+    fn hash(&self, l: usize, r: usize) -> u64 {
+        add_mod(self.prefix[r], MOD - mul_mod(self.prefix[l], self.power[r - l]))
+    }
+
+    /// The longest common prefix of `s[i..]` and `s[j..]`.
+    fn lcp(&self, i: usize, j: usize) -> usize {
+        let (mut lo, mut hi) = (0, self.s.len() - i.max(j));
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if self.hash(i, i + mid) == self.hash(j, j + mid) { lo = mid } else { hi = mid - 1 }
+        }
+        lo
+    }
+
+    /// The longest common suffix of `s[..i]` and `s[..j]`.
+    fn lcs(&self, i: usize, j: usize) -> usize {
+        let (mut lo, mut hi) = (0, i.min(j));
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if self.hash(i - mid, i) == self.hash(j - mid, j) { lo = mid } else { hi = mid - 1 }
+        }
+        lo
+    }
+
+    /// Compares `s[i..]` with `s[j..]`, with the character order reversed
+    /// when `flip` is set. A proper prefix is smaller under both orders.
+    fn compare(&self, i: usize, j: usize, flip: bool) -> Ordering {
+        let k = self.lcp(i, j);
+        match (self.s.get(i + k), self.s.get(j + k)) {
+            (Some(a), Some(b)) if flip => b.cmp(a),
+            (a, b) => a.cmp(&b),
+        }
+    }
+}
+
+/// Duval's algorithm: splits `s` into its Lyndon factorization
+/// `w_1 >= w_2 >= ... >= w_k` of Lyndon words in `O(n)`, returned as the
+/// start of each factor.
+///
+/// `s[i..j]` is kept as a power of a Lyndon word followed by a prefix of it;
+/// a larger next character merges everything into one Lyndon word, a smaller
+/// one emits the complete copies of the current word.
+pub fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut starts = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            k = if s[k] < s[j] { i } else { k + 1 };
+            j += 1;
+        }
+        while i <= k {
+            starts.push(i);
+            i += j - k;
+        }
+    }
+    starts
+}
+
+/// Every run (maximal repetition) of `s` as `(t, l, r)`: `s[l..r]` has
+/// smallest period `t`, `r - l >= 2t`, and neither end can be extended.
+/// Sorted by `t`, then `l`.
+///
+/// By the runs theorem, every run contains a Lyndon root `s[i..i + t]` that
+/// is the longest Lyndon word starting at `i` under one of the two character
+/// orders. That word ends at the next suffix smaller than `s[i..]`, found
+/// with a stack; extending it both ways by common prefix and suffix yields
+/// the run, or nothing if it is shorter than `2t`. This is `O(n log n)`
+/// with hashed comparisons.
+pub fn runs(s: &[u8]) -> Vec<(usize, usize, usize)> {
+    let n = s.len();
+    let oracle = LcpOracle::new(s);
+    let mut found = Vec::new();
+    for flip in [false, true] {
+        let mut stack: Vec<usize> = Vec::new();
+        for i in (0..n).rev() {
+            while stack.last().is_some_and(|&j| oracle.compare(i, j, flip) == Ordering::Less) {
+                stack.pop();
+            }
+            let j = stack.last().copied().unwrap_or(n);
+            stack.push(i);
+
+            let t = j - i;
+            let l = i - oracle.lcs(i, j);
+            let r = j + if j < n { oracle.lcp(i, j) } else { 0 };
+            if r - l >= 2 * t {
+                found.push((t, l, r));
+            }
+        }
+    }
+    found.sort_unstable();
+    found.dedup();
+    found
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+
+    // --- Output ---
+    let runs = runs(s.trim().as_bytes());
+    let mut output = Vec::with_capacity(runs.len() + 1);
+    output.push(runs.len().to_string());
+    for (t, l, r) in runs {
+        output.push(format!("{} {} {}", t, l, r));
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,208 @@
+use std::io::{self, BufRead};
+
+/// Represents a directed graph using an adjacency list.
+#[derive(Debug)]
+pub struct Graph {
+    /// `adj[i]` contains the heads of the edges leaving node `i`.
+    adj: Vec<Vec<usize>>,
+}
+
+/// The strongly connected components of a graph.
+#[derive(Debug)]
+pub struct Scc {
+    /// `component[v]` is the component of node `v`. Components are numbered
+    /// in topological order: every edge goes to the same or a later component.
+    component: Vec<usize>,
+    count: usize,
+}
+
+impl Graph {
+    /// Creates a new Graph from a given size and a list of edges.
+    pub fn new(size: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+        }
+
+        Self { adj }
+    }
+
+    pub fn size(&self) -> usize {
+        self.adj.len()
+    }
+
+    pub fn neighbours(&self, v: usize) -> &[usize] {
+        &self.adj[v]
+    }
+
+    /// Finds the strongly connected components with Tarjan's algorithm.
+    /// The DFS is iterative so long paths don't overflow the stack.
+    pub fn scc(&self) -> Scc {
+        let n = self.adj.len();
+        let mut index = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut component = vec![usize::MAX; n];
+        let mut count = 0;
+        let mut next_index = 0;
+
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            // Each call frame is `(node, index of the next edge to look at)`.
+            let mut frames = vec![(root, 0)];
+            index[root] = next_index;
+            low[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (u, ref mut next_edge)) = frames.last_mut() {
+                if let Some(&v) = self.adj[u].get(*next_edge) {
+                    *next_edge += 1;
+                    if index[v] == usize::MAX {
+                        // Tree edge: descend.
+                        index[v] = next_index;
+                        low[v] = next_index;
+                        next_index += 1;
+                        stack.push(v);
+                        on_stack[v] = true;
+                        frames.push((v, 0));
+                    } else if on_stack[v] {
+                        low[u] = low[u].min(index[v]);
+                    }
+                    continue;
+                }
+
+                // All edges of `u` are done.
+                frames.pop();
+                if let Some(&(p, _)) = frames.last() {
+                    low[p] = low[p].min(low[u]);
+                }
+                if low[u] == index[u] {
+                    // `u` is the root of a component: pop it off the stack.
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component[w] = count;
+                        if w == u {
+                            break;
+                        }
+                    }
+                    count += 1;
+                }
+            }
+        }
+
+        // Tarjan finishes components in reverse topological order.
+        for c in component.iter_mut() {
+            *c = count - 1 - *c;
+        }
+
+        Scc { component, count }
+    }
+}
+
+impl Scc {
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn component(&self, v: usize) -> usize {
+        self.component[v]
+    }
+
+    /// Returns the nodes of every component, components in topological order.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = vec![Vec::new(); self.count];
+        for (v, &c) in self.component.iter().enumerate() {
+            groups[c].push(v);
+        }
+        groups
+    }
+
+    /// Returns the condensation of `graph`: the DAG with one node per
+    /// component and an edge between two components whenever some edge of
+    /// `graph` connects them. Parallel edges are merged and self-loops
+    /// dropped, and the numbering is topological, so DAG passes (longest
+    /// path, reachability, 2-SAT assignment) can run on it directly.
+    pub fn condensation(&self, graph: &Graph) -> Graph {
+        let mut adj = vec![Vec::new(); self.count];
+        for u in 0..graph.size() {
+            for &v in graph.neighbours(u) {
+                let (cu, cv) = (self.component[u], self.component[v]);
+                if cu != cv {
+                    adj[cu].push(cv);
+                }
+            }
+        }
+        for list in adj.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Graph { adj }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+
+    let edges: Vec<(usize, usize)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            (a, b)
+        })
+        .collect();
+
+    // --- Output ---
+    // Components are already numbered topologically.
+    let scc = Graph::new(n, &edges).scc();
+    let mut output = vec![scc.count().to_string()];
+    for group in scc.groups() {
+        let nodes: Vec<String> = group.iter().map(|v| v.to_string()).collect();
+        output.push(format!("{} {}", group.len(), nodes.join(" ")));
+    }
+    println!("{}", output.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condensation_merges_parallel_edges_and_drops_self_loops() {
+        // Components {0, 1}, {2} and {3, 4}. 0 -> 2 and 1 -> 2 are parallel
+        // in the condensation, as are 1 -> 3 and 0 -> 4; 2 and 3 have
+        // self-loops.
+        let edges = [(0, 1), (1, 0), (0, 2), (0, 2), (1, 2), (2, 2), (2, 3), (3, 3), (3, 4), (4, 3), (1, 3), (0, 4)];
+        let graph = Graph::new(5, &edges);
+        let scc = graph.scc();
+        assert_eq!(scc.count(), 3);
+        let (a, b, c) = (scc.component(0), scc.component(2), scc.component(3));
+        assert_eq!(scc.component(1), a);
+        assert_eq!(scc.component(4), c);
+
+        let dag = scc.condensation(&graph);
+        assert_eq!(dag.size(), 3);
+        assert_eq!(dag.neighbours(a), [b, c]);
+        assert_eq!(dag.neighbours(b), [c]);
+        assert!(dag.neighbours(c).is_empty());
+        for u in 0..dag.size() {
+            assert!(dag.neighbours(u).iter().all(|&v| u < v));
+        }
+    }
+}
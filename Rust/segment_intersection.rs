@@ -0,0 +1,159 @@
+use std::io::{self, BufRead};
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The orientation of `a -> b -> c`: positive for a left turn, negative for
+/// a right turn, zero when collinear.
+pub fn orient(a: Point, b: Point, c: Point) -> i128 {
+    (b - a).cross(c - a)
+}
+
+/// Whether `p` lies on the closed segment `ab`.
+pub fn on_segment(p: Point, a: Point, b: Point) -> bool {
+    orient(a, b, p) == 0 && (a - p).dot(b - p) <= 0
+}
+
+/// Whether the closed segments `ab` and `cd` share at least one point,
+/// including touching endpoints and collinear overlaps. Only the signs of
+/// exact orientations are used.
+pub fn segments_intersect(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let (d1, d2) = (orient(a, b, c).signum(), orient(a, b, d).signum());
+    let (d3, d4) = (orient(c, d, a).signum(), orient(c, d, b).signum());
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    on_segment(c, a, b) || on_segment(d, a, b) || on_segment(a, c, d) || on_segment(b, c, d)
+}
+
+/// A Fenwick tree of counts over `0..n`.
+struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    fn new(n: usize) -> Self {
+        Self { tree: vec![0; n + 1] }
+    }
+
+    fn add(&mut self, i: usize, delta: i64) {
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// The sum over `0..i`.
+    fn prefix(&self, mut i: usize) -> i64 {
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+}
+
+/// The number of pairs of a horizontal and a vertical segment that share a
+/// point, by a sweep over `x` in `O((H + V) log(H + V))`.
+///
+/// Horizontals are `(y, x1, x2)` and verticals `(x, y1, y2)`, with closed
+/// ranges. At each `x` the sweep first inserts the horizontals starting
+/// there, then counts the active `y` within every vertical's range, and only
+/// then removes the horizontals ending there, so touching counts.
+pub fn count_hv_intersections(horizontals: &[(i64, i64, i64)], verticals: &[(i64, i64, i64)]) -> u64 {
+    let mut ys: Vec<i64> = horizontals.iter().map(|&(y, _, _)| y).collect();
+    ys.sort_unstable();
+    ys.dedup();
+    let slot = |y: i64| ys.partition_point(|&v| v < y);
+
+    // Events as `(x, kind, index)`: insert, then query, then remove.
+    let mut events = Vec::with_capacity(2 * horizontals.len() + verticals.len());
+    for (i, &(_, x1, x2)) in horizontals.iter().enumerate() {
+        events.push((x1.min(x2), 0, i));
+        events.push((x1.max(x2), 2, i));
+    }
+    for (i, &(x, _, _)) in verticals.iter().enumerate() {
+        events.push((x, 1, i));
+    }
+    events.sort_unstable();
+
+    let mut active = Fenwick::new(ys.len());
+    let mut count = 0;
+    for (_, kind, i) in events {
+        match kind {
+            0 => active.add(slot(horizontals[i].0), 1),
+            2 => active.add(slot(horizontals[i].0), -1),
+            _ => {
+                let (_, y1, y2) = verticals[i];
+                let (lo, hi) = (y1.min(y2), y1.max(y2));
+                let below_hi = ys.partition_point(|&v| v <= hi);
+                count += (active.prefix(below_hi) - active.prefix(slot(lo))) as u64;
+            }
+        }
+    }
+    count
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n`, then `n` axis-parallel segments `x1 y1 x2 y2`. Segments with
+    // `y1 = y2` count as horizontal, all others as vertical.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let (mut horizontals, mut verticals) = (Vec::new(), Vec::new());
+    for _ in 0..n {
+        let line = lines.next().unwrap().expect("Failed to read a segment");
+        let v: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a coordinate")).collect();
+        let (a, b) = (Point::new(v[0], v[1]), Point::new(v[2], v[3]));
+        if a.y == b.y {
+            horizontals.push((a.y, a.x, b.x));
+        } else {
+            assert_eq!(a.x, b.x, "segments must be axis-parallel");
+            verticals.push((a.x, a.y, b.y));
+        }
+    }
+
+    // --- Output ---
+    // Intersecting horizontal-vertical pairs.
+    println!("{}", count_hv_intersections(&horizontals, &verticals));
+}
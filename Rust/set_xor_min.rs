@@ -0,0 +1,152 @@
+use std::io::{self, BufRead};
+
+const NONE: u32 = u32::MAX;
+
+/// A trie over the alphabet `0..sigma`, with every node in one arena.
+///
+/// Words are slices of symbol indices. Each node counts the words passing
+/// through it, so prefix counts are a walk, and removing a word only
+/// decrements counts: nodes whose count drops to zero stay allocated and
+/// behave as absent.
+#[derive(Clone, Debug)]
+pub struct Trie {
+    sigma: usize,
+    /// `children[v * sigma + c]` is the child of `v` along `c`, or `NONE`.
+    children: Vec<u32>,
+    /// Words in the subtree of each node, counting multiplicity.
+    passing: Vec<usize>,
+    /// Words ending exactly at each node.
+    ending: Vec<usize>,
+}
+
+impl Trie {
+    /// The root of every trie.
+    pub const ROOT: usize = 0;
+
+    /// An empty trie over `sigma` symbols.
+    pub fn new(sigma: usize) -> Self {
+        Self { sigma, children: vec![NONE; sigma], passing: vec![0], ending: vec![0] }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.passing.len()
+    }
+
+    /// The child of `v` along `c`, if it holds any word.
+    pub fn child(&self, v: usize, c: usize) -> Option<usize> {
+        let u = self.children[v * self.sigma + c];
+        (u != NONE && self.passing[u as usize] > 0).then_some(u as usize)
+    }
+
+    /// The node spelled by `word` from the root, if some stored word has it
+    /// as a prefix.
+    pub fn walk(&self, word: &[usize]) -> Option<usize> {
+        word.iter().try_fold(Self::ROOT, |v, &c| self.child(v, c))
+    }
+
+    /// Adds one copy of `word` and returns its final node.
+    pub fn insert(&mut self, word: &[usize]) -> usize {
+        let mut v = Self::ROOT;
+        self.passing[v] += 1;
+        for &c in word {
+            let slot = v * self.sigma + c;
+            if self.children[slot] == NONE {
+                self.children[slot] = self.passing.len() as u32;
+                self.children.extend(std::iter::repeat_n(NONE, self.sigma));
+                self.passing.push(0);
+                self.ending.push(0);
+            }
+            v = self.children[slot] as usize;
+            self.passing[v] += 1;
+        }
+        self.ending[v] += 1;
+        v
+    }
+
+    /// Removes one copy of `word`; returns whether there was one.
+    pub fn remove(&mut self, word: &[usize]) -> bool {
+        let Some(end) = self.walk(word) else {
+            return false;
+        };
+        if self.ending[end] == 0 {
+            return false;
+        }
+        self.ending[end] -= 1;
+        let mut v = Self::ROOT;
+        self.passing[v] -= 1;
+        for &c in word {
+            v = self.children[v * self.sigma + c] as usize;
+            self.passing[v] -= 1;
+        }
+        true
+    }
+
+    /// Copies of `word` stored.
+    pub fn count(&self, word: &[usize]) -> usize {
+        self.walk(word).map_or(0, |v| self.ending[v])
+    }
+
+    /// Stored words that start with `prefix`, counting multiplicity.
+    pub fn count_prefix(&self, prefix: &[usize]) -> usize {
+        self.walk(prefix).map_or(0, |v| self.passing[v])
+    }
+}
+
+/// Values are read as words of this many bits, most significant first.
+const BITS: usize = 30;
+
+fn bits_of(x: u32) -> [usize; BITS] {
+    std::array::from_fn(|i| (x >> (BITS - 1 - i) & 1) as usize)
+}
+
+/// `min(x ^ y)` over the stored `y`: at every level, follow the bit of `x`
+/// if possible, which keeps the higher bits of the xor at zero.
+fn xor_min(trie: &Trie, x: u32) -> u32 {
+    let mut v = Trie::ROOT;
+    let mut result = 0;
+    for (i, b) in bits_of(x).into_iter().enumerate() {
+        v = match trie.child(v, b) {
+            Some(u) => u,
+            None => {
+                result |= 1 << (BITS - 1 - i);
+                trie.child(v, b ^ 1).unwrap()
+            }
+        };
+    }
+    result
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let q: usize = first_line.trim().parse().expect("Failed to parse q");
+
+    // --- Query Processing and Output ---
+    // The set holds each value at most once.
+    let mut trie = Trie::new(2);
+    let mut output = Vec::new();
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: u8 = parts.next().unwrap().parse().expect("Failed to parse t");
+        let x: u32 = parts.next().unwrap().parse().expect("Failed to parse x");
+        let word = bits_of(x);
+        match t {
+            0 => {
+                if trie.count(&word) == 0 {
+                    trie.insert(&word);
+                }
+            }
+            1 => {
+                trie.remove(&word);
+            }
+            2 => output.push(xor_min(&trie, x).to_string()),
+            _ => unreachable!(),
+        }
+    }
+    println!("{}", output.join("\n"));
+}
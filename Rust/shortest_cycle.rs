@@ -0,0 +1,175 @@
+// Minimum weight cycle of a graph with non-negative edge weights.
+//
+// Input: `N M D` (`D = 1` for a directed graph, `0` for an undirected one),
+// then `M` edges `u v w`. Prints `-1` if the graph is acyclic, otherwise the
+// total weight and the number of edges of a lightest cycle followed by its
+// edge indices in order, one per line.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::io::{self, BufRead};
+
+/// Represents a weighted graph using an adjacency list.
+#[derive(Debug)]
+struct Graph {
+    /// `adj[i]` contains triples `(neighbor, weight, edge_index)` for node `i`.
+    adj: Vec<Vec<(usize, i64, usize)>>,
+    edges: Vec<(usize, usize, i64)>,
+    directed: bool,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct DijState {
+    node: usize,
+    distance: i64,
+}
+
+impl Ord for DijState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for DijState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Graph {
+    /// Creates a new Graph from a given size and a list of edges.
+    /// Undirected edges are stored in both directions under the same index.
+    pub fn new(size: usize, edges: &[(usize, usize, i64)], directed: bool) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            adj[u].push((v, w, i));
+            if !directed && u != v {
+                adj[v].push((u, w, i));
+            }
+        }
+
+        Self { adj, edges: edges.to_vec(), directed }
+    }
+
+    /// Runs Dijkstra from `source` without using the edge `banned`.
+    ///
+    /// Returns the distances (`i64::MAX` when unreachable) and, for every
+    /// reached node other than the source, the index of the last edge on its
+    /// shortest path tree path.
+    fn dijkstra(&self, source: usize, banned: Option<usize>) -> (Vec<i64>, Vec<usize>) {
+        let n = self.adj.len();
+        let mut dist = vec![i64::MAX; n];
+        let mut prev_edge = vec![usize::MAX; n];
+        let mut pq = BinaryHeap::new();
+        dist[source] = 0;
+        pq.push(DijState { node: source, distance: 0 });
+
+        while let Some(s) = pq.pop() {
+            if s.distance > dist[s.node] {
+                continue;
+            }
+            for &(neighbour, weight, index) in &self.adj[s.node] {
+                if Some(index) == banned {
+                    continue;
+                }
+                if dist[neighbour] > s.distance + weight {
+                    dist[neighbour] = s.distance + weight;
+                    prev_edge[neighbour] = index;
+                    pq.push(DijState { node: neighbour, distance: dist[neighbour] });
+                }
+            }
+        }
+
+        (dist, prev_edge)
+    }
+
+    /// Returns the edge indices of the tree path from `source` to `target`.
+    fn path_edges(&self, source: usize, target: usize, prev_edge: &[usize]) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut node = target;
+        while node != source {
+            let index = prev_edge[node];
+            path.push(index);
+            // Step to the other endpoint of the edge we arrived by.
+            let (u, v, _) = self.edges[index];
+            node = if v == node { u } else { v };
+        }
+        path.reverse();
+        path
+    }
+
+    /// Finds a minimum weight cycle, returning its weight and edge indices in
+    /// cycle order, or `None` if the graph has no cycle.
+    ///
+    /// Directed graphs run Dijkstra from every node `s` and close the cycle
+    /// with an edge back into `s`. In undirected graphs an edge could simply
+    /// be walked back and forth, so instead every edge `(u, v)` is removed in
+    /// turn and closed with the shortest remaining `u`-`v` path.
+    pub fn shortest_cycle(&self) -> Option<(i64, Vec<usize>)> {
+        let mut best: Option<(i64, Vec<usize>)> = None;
+
+        if self.directed {
+            for s in 0..self.adj.len() {
+                let (dist, prev_edge) = self.dijkstra(s, None);
+                for (index, &(u, v, w)) in self.edges.iter().enumerate() {
+                    if v != s || dist[u] == i64::MAX {
+                        continue;
+                    }
+                    if best.as_ref().is_none_or(|b| dist[u] + w < b.0) {
+                        let mut cycle = self.path_edges(s, u, &prev_edge);
+                        cycle.push(index);
+                        best = Some((dist[u] + w, cycle));
+                    }
+                }
+            }
+        } else {
+            for (index, &(u, v, w)) in self.edges.iter().enumerate() {
+                let (dist, prev_edge) = self.dijkstra(u, Some(index));
+                if dist[v] == i64::MAX {
+                    continue;
+                }
+                if best.as_ref().is_none_or(|b| dist[v] + w < b.0) {
+                    let mut cycle = self.path_edges(u, v, &prev_edge);
+                    cycle.push(index);
+                    best = Some((dist[v] + w, cycle));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let m: usize = parts.next().unwrap().parse().expect("Failed to parse m");
+    let d: u8 = parts.next().unwrap().parse().expect("Failed to parse d");
+
+    let edges: Vec<(usize, usize, i64)> = (0..m)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            let w: i64 = parts.next().unwrap().parse().expect("Failed to parse w");
+            (u, v, w)
+        })
+        .collect();
+
+    // --- Cycle Finding and Output ---
+    match Graph::new(n, &edges, d == 1).shortest_cycle() {
+        None => println!("-1"),
+        Some((weight, cycle)) => {
+            println!("{} {}", weight, cycle.len());
+            for index in cycle {
+                println!("{}", index);
+            }
+        }
+    }
+}
@@ -3,89 +3,260 @@ use std::collections::BinaryHeap;
 use std::cmp::Ordering;
 
 
-/// Represents a directed graph using an adjacency list.
+/// The weight type of the shortest-path engine: totally ordered, with a
+/// zero and an addition that saturates at `infinity`, the "unreachable"
+/// sentinel. A path whose length reaches `infinity` is treated as
+/// unreachable, so adversarial weight sums cannot overflow.
+pub trait Cost: Copy + Ord {
+    // Required methods
+    fn zero() -> Self;
+    fn infinity() -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {$(
+        impl Cost for $t {
+            fn zero() -> Self { 0 }
+            fn infinity() -> Self { <$t>::MAX }
+            fn saturating_add(self, other: Self) -> Self { <$t>::saturating_add(self, other) }
+        }
+    )*};
+}
+
+impl_cost!(u32, u64, u128, i64);
+
+/// Lexicographic pairs, e.g. `(distance, hops)` to prefer the shortest path
+/// with the fewest edges. The pair is infinite as soon as its first
+/// component is.
+impl<A: Cost, B: Cost> Cost for (A, B) {
+    fn zero() -> Self { (A::zero(), B::zero()) }
+    fn infinity() -> Self { (A::infinity(), B::infinity()) }
+    fn saturating_add(self, other: Self) -> Self {
+        let first = self.0.saturating_add(other.0);
+        if first == A::infinity() {
+            return Self::infinity();
+        }
+        (first, self.1.saturating_add(other.1))
+    }
+}
+
+/// Represents a directed or undirected graph using an adjacency list.
+/// An undirected edge is stored in both directions under its one input index.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug)]
-struct Graph {
-    /// `adj[i]` contains a list of pairs `(neighbor, weight)` for node `i`.
-    adj: Vec<Vec<(usize, i64)>>,
+pub struct Graph<W: Cost> {
+    /// `adj[i]` contains a list of triples `(neighbor, weight, edge index)` for node `i`.
+    adj: Vec<Vec<(usize, W, usize)>>,
+    /// `radj[i]` contains a list of pairs `(neighbor, weight)` for the edges entering node `i`.
+    radj: Vec<Vec<(usize, W)>>,
+    edges: Vec<(usize, usize, W)>,
+    directed: bool,
     prev_node: Vec<usize>,
+    /// `prev_edge[i]` is the index of the edge that last improved the distance to node `i`.
+    prev_edge: Vec<usize>,
     source_node: usize,
-    shortest_path_vec: Vec<i64>,
+    shortest_path_vec: Vec<W>,
+}
+
+/// A shortest path from the source, both as its vertex sequence and as the
+/// indices (in input order) of the edges it uses.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPath<W> {
+    pub distance: W,
+    /// The vertices from the source to the target, both included.
+    pub nodes: Vec<usize>,
+    /// `edges[i]` goes from `nodes[i]` to `nodes[i + 1]`.
+    pub edges: Vec<usize>,
 }
 
 #[derive(Eq, PartialEq, Debug)]
-struct DijState{
+struct DijState<W> {
     node: usize,
-    distance: i64,
+    distance: W,
 }
 
-impl Ord for DijState {
+impl<W: Ord> Ord for DijState<W> {
     fn cmp(&self, other: &Self) -> Ordering {
         other.distance.cmp(&self.distance)
     }
 }
 
-impl PartialOrd for DijState {
+impl<W: Ord> PartialOrd for DijState<W> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         // Some(other.distance.cmp(&self.distance))
         Some(self.cmp(other))
     }
 }
 
-impl Graph {
-    /// Creates a new Graph from a given size and a list of edges.
-    pub fn new(size: usize, edges: &[(usize, usize, i64)], source_node: usize) -> Self {
+impl<W: Cost> Graph<W> {
+    /// Creates a new directed Graph from a given size and a list of edges.
+    pub fn new(size: usize, edges: &[(usize, usize, W)], source_node: usize) -> Self {
+        Self::build(size, edges, source_node, true)
+    }
+
+    /// Creates a new undirected Graph from a given size and a list of edges.
+    pub fn new_undirected(size: usize, edges: &[(usize, usize, W)], source_node: usize) -> Self {
+        Self::build(size, edges, source_node, false)
+    }
+
+    fn build(size: usize, edges: &[(usize, usize, W)], source_node: usize, directed: bool) -> Self {
         let mut adj = vec![Vec::new(); size];
+        let mut radj = vec![Vec::new(); size];
         let prev_node = vec![usize::MAX; size];
-        let shortest_path_vec = vec![i64::MAX; size];
-        for &(u, v, w) in edges {
-            adj[u].push((v, w));
+        let prev_edge = vec![usize::MAX; size];
+        let shortest_path_vec = vec![W::infinity(); size];
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            adj[u].push((v, w, i));
+            radj[v].push((u, w));
+            if !directed {
+                adj[v].push((u, w, i));
+                radj[u].push((v, w));
+            }
         }
 
-        Self { adj, prev_node, source_node, shortest_path_vec}
+        Self { adj, radj, edges: edges.to_vec(), directed, prev_node, prev_edge, source_node, shortest_path_vec}
     }
 
-    pub fn get_shortest_path(&self, v: usize) -> Option<(i64, usize, Vec<(usize, usize)>)> {
-        let distance = self.shortest_path_vec[v]; 
-        if distance == i64::MAX {
+    /// Returns the distance to `v`, the number of edges on a shortest path and
+    /// that path as `(from, to)` pairs, or `None` if `v` is unreachable.
+    pub fn get_shortest_path(&self, v: usize) -> Option<(W, usize, Vec<(usize, usize)>)> {
+        let path = self.shortest_path(v)?;
+        let ans: Vec<(usize, usize)> = path.nodes.windows(2).map(|w| (w[0], w[1])).collect();
+
+        Some((path.distance, ans.len(), ans))
+    }
+
+    /// Returns a shortest path from the source to `v` as vertices and edge
+    /// indices, or `None` if `v` is unreachable. Must be called after
+    /// `populate_all_shortest_path`.
+    pub fn shortest_path(&self, v: usize) -> Option<ShortestPath<W>> {
+        let distance = self.shortest_path_vec[v];
+        if distance == W::infinity() {
             return None
         }
-        
-        let mut ans = Vec::<(usize, usize)>::new();
+
+        let mut nodes = vec![v];
+        let mut edges = Vec::new();
         let mut node = v;
 
         while node != self.source_node {
-            ans.push((self.prev_node[node], node));
+            edges.push(self.prev_edge[node]);
             node = self.prev_node[node];
+            nodes.push(node);
         }
-        
-        ans.reverse();
 
-        Some((distance, ans.len(), ans))
+        nodes.reverse();
+        edges.reverse();
+
+        Some(ShortestPath { distance, nodes, edges })
     }
 
     pub fn populate_all_shortest_path(&mut self) {
         let mut pq = BinaryHeap::new();
-        self.shortest_path_vec[self.source_node] = 0;
+        self.shortest_path_vec[self.source_node] = W::zero();
         self.prev_node[self.source_node] = self.source_node;
 
-        pq.push(DijState {node: self.source_node, distance: 0 });
+        pq.push(DijState {node: self.source_node, distance: W::zero() });
 
         while !pq.is_empty() {
             if let Some(s) = pq.pop() {
                 if s.distance > self.shortest_path_vec[s.node] {
                     continue;
                 }
-                for (neighbour, weight) in &self.adj[s.node] {
-                    if self.shortest_path_vec[*neighbour] > s.distance + *weight {
-                        self.shortest_path_vec[*neighbour] = s.distance + *weight;
+                for (neighbour, weight, edge) in &self.adj[s.node] {
+                    let candidate = s.distance.saturating_add(*weight);
+                    if self.shortest_path_vec[*neighbour] > candidate {
+                        self.shortest_path_vec[*neighbour] = candidate;
                         self.prev_node[*neighbour] = s.node;
+                        self.prev_edge[*neighbour] = *edge;
                         pq.push(DijState {node: *neighbour, distance: self.shortest_path_vec[*neighbour]});
                     }
                 }
             }
         }
     }
+
+    /// Returns the distance from every node to `target` (`W::infinity()` if it
+    /// can't reach it), running Dijkstra on the reversed edges.
+    pub fn distances_to(&self, target: usize) -> Vec<W> {
+        let mut dist = vec![W::infinity(); self.radj.len()];
+        let mut pq = BinaryHeap::new();
+        dist[target] = W::zero();
+        pq.push(DijState {node: target, distance: W::zero() });
+
+        while let Some(s) = pq.pop() {
+            if s.distance > dist[s.node] {
+                continue;
+            }
+            for (neighbour, weight) in &self.radj[s.node] {
+                let candidate = s.distance.saturating_add(*weight);
+                if dist[*neighbour] > candidate {
+                    dist[*neighbour] = candidate;
+                    pq.push(DijState {node: *neighbour, distance: dist[*neighbour]});
+                }
+            }
+        }
+        dist
+    }
+
+    /// Returns the subgraph of everything lying on at least one shortest path
+    /// from the source to `target` (the "shortest-path DAG"), as a flag per
+    /// node and the indices of the edges in input order, or `None` if
+    /// `target` is unreachable. Must be called after `populate_all_shortest_path`.
+    ///
+    /// An edge `(u, v, w)` is on such a path exactly when
+    /// `dist(s, u) + w + dist(v, t) == dist(s, t)`, and likewise for nodes;
+    /// an undirected edge may also be used from `v` to `u`.
+    pub fn shortest_path_dag(&self, target: usize) -> Option<(Vec<bool>, Vec<usize>)> {
+        let total = self.shortest_path_vec[target];
+        if total == W::infinity() {
+            return None
+        }
+        let to_target = self.distances_to(target);
+        let from_source = &self.shortest_path_vec;
+
+        let nodes = (0..self.adj.len())
+            .map(|v| from_source[v].saturating_add(to_target[v]) == total)
+            .collect();
+        let edges = self.edges
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(u, v, w))| {
+                // A sum involving the sentinel saturates and never equals `total`.
+                let tight = |a: usize, b: usize| from_source[a].saturating_add(w).saturating_add(to_target[b]) == total;
+                tight(u, v) || (!self.directed && tight(v, u))
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        Some((nodes, edges))
+    }
+}
+
+/// Graphviz rendering for debugging, e.g. to dump the graph of a failing
+/// stress test. Nodes and edges appear in index and input order, so the
+/// output only depends on the graph.
+#[cfg(feature = "debug-viz")]
+impl<W: Cost + std::fmt::Debug> Graph<W> {
+    /// Returns the graph in DOT format, every edge labelled with its input
+    /// index and weight.
+    pub fn to_dot(&self) -> String {
+        use std::fmt::Write;
+
+        let (kind, arrow) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+        let mut out = format!("{} Graph {{\n", kind);
+        for v in 0..self.adj.len() {
+            writeln!(out, "    {};", v).unwrap();
+        }
+        for (i, (u, v, w)) in self.edges.iter().enumerate() {
+            let label = format!("{}: {:?}", i, w).replace('"', "\\\"");
+            writeln!(out, "    {} {} {} [label=\"{}\"];", u, arrow, v, label).unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
 }
 
 fn main() {
@@ -114,7 +285,7 @@ fn main() {
         })
         .collect();
     
-    let mut g = Graph::new(n, &edges, s);
+    let mut g = Graph::<i64>::new(n, &edges, s);
     g.populate_all_shortest_path();
 
     match g.get_shortest_path(t) { 
@@ -126,4 +297,69 @@ fn main() {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX: i64 = i64::MAX;
+
+    #[test]
+    fn weights_near_the_sentinel_do_not_overflow() {
+        // 0 -> 1 -> 2 overflows without saturation; 0 -> 3 -> 2 sums to MAX - 1.
+        let edges = [(0, 1, MAX - 1), (1, 2, 5), (0, 3, MAX / 2), (3, 2, MAX / 2)];
+        let mut g = Graph::new(4, &edges, 0);
+        g.populate_all_shortest_path();
+        assert_eq!(g.shortest_path(1).map(|p| p.distance), Some(MAX - 1));
+        let path = g.shortest_path(2).unwrap();
+        assert_eq!(path.distance, MAX - 1);
+        assert_eq!(path.nodes, vec![0, 3, 2]);
+        assert_eq!(path.edges, vec![2, 3]);
+    }
+
+    #[test]
+    fn a_sum_reaching_the_sentinel_is_unreachable() {
+        let edges = [(0, 1, MAX - 3), (1, 2, 3), (2, 3, 0)];
+        let mut g = Graph::new(4, &edges, 0);
+        g.populate_all_shortest_path();
+        assert_eq!(g.shortest_path(1).map(|p| p.distance), Some(MAX - 3));
+        assert_eq!(g.shortest_path(2), None);
+        assert_eq!(g.shortest_path(3), None);
+        assert_eq!(g.get_shortest_path(3), None);
+        assert_eq!(g.shortest_path_dag(2), None);
+
+        // Backwards, the same sum must not make 0 reach 2 either.
+        assert_eq!(g.distances_to(2)[0], MAX);
+    }
+
+    #[test]
+    fn dag_membership_with_saturated_sums() {
+        let edges = [
+            (0, 1, 1),
+            (1, 2, 1),
+            (0, 2, 2),
+            // 3 is reachable, but only with a sum that saturates on the way to 2.
+            (0, 3, MAX - 1),
+            (3, 2, MAX - 1),
+            // 4 can reach 2 but is unreachable from 0.
+            (4, 2, 1),
+            // 5 is reachable only through a sum that saturates.
+            (2, 5, MAX),
+        ];
+        let mut g = Graph::new(6, &edges, 0);
+        g.populate_all_shortest_path();
+        let (nodes, dag_edges) = g.shortest_path_dag(2).unwrap();
+        assert_eq!(nodes, vec![true, true, true, false, false, false]);
+        assert_eq!(dag_edges, vec![0, 1, 2]);
+
+        // Undirected: 3 hangs off the path at distance 1 + (MAX - 1) = MAX.
+        let edges = [(0, 1, 1), (1, 2, 1), (1, 3, MAX - 1)];
+        let mut g = Graph::new_undirected(4, &edges, 0);
+        g.populate_all_shortest_path();
+        assert_eq!(g.shortest_path(3), None);
+        let (nodes, dag_edges) = g.shortest_path_dag(2).unwrap();
+        assert_eq!(nodes, vec![true, true, true, false]);
+        assert_eq!(dag_edges, vec![0, 1]);
+    }
+}
@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead};
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// Which part of the plane `p` points into, in increasing `atan2` order on
+/// `(-pi, pi]`: below the x axis, the positive x axis (and the origin, whose
+/// argument is taken as 0), above the x axis, then the negative x axis.
+/// Within the open halves, the cross product alone orders directions.
+fn half(p: Point) -> u8 {
+    match (p.y.signum(), p.x < 0) {
+        (-1, _) => 0,
+        (0, false) => 1,
+        (1, _) => 2,
+        _ => 3,
+    }
+}
+
+/// Compares `a` and `b` by argument as `atan2(y, x)` would, exactly and
+/// without floating point. Parallel vectors of the same direction compare
+/// equal.
+pub fn cmp_by_argument(a: Point, b: Point) -> Ordering {
+    half(a).cmp(&half(b)).then_with(|| 0.cmp(&a.cross(b)))
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let mut points: Vec<Point> = (0..n)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read a point");
+            let mut parts = line.split_whitespace();
+            let x = parts.next().unwrap().parse().expect("Failed to parse x");
+            let y = parts.next().unwrap().parse().expect("Failed to parse y");
+            Point::new(x, y)
+        })
+        .collect();
+
+    // --- Output ---
+    points.sort_by(|&a, &b| cmp_by_argument(a, b));
+    let output: Vec<String> = points.iter().map(|p| format!("{} {}", p.x, p.y)).collect();
+    println!("{}", output.join("\n"));
+}
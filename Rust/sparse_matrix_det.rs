@@ -0,0 +1,167 @@
+use std::io::{self, BufRead};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MOD: u64 = 998244353;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    base %= MOD;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MOD;
+        }
+        base = base * base % MOD;
+        exp >>= 1;
+    }
+    result
+}
+
+/// xorshift64 seeded from the clock, so an adversarial input cannot be
+/// tuned against fixed projections.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn from_clock() -> Self {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+        Rng(nanos | 1)
+    }
+
+    /// A uniformly random non-zero residue.
+    pub fn nonzero(&mut self) -> u64 {
+        loop {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            let x = self.0 % MOD;
+            if x != 0 {
+                return x;
+            }
+        }
+    }
+}
+
+/// The shortest linear recurrence `s_i = c_1 s_{i-1} + ... + c_L s_{i-L}`
+/// satisfied by `s`, by Berlekamp-Massey in `O(|s|^2)`; returns `[c_1, ..., c_L]`.
+pub fn berlekamp_massey(s: &[u64]) -> Vec<u64> {
+    // `cur` and `prev` hold connection polynomials `1 - c_1 x - ...`.
+    let mut cur = vec![1u64];
+    let mut prev = vec![1u64];
+    let mut prev_disc = 1;
+    let mut shift = 1;
+
+    for i in 0..s.len() {
+        let disc = cur.iter().enumerate().fold(0, |acc, (j, &c)| (acc + c * s[i - j]) % MOD);
+        if disc == 0 {
+            shift += 1;
+            continue;
+        }
+        // `cur -= (disc / prev_disc) x^shift prev` cancels the discrepancy.
+        let coef = disc * pow_mod(prev_disc, MOD - 2) % MOD;
+        let old = cur.clone();
+        if cur.len() < prev.len() + shift {
+            cur.resize(prev.len() + shift, 0);
+        }
+        for (j, &p) in prev.iter().enumerate() {
+            cur[j + shift] = (cur[j + shift] + MOD - coef * p % MOD) % MOD;
+        }
+        if 2 * (old.len() - 1) <= i {
+            prev = old;
+            prev_disc = disc;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+
+    cur[1..].iter().map(|&c| (MOD - c) % MOD).collect()
+}
+
+/// An `n x n` matrix given by its non-zero entries `(row, col, value)`.
+pub struct SparseMatrix {
+    n: usize,
+    entries: Vec<(usize, usize, u64)>,
+}
+
+impl SparseMatrix {
+    pub fn new(n: usize, entries: Vec<(usize, usize, u64)>) -> Self {
+        Self { n, entries }
+    }
+
+    /// `A x` in `O(n + K)` for `K` stored entries.
+    fn apply(&self, x: &[u64]) -> Vec<u64> {
+        let mut y = vec![0; self.n];
+        for &(i, j, v) in &self.entries {
+            y[i] = (y[i] + v * x[j]) % MOD;
+        }
+        y
+    }
+
+    /// The minimal polynomial of the black box `A` projected by random `u`,
+    /// `v`, as the recurrence returned by `berlekamp_massey`. Its degree is
+    /// at most that of the true minimal polynomial, and equal to it with
+    /// high probability.
+    pub fn minimal_polynomial(&self, rng: &mut Rng) -> Vec<u64> {
+        let u: Vec<u64> = (0..self.n).map(|_| rng.nonzero()).collect();
+        let mut v: Vec<u64> = (0..self.n).map(|_| rng.nonzero()).collect();
+        let mut seq = Vec::with_capacity(2 * self.n);
+        for _ in 0..2 * self.n {
+            seq.push(u.iter().zip(&v).fold(0, |acc, (&a, &b)| (acc + a * b) % MOD));
+            v = self.apply(&v);
+        }
+        berlekamp_massey(&seq)
+    }
+
+    /// The determinant by Wiedemann's algorithm in `O(n (n + K))`; correct
+    /// with probability about `1 - 2n / MOD`.
+    ///
+    /// Scaling the columns by a random diagonal `D` makes the minimal
+    /// polynomial of `B = A D` its characteristic polynomial with high
+    /// probability when `A` is non-singular. A recurrence of full length
+    /// `n` then has constant term `-c_n = (-1)^n det B`; a shorter one means
+    /// `A` is singular.
+    pub fn determinant(&self, rng: &mut Rng) -> u64 {
+        if self.n == 0 {
+            return 1;
+        }
+        let d: Vec<u64> = (0..self.n).map(|_| rng.nonzero()).collect();
+        let scaled = SparseMatrix::new(self.n, self.entries.iter().map(|&(i, j, v)| (i, j, v * d[j] % MOD)).collect());
+        let rec = scaled.minimal_polynomial(rng);
+        if rec.len() < self.n {
+            return 0;
+        }
+
+        let mut det_b = rec[self.n - 1];
+        if self.n.is_multiple_of(2) {
+            det_b = (MOD - det_b) % MOD;
+        }
+        let det_d = d.iter().fold(1, |acc, &x| acc * x % MOD);
+        det_b * pow_mod(det_d, MOD - 2) % MOD
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let k: usize = parts.next().unwrap().parse().expect("Failed to parse k");
+
+    let entries = (0..k)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an entry line");
+            let mut parts = line.split_whitespace();
+            let a: usize = parts.next().unwrap().parse().expect("Failed to parse a");
+            let b: usize = parts.next().unwrap().parse().expect("Failed to parse b");
+            let c: u64 = parts.next().unwrap().parse().expect("Failed to parse c");
+            (a, b, c)
+        })
+        .collect();
+
+    // --- Output ---
+    let mut rng = Rng::from_clock();
+    println!("{}", SparseMatrix::new(n, entries).determinant(&mut rng));
+}
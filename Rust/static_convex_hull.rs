@@ -0,0 +1,118 @@
+use std::io::{self, BufRead};
+
+/// A point (or vector) with integer coordinates. Products are taken in
+/// `i128`, so any coordinates whose differences fit in an `i64` are exact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// `self x other`: positive when `other` is counterclockwise of `self`.
+    pub fn cross(self, other: Point) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    pub fn dot(self, other: Point) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The orientation of `a -> b -> c`: positive for a left turn, negative for
+/// a right turn, zero when collinear.
+pub fn orient(a: Point, b: Point, c: Point) -> i128 {
+    (b - a).cross(c - a)
+}
+
+/// The convex hull of `points` in counterclockwise order, starting from the
+/// leftmost (then lowest) point, by Andrew's monotone chain in `O(n log n)`.
+///
+/// Duplicates are merged. With `keep_collinear`, points lying on a hull edge
+/// are kept as vertices; otherwise only strict corners are. If every point
+/// is collinear, the hull is the two extremes, or with `keep_collinear`
+/// every point in sorted order.
+pub fn convex_hull(points: &[Point], keep_collinear: bool) -> Vec<Point> {
+    let mut pts = points.to_vec();
+    pts.sort_unstable();
+    pts.dedup();
+    if pts.len() <= 2 {
+        return pts;
+    }
+    if keep_collinear && pts.iter().all(|&p| orient(pts[0], pts[1], p) == 0) {
+        return pts;
+    }
+
+    // A turn that is not strictly left (or, keeping collinear points, that
+    // is strictly right) means the middle point is not a hull vertex.
+    let bad = |turn: i128| if keep_collinear { turn < 0 } else { turn <= 0 };
+    let mut hull: Vec<Point> = Vec::with_capacity(2 * pts.len());
+    for &p in &pts {
+        while hull.len() >= 2 && bad(orient(hull[hull.len() - 2], hull[hull.len() - 1], p)) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The upper chain, right to left, never pops into the lower one.
+    let lower_len = hull.len() + 1;
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && bad(orient(hull[hull.len() - 2], hull[hull.len() - 1], p)) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // The upper chain ends back at the first point.
+    hull.pop();
+    hull
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::new();
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read n");
+        let n: usize = line.trim().parse().expect("Failed to parse n");
+        let points: Vec<Point> = (0..n)
+            .map(|_| {
+                let line = lines.next().unwrap().expect("Failed to read a point");
+                let mut parts = line.split_whitespace();
+                let x = parts.next().unwrap().parse().expect("Failed to parse x");
+                let y = parts.next().unwrap().parse().expect("Failed to parse y");
+                Point::new(x, y)
+            })
+            .collect();
+
+        let hull = convex_hull(&points, false);
+        output.push(hull.len().to_string());
+        for p in hull {
+            output.push(format!("{} {}", p.x, p.y));
+        }
+    }
+    println!("{}", output.join("\n"));
+}
@@ -1,3 +1,4 @@
+use std::env;
 use std::io::{self, BufRead};
 use std::ops::Range;
 
@@ -7,57 +8,31 @@ pub trait Monoid {
     fn op(a: &Self, b: &Self) -> Self;
 }
 
+/// Marks a missing child in the node arena.
+const NONE: u32 = u32::MAX;
+
 /// Represents a single node in the segment tree.
-/// Using std::ops::Range makes the [start, end) interval explicit and provides useful methods.
+/// Children are indices into the tree's node arena, or `NONE` for a leaf; a node's
+/// [start, end) interval is not stored but recomputed on the way down from the root.
 #[derive(Debug)]
 struct Node<T: Monoid + Clone> {
     value: T,
-    range: Range<usize>,
-    left: Option<Box<Node<T>>>,
-    right: Option<Box<Node<T>>>,
-}
-
-impl<T: Monoid + Clone> Node<T> {
-    /// Creates a new node and recursively builds its children to cover the given range.
-    fn new(range: Range<usize>) -> Option<Box<Node<T>>> {
-        // An empty range results in no node.
-        if range.is_empty() {
-            return None;
-        }
-
-        let mut node = Box::new(Node {
-            value: T::id(),
-            range: range.clone(),
-            left: None,
-            right: None,
-        });
-
-        // If the range represents more than one element, it's an internal node, so create children.
-        if range.len() > 1 {
-            let mid = range.start + range.len() / 2;
-            node.left = Node::new(range.start..mid);
-            node.right = Node::new(mid..range.end);
-        }
-
-        Some(node)
-    }
-
-    /// Recalculates this node's value based on its children's values.
-    /// This is called after a child's value has been updated.
-    fn update_value(&mut self) {
-        let left_val = self.left.as_ref().map_or(T::id(), |n| n.value.clone());
-        let right_val = self.right.as_ref().map_or(T::id(), |n| n.value.clone());
-        self.value = T::op(&left_val, &right_val);
-    }
+    left: u32,
+    right: u32,
 }
 
 /// A segment tree implementation for sum queries on a range.
+///
+/// Every node lives in one `Vec` and refers to its children by `u32` index instead
+/// of owning them through a `Box`, so building the tree is a single allocation and
+/// the whole structure can be inspected as a flat array.
 #[derive(Debug)]
 pub struct SegmentTree<T>
 where
     T: Monoid + Clone
 {
-    root: Option<Box<Node<T>>>,
+    /// The root, if any, is `nodes[0]`; nodes are stored in preorder.
+    nodes: Vec<Node<T>>,
     size: usize,
 }
 
@@ -67,10 +42,45 @@ where
 {
     /// Creates a new SegmentTree for a sequence of `size` elements.
     pub fn new(size: usize) -> Self {
-        Self {
-            root: Node::new(0..size),
+        let mut tree = Self {
+            nodes: Vec::with_capacity(2 * size),
             size,
+        };
+        // An empty range results in no node.
+        if size > 0 {
+            tree.build(0..size);
+        }
+        tree
+    }
+
+    /// Creates the nodes covering the given range and returns the index of the topmost one.
+    fn build(&mut self, range: Range<usize>) -> u32 {
+        let index = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            value: T::id(),
+            left: NONE,
+            right: NONE,
+        });
+
+        // If the range represents more than one element, it's an internal node, so create children.
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            let left = self.build(range.start..mid);
+            let right = self.build(mid..range.end);
+            let node = &mut self.nodes[index as usize];
+            node.left = left;
+            node.right = right;
         }
+
+        index
+    }
+
+    /// Recalculates a node's value based on its children's values.
+    /// This is called after a child's value has been updated.
+    fn update_value(&mut self, index: u32) {
+        let node = &self.nodes[index as usize];
+        let value = T::op(&self.nodes[node.left as usize].value, &self.nodes[node.right as usize].value);
+        self.nodes[index as usize].value = value;
     }
 
     /// Sets the value at a specific index.
@@ -79,65 +89,155 @@ where
         if index >= self.size {
             return;
         }
-        if let Some(root) = self.root.as_mut() {
-            Self::set_recursive(root, index, val);
-        }
+        self.set_recursive(0, 0..self.size, index, val);
     }
 
     /// Helper function to recursively find the correct leaf node and update values up the tree.
-    fn set_recursive(node: &mut Node<T>, index: usize, val: T) {
+    fn set_recursive(&mut self, node: u32, range: Range<usize>, index: usize, val: T) {
         // Base case: we have reached the leaf node corresponding to the index.
-        if node.range.len() == 1 {
-            node.value = val;
+        if range.len() == 1 {
+            self.nodes[node as usize].value = val;
             return;
         }
 
         // Recursive step: determine whether to go left or right.
-        let mid = node.range.start + node.range.len() / 2;
-        // The `unwrap`s here are safe due to the invariant that non-leaf nodes always have children.
+        // Non-leaf nodes always have both children.
+        let mid = range.start + range.len() / 2;
+        let Node { left, right, .. } = self.nodes[node as usize];
         if index < mid {
-            Self::set_recursive(node.left.as_mut().unwrap(), index, val);
+            self.set_recursive(left, range.start..mid, index, val);
         } else {
-            Self::set_recursive(node.right.as_mut().unwrap(), index, val);
+            self.set_recursive(right, mid..range.end, index, val);
         }
 
         // After recursion, update the current node's value based on its children.
-        node.update_value();
+        self.update_value(node);
     }
 
     /// Returns the sum of values in the given half-open range `[start, end)`.
     pub fn get(&self, query_range: Range<usize>) -> T {
-        self.root
-            .as_ref()
-            .map_or(T::id(), |root| Self::get_recursive(root, &query_range))
+        if self.size == 0 {
+            return T::id();
+        }
+        self.get_recursive(0, 0..self.size, &query_range)
     }
 
     /// Helper function to recursively calculate the sum over a given query range.
-    fn get_recursive(node: &Node<T>, query_range: &Range<usize>) -> T {
+    fn get_recursive(&self, node: u32, range: Range<usize>, query_range: &Range<usize>) -> T {
         // Case 1: The node's range has no overlap with the query range.
-        if query_range.end <= node.range.start || query_range.start >= node.range.end {
+        if query_range.end <= range.start || query_range.start >= range.end {
             return T::id();
         }
 
+        let node = &self.nodes[node as usize];
         // Case 2: The node's range is completely contained within the query range.
-        if query_range.start <= node.range.start && query_range.end >= node.range.end {
+        if query_range.start <= range.start && query_range.end >= range.end {
             return node.value.clone();
         }
 
         // Case 3: Partial overlap. Recurse into children and sum their results.
-        let left_sum = node
-            .left
-            .as_ref()
-            .map_or(T::id(), |n| Self::get_recursive(n, query_range));
-        let right_sum = node
-            .right
-            .as_ref()
-            .map_or(T::id(), |n| Self::get_recursive(n, query_range));
+        let mid = range.start + range.len() / 2;
+        let left_sum = self.get_recursive(node.left, range.start..mid, query_range);
+        let right_sum = self.get_recursive(node.right, mid..range.end, query_range);
 
         T::op(&left_sum, &right_sum)
     }
 }
 
+/// A static range-minimum structure in `O(n)` space and `O(1)` per query.
+///
+/// The values are cut into blocks of 64. A sparse table over the block
+/// minima answers the whole blocks of a query, and inside a block, `masks[i]`
+/// is the monotonic stack of the block's prefix ending at `i` as a bitmask:
+/// the minimum of `[l, i]` within one block is at the lowest bit of
+/// `masks[i]` that is at least `l`. Ties go to the leftmost position.
+#[derive(Debug)]
+pub struct BlockRmq<T: Ord + Clone> {
+    values: Vec<T>,
+    masks: Vec<u64>,
+    /// `table[k][b]` is the position of the minimum of blocks `b..b + 2^k`.
+    table: Vec<Vec<usize>>,
+}
+
+impl<T: Ord + Clone> BlockRmq<T> {
+    const BLOCK: usize = 64;
+
+    pub fn new(values: Vec<T>) -> Self {
+        let n = values.len();
+        let mut masks = vec![0u64; n];
+        let mut stack: Vec<usize> = Vec::with_capacity(Self::BLOCK);
+        for i in 0..n {
+            if i % Self::BLOCK == 0 {
+                stack.clear();
+            }
+            let mut mask = if stack.is_empty() { 0 } else { masks[i - 1] };
+            while let Some(&top) = stack.last() {
+                if values[top] <= values[i] {
+                    break;
+                }
+                mask &= !(1 << (top % Self::BLOCK));
+                stack.pop();
+            }
+            stack.push(i);
+            masks[i] = mask | 1 << (i % Self::BLOCK);
+        }
+
+        let blocks = n.div_ceil(Self::BLOCK);
+        let block_min = (0..blocks)
+            .map(|b| {
+                let end = ((b + 1) * Self::BLOCK).min(n) - 1;
+                b * Self::BLOCK + masks[end].trailing_zeros() as usize
+            })
+            .collect();
+        let mut table: Vec<Vec<usize>> = vec![block_min];
+        let mut k = 1;
+        while (1 << k) <= blocks {
+            let prev = &table[k - 1];
+            let half = 1 << (k - 1);
+            let next = (0..=blocks - (1 << k))
+                .map(|b| Self::argmin(&values, prev[b], prev[b + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+
+        Self { values, masks, table }
+    }
+
+    fn argmin(values: &[T], i: usize, j: usize) -> usize {
+        if values[j] < values[i] { j } else { i }
+    }
+
+    /// The position of the minimum of `[l, r]`, both in one block.
+    fn in_block(&self, l: usize, r: usize) -> usize {
+        let mask = self.masks[r] & (!0u64 << (l % Self::BLOCK));
+        r - r % Self::BLOCK + mask.trailing_zeros() as usize
+    }
+
+    /// Returns the position of the minimum in the non-empty half-open range
+    /// `[start, end)`, the leftmost one on ties.
+    pub fn argmin_in(&self, start: usize, end: usize) -> usize {
+        assert!(start < end, "the range must not be empty");
+        let (l, r) = (start, end - 1);
+        let (bl, br) = (l / Self::BLOCK, r / Self::BLOCK);
+        if bl == br {
+            return self.in_block(l, r);
+        }
+        let mut best = self.in_block(l, (bl + 1) * Self::BLOCK - 1);
+        if bl + 1 < br {
+            let k = (usize::BITS - 1 - (br - bl - 1).leading_zeros()) as usize;
+            best = Self::argmin(&self.values, best, self.table[k][bl + 1]);
+            best = Self::argmin(&self.values, best, self.table[k][br - (1 << k)]);
+        }
+        Self::argmin(&self.values, best, self.in_block(br * Self::BLOCK, r))
+    }
+
+    /// Returns the minimum in the non-empty half-open range `[start, end)`.
+    pub fn get(&self, start: usize, end: usize) -> T {
+        self.values[self.argmin_in(start, end)].clone()
+    }
+}
+
 
 #[derive(Clone)]
 struct S {
@@ -159,30 +259,43 @@ fn main() {
     let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
     let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
 
-    
-    let mut st = SegmentTree::<S>::new(n);
-
-    // Read initial array values and populate the segment tree.
-    if n > 0 {
-        let initial_values: Vec<i32> = lines
+    let initial_values: Vec<i32> = if n > 0 {
+        lines
             .next()
             .unwrap()
             .split_whitespace()
             .map(|s| s.parse().expect("Failed to parse initial value"))
-            .collect();
+            .collect()
+    } else {
+        Vec::new()
+    };
 
-        for (i, &v) in initial_values.iter().enumerate() {
-            st.set(i, S {val: v});
-        }
-    }
+    let queries: Vec<(usize, usize)> = (0..q)
+        .map(|_| {
+            let query_line = lines.next().unwrap();
+            let mut parts = query_line.split_whitespace();
+            let l: usize = parts.next().unwrap().parse().expect("Failed to parse l");
+            let r: usize = parts.next().unwrap().parse().expect("Failed to parse r");
+            (l, r)
+        })
+        .collect();
 
-    // Process q queries.
-    for _ in 0..q {
-        let query_line = lines.next().unwrap();
-        let mut parts = query_line.split_whitespace();
-        let l: usize = parts.next().unwrap().parse().expect("Failed to parse l");
-        let r: usize = parts.next().unwrap().parse().expect("Failed to parse r");
+    // The backend can be picked on the command line for comparisons; the judge
+    // runs without arguments and gets the O(n) memory block decomposition.
+    let answers: Vec<i32> = match env::args().nth(1).as_deref() {
+        Some("segment-tree") => {
+            let mut st = SegmentTree::<S>::new(n);
+            for (i, &v) in initial_values.iter().enumerate() {
+                st.set(i, S {val: v});
+            }
+            queries.iter().map(|&(l, r)| st.get(l..r).val).collect()
+        }
+        _ => {
+            let rmq = BlockRmq::new(initial_values);
+            queries.iter().map(|&(l, r)| rmq.get(l, r)).collect()
+        }
+    };
 
-        println!("{}", st.get(l..r).val);
-    }
+    let output: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+    println!("{}", output.join("\n"));
 }
@@ -0,0 +1,168 @@
+use std::io::{self, BufRead};
+
+/// A step from a Stern-Brocot node to its left or right child.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dir {
+    L,
+    R,
+}
+
+/// A path from the root `1/1`, run-length encoded as `(direction, count)`
+/// with consecutive runs in alternating directions.
+pub type Path = Vec<(Dir, u64)>;
+
+/// The node `a/b` (in lowest terms) together with the bounds `l` and `r` of
+/// its subtree: every fraction strictly between them, and nothing else,
+/// lies below it, and `a/b` is their mediant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub left: (u64, u64),
+    pub right: (u64, u64),
+}
+
+impl Node {
+    /// The root `1/1` with bounds `0/1` and `1/0`.
+    pub fn root() -> Self {
+        Node { left: (0, 1), right: (1, 0) }
+    }
+
+    pub fn fraction(&self) -> (u64, u64) {
+        (self.left.0 + self.right.0, self.left.1 + self.right.1)
+    }
+
+    /// Takes `count` steps in direction `dir`. Going right `n` times moves
+    /// the left bound to `left + n right`, and symmetrically for going left.
+    pub fn walk(&mut self, dir: Dir, count: u64) {
+        match dir {
+            Dir::L => {
+                self.right = (self.right.0 + count * self.left.0, self.right.1 + count * self.left.1);
+            }
+            Dir::R => {
+                self.left = (self.left.0 + count * self.right.0, self.left.1 + count * self.right.1);
+            }
+        }
+    }
+}
+
+/// The path from the root to `a/b` (coprime, both positive), read off the
+/// continued fraction of `a/b` in `O(log max(a, b))`.
+pub fn encode(mut a: u64, mut b: u64) -> Path {
+    let mut path = Vec::new();
+    while a != b {
+        if a < b {
+            let k = (b - 1) / a;
+            path.push((Dir::L, k));
+            b -= k * a;
+        } else {
+            let k = (a - 1) / b;
+            path.push((Dir::R, k));
+            a -= k * b;
+        }
+    }
+    path
+}
+
+/// The node reached by following `path` from the root.
+pub fn decode(path: &[(Dir, u64)]) -> Node {
+    let mut node = Node::root();
+    for &(dir, count) in path {
+        node.walk(dir, count);
+    }
+    node
+}
+
+/// The first `depth` steps of `path`, or `None` if it is shorter than that.
+pub fn ancestor(path: &[(Dir, u64)], mut depth: u64) -> Option<Path> {
+    let mut prefix = Vec::new();
+    for &(dir, count) in path {
+        if depth == 0 {
+            break;
+        }
+        let take = count.min(depth);
+        prefix.push((dir, take));
+        depth -= take;
+    }
+    (depth == 0).then_some(prefix)
+}
+
+/// The lowest common ancestor of two nodes: their longest common path prefix.
+pub fn lca(p: &[(Dir, u64)], q: &[(Dir, u64)]) -> Path {
+    let mut common = Vec::new();
+    for (&(d1, c1), &(d2, c2)) in p.iter().zip(q) {
+        if d1 != d2 {
+            break;
+        }
+        common.push((d1, c1.min(c2)));
+        if c1 != c2 {
+            break;
+        }
+    }
+    common
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let kind = parts.next().unwrap();
+        let mut next = || -> u64 { parts.next().unwrap().parse().expect("Failed to parse a query argument") };
+
+        let answer = match kind {
+            "ENCODE_PATH" => {
+                let (a, b) = (next(), next());
+                let path = encode(a, b);
+                let mut words = vec![path.len().to_string()];
+                for (dir, count) in path {
+                    words.push(if dir == Dir::L { "L" } else { "R" }.to_string());
+                    words.push(count.to_string());
+                }
+                words.join(" ")
+            }
+            "DECODE_PATH" => {
+                let k = next() as usize;
+                let path: Path = (0..k)
+                    .map(|_| {
+                        let dir = if parts.next().unwrap() == "L" { Dir::L } else { Dir::R };
+                        let count: u64 = parts.next().unwrap().parse().expect("Failed to parse a run length");
+                        (dir, count)
+                    })
+                    .collect();
+                let (a, b) = decode(&path).fraction();
+                format!("{} {}", a, b)
+            }
+            "LCA" => {
+                let (a, b, c, d) = (next(), next(), next(), next());
+                let (f, g) = decode(&lca(&encode(a, b), &encode(c, d))).fraction();
+                format!("{} {}", f, g)
+            }
+            "ANCESTOR" => {
+                let (k, a, b) = (next(), next(), next());
+                match ancestor(&encode(a, b), k) {
+                    Some(path) => {
+                        let (f, g) = decode(&path).fraction();
+                        format!("{} {}", f, g)
+                    }
+                    None => "-1".to_string(),
+                }
+            }
+            "RANGE" => {
+                let (a, b) = (next(), next());
+                let node = decode(&encode(a, b));
+                format!("{} {} {} {}", node.left.0, node.left.1, node.right.0, node.right.1)
+            }
+            _ => unreachable!(),
+        };
+        output.push(answer);
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,176 @@
+use std::io::{self, BufRead};
+
+/// NTT-friendly prime with primitive root 3.
+const MOD: u64 = 998244353;
+
+fn pow_mod(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1;
+    base %= MOD;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MOD;
+        }
+        base = base * base % MOD;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place iterative number theoretic transform modulo `MOD` of length
+/// `a.len()` (a power of two).
+fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(3, (MOD - 1) / len as u64);
+        if invert {
+            w_len = pow_mod(w_len, MOD - 2);
+        }
+        // Twiddle factors for this level, shared by every chunk.
+        let mut twiddles = Vec::with_capacity(len / 2);
+        let mut w = 1;
+        for _ in 0..len / 2 {
+            twiddles.push(w);
+            w = w * w_len % MOD;
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let u = *x;
+                let v = *y * w % MOD;
+                *x = if u + v >= MOD { u + v - MOD } else { u + v };
+                *y = if u >= v { u - v } else { u + MOD - v };
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, MOD - 2);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % MOD;
+        }
+    }
+}
+
+/// The product of the polynomials `a` and `b` modulo `MOD`. Small inputs use
+/// the schoolbook product, which beats the transform below a few dozen terms.
+fn convolve(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let result_len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= 32 {
+        let mut result = vec![0; result_len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] = (result[i + j] + x * y) % MOD;
+            }
+        }
+        return result;
+    }
+
+    let size = result_len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(size, 0);
+    fb.resize(size, 0);
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, &y) in fa.iter_mut().zip(&fb) {
+        *x = *x * y % MOD;
+    }
+    ntt(&mut fa, true);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// Factorials and inverse factorials of `0..n` modulo `MOD`.
+fn factorials(n: usize) -> (Vec<u64>, Vec<u64>) {
+    let mut fact = vec![1; n.max(1)];
+    for i in 1..fact.len() {
+        fact[i] = fact[i - 1] * i as u64 % MOD;
+    }
+    let mut inv_fact = vec![1; fact.len()];
+    inv_fact[fact.len() - 1] = pow_mod(fact[fact.len() - 1], MOD - 2);
+    for i in (1..fact.len()).rev() {
+        inv_fact[i - 1] = inv_fact[i] * i as u64 % MOD;
+    }
+    (fact, inv_fact)
+}
+
+/// The coefficients of `f(x + c)` from those of `f`, by one convolution:
+/// `[x^i] f(x + c) = (1 / i!) sum_j (f_j j!) (c^{j-i} / (j - i)!)`.
+fn taylor_shift(f: &[u64], c: u64, fact: &[u64], inv_fact: &[u64]) -> Vec<u64> {
+    let n = f.len();
+    let a: Vec<u64> = f.iter().enumerate().map(|(j, &x)| x * fact[j] % MOD).rev().collect();
+    let mut b = Vec::with_capacity(n);
+    let mut power = 1;
+    for &inv in inv_fact.iter().take(n) {
+        b.push(power * inv % MOD);
+        power = power * c % MOD;
+    }
+    // With `a` reversed, the sum for `x^i` lands at index `n - 1 - i`.
+    let product = convolve(&a, &b);
+    (0..n).map(|i| product[n - 1 - i] * inv_fact[i] % MOD).collect()
+}
+
+/// The signed Stirling numbers of the first kind `s(n, 0..=n)`, i.e. the
+/// coefficients of the falling factorial `x (x - 1) ... (x - n + 1)`, in
+/// `O(n log n)`.
+///
+/// Doubling on the bits of `n`: `(x)_{2m} = (x)_m (x - m)_m`, where the second
+/// factor is a Taylor shift of the first, and `(x)_{m+1} = (x)_m (x - m)`.
+pub fn stirling_first_row(n: usize) -> Vec<u64> {
+    let (fact, inv_fact) = factorials(n + 1);
+    let mut row = vec![1];
+    let mut m = 0;
+    for bit in (0..usize::BITS - n.leading_zeros()).rev() {
+        if m > 0 {
+            let shifted = taylor_shift(&row, MOD - m as u64 % MOD, &fact, &inv_fact);
+            row = convolve(&row, &shifted);
+            m *= 2;
+        }
+        if n >> bit & 1 == 1 {
+            // Multiply by `x - m`.
+            let mut next = vec![0; row.len() + 1];
+            for (i, &c) in row.iter().enumerate() {
+                next[i + 1] = (next[i + 1] + c) % MOD;
+                next[i] = (next[i] + MOD - c * (m as u64 % MOD) % MOD) % MOD;
+            }
+            row = next;
+            m += 1;
+        }
+    }
+    row
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    // --- Output ---
+    let output: Vec<String> = stirling_first_row(n).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,287 @@
+use std::io::{self, BufRead};
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+pub struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns the nodes in BFS order together with the depth of every node.
+    /// Iterative on purpose: judge trees can be paths of length 5 * 10^5.
+    pub fn bfs_order(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut order = Vec::with_capacity(self.size());
+        let mut depth = vec![0; self.size()];
+        order.push(self.root);
+
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &c in &self.children[u] {
+                depth[c] = depth[u] + 1;
+                order.push(c);
+            }
+        }
+
+        (order, depth)
+    }
+}
+
+/// The running aggregate of DSU on tree: a multiset of vertices that grows
+/// and shrinks one vertex at a time.
+pub trait SubtreeState {
+    fn add(&mut self, v: usize);
+    fn remove(&mut self, v: usize);
+}
+
+/// DSU on tree (small to large): calls `answer(state, v)` for every vertex
+/// `v` while `state` holds exactly the vertices of its subtree, in
+/// `O(n log n)` calls of `add` and `remove`.
+///
+/// A vertex is solved after all its children; the state of its heavy
+/// (largest) child is kept and the light children's subtrees are added on
+/// top, while a light child clears its state once answered. A vertex is
+/// only re-added when it sits in a light subtree, which happens at most
+/// `log2 n` times on its way to the root.
+pub fn dsu_on_tree<S: SubtreeState>(tree: &Tree, state: &mut S, mut answer: impl FnMut(&mut S, usize)) {
+    let n = tree.size();
+    if n == 0 {
+        return;
+    }
+    let (bfs, _) = tree.bfs_order();
+    let mut size = vec![1; n];
+    for &v in bfs.iter().rev() {
+        if v != tree.root {
+            size[tree.parent[v]] += size[v];
+        }
+    }
+    // Children with the heavy (largest) one last.
+    let kids: Vec<Vec<usize>> = (0..n)
+        .map(|v| {
+            let mut kids = tree.children[v].clone();
+            if let Some(i) = (0..kids.len()).max_by_key(|&i| size[kids[i]]) {
+                let last = kids.len() - 1;
+                kids.swap(i, last);
+            }
+            kids
+        })
+        .collect();
+
+    // Preorder, so every subtree is the contiguous `order[tin[v]..tin[v] + size[v]]`.
+    let mut order = Vec::with_capacity(n);
+    let mut tin = vec![0; n];
+    let mut stack = vec![tree.root];
+    while let Some(v) = stack.pop() {
+        tin[v] = order.len();
+        order.push(v);
+        stack.extend(tree.children[v].iter().rev());
+    }
+
+    // Each frame is `(vertex, keep its state, index of the next child)`.
+    let mut frames = vec![(tree.root, true, 0)];
+    while let Some((v, keep, next)) = frames.last_mut() {
+        let (v, keep) = (*v, *keep);
+        if let Some(&c) = kids[v].get(*next) {
+            *next += 1;
+            let is_heavy = *next == kids[v].len();
+            frames.push((c, is_heavy, 0));
+            continue;
+        }
+        frames.pop();
+        let light = &kids[v][..kids[v].len().saturating_sub(1)];
+        for &c in light {
+            for &u in &order[tin[c]..tin[c] + size[c]] {
+                state.add(u);
+            }
+        }
+        state.add(v);
+        answer(state, v);
+        if !keep {
+            for &u in &order[tin[v]..tin[v] + size[v]] {
+                state.remove(u);
+            }
+        }
+    }
+}
+
+/// The number of distinct colors among the vertices added.
+struct DistinctColors {
+    color: Vec<usize>,
+    count: Vec<u32>,
+    distinct: usize,
+}
+
+impl SubtreeState for DistinctColors {
+    fn add(&mut self, v: usize) {
+        let c = self.color[v];
+        self.count[c] += 1;
+        if self.count[c] == 1 {
+            self.distinct += 1;
+        }
+    }
+
+    fn remove(&mut self, v: usize) {
+        let c = self.color[v];
+        self.count[c] -= 1;
+        if self.count[c] == 0 {
+            self.distinct -= 1;
+        }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n`, the color of every vertex, then `p_i` for i = 1..n; node 0 is the root.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+    let line = lines.next().unwrap().expect("Failed to read the colors");
+    let colors: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a color")).collect();
+    let mut parent = vec![0; n];
+    if n > 1 {
+        let line = lines.next().unwrap().expect("Failed to read the parents");
+        for (i, p) in line.split_whitespace().enumerate() {
+            parent[i + 1] = p.parse().expect("Failed to parse p");
+        }
+    }
+
+    // --- Solving and Output ---
+    // The number of distinct colors in every subtree.
+    let mut sorted = colors.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let color = colors.iter().map(|c| sorted.binary_search(c).unwrap()).collect();
+    let mut state = DistinctColors { color, count: vec![0; sorted.len()], distinct: 0 };
+
+    let tree = Tree::new(parent, 0);
+    let mut answers = vec![0; n];
+    dsu_on_tree(&tree, &mut state, |state, v| answers[v] = state.distinct);
+
+    let output: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+    println!("{}", output.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// A random tree on `n` vertices with a random root and labels; `shape`
+    /// picks a random tree, a path or a star.
+    fn random_tree(rng: &mut Rng, n: usize, shape: usize) -> Tree {
+        let mut label: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            label.swap(i, rng.below(i + 1));
+        }
+        let mut parent = vec![label[0]; n];
+        for i in 1..n {
+            let p = match shape {
+                0 => rng.below(i),
+                1 => i - 1,
+                _ => 0,
+            };
+            parent[label[i]] = label[p];
+        }
+        Tree::new(parent, label[0])
+    }
+
+    /// The vertices of the subtree of `v`.
+    fn subtree(tree: &Tree, v: usize) -> Vec<usize> {
+        let mut stack = vec![v];
+        let mut out = Vec::new();
+        while let Some(u) = stack.pop() {
+            out.push(u);
+            stack.extend(&tree.children[u]);
+        }
+        out
+    }
+
+    /// Tracks the exact set of vertices added, rejecting double adds.
+    struct Members(HashSet<usize>);
+
+    impl SubtreeState for Members {
+        fn add(&mut self, v: usize) {
+            assert!(self.0.insert(v), "{} added twice", v);
+        }
+
+        fn remove(&mut self, v: usize) {
+            assert!(self.0.remove(&v), "{} removed while absent", v);
+        }
+    }
+
+    #[test]
+    fn distinct_colors_match_brute_force() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for case in 0..600 {
+            let n = 1 + rng.below(60);
+            let tree = random_tree(&mut rng, n, case % 3);
+            let palette = 1 + rng.below(n);
+            let color: Vec<usize> = (0..n).map(|_| rng.below(palette)).collect();
+
+            let mut state = DistinctColors { color: color.clone(), count: vec![0; palette], distinct: 0 };
+            let mut answers = vec![usize::MAX; n];
+            dsu_on_tree(&tree, &mut state, |state, v| answers[v] = state.distinct);
+
+            for (v, &answer) in answers.iter().enumerate() {
+                let naive: HashSet<usize> = subtree(&tree, v).into_iter().map(|u| color[u]).collect();
+                assert_eq!(answer, naive.len(), "subtree of {}", v);
+            }
+        }
+    }
+
+    #[test]
+    fn state_holds_exactly_the_subtree() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        for case in 0..300 {
+            let n = 1 + rng.below(60);
+            let tree = random_tree(&mut rng, n, case % 3);
+            let mut answered = vec![false; n];
+            dsu_on_tree(&tree, &mut Members(HashSet::new()), |state, v| {
+                assert!(!answered[v]);
+                answered[v] = true;
+                assert_eq!(state.0, subtree(&tree, v).into_iter().collect());
+            });
+            assert!(answered.iter().all(|&a| a));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::io::{self, BufRead};
+
+/// Lowercase letters only; transitions are a dense table per state.
+const SIGMA: usize = 26;
+const NONE: u32 = u32::MAX;
+
+/// The suffix automaton of a string, built online one character at a time.
+///
+/// Each state is a class of substrings with the same set of end positions
+/// (`endpos`); its strings are the suffixes of the longest one, `len[v]`,
+/// down to just above `len[link[v]]`. There are at most `2n - 1` states and
+/// `3n - 4` transitions.
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton {
+    len: Vec<usize>,
+    link: Vec<u32>,
+    next: Vec<[u32; SIGMA]>,
+    /// Whether the state was created for a new prefix rather than cloned;
+    /// counting these over a suffix-link subtree gives `|endpos|`.
+    is_prefix: Vec<bool>,
+    /// The state of the whole string read so far.
+    last: usize,
+    /// Distinct non-empty substrings of the string read so far.
+    distinct: u64,
+}
+
+impl SuffixAutomaton {
+    /// The automaton of the empty string: a single root.
+    pub fn new() -> Self {
+        Self { len: vec![0], link: vec![NONE], next: vec![[NONE; SIGMA]], is_prefix: vec![false], last: 0, distinct: 0 }
+    }
+
+    pub fn from_bytes(s: &[u8]) -> Self {
+        let mut sam = Self::new();
+        for &c in s {
+            sam.extend(c);
+        }
+        sam
+    }
+
+    /// The number of states, including the root.
+    pub fn state_count(&self) -> usize {
+        self.len.len()
+    }
+
+    fn new_state(&mut self, len: usize, link: u32, next: [u32; SIGMA], is_prefix: bool) -> usize {
+        self.len.push(len);
+        self.link.push(link);
+        self.next.push(next);
+        self.is_prefix.push(is_prefix);
+        self.len.len() - 1
+    }
+
+    /// Appends the lowercase letter `c` in amortised `O(1)` and returns how
+    /// many distinct substrings appeared: the suffixes of the new string
+    /// longer than `len[link[cur]]`, which occurred nowhere before.
+    pub fn extend(&mut self, c: u8) -> u64 {
+        let c = (c - b'a') as usize;
+        let cur = self.new_state(self.len[self.last] + 1, 0, [NONE; SIGMA], true);
+
+        // Every suffix of the old string without a `c` edge gains one to `cur`.
+        let mut p = self.last as u32;
+        while p != NONE && self.next[p as usize][c] == NONE {
+            self.next[p as usize][c] = cur as u32;
+            p = self.link[p as usize];
+        }
+
+        if p != NONE {
+            let q = self.next[p as usize][c] as usize;
+            if self.len[p as usize] + 1 == self.len[q] {
+                self.link[cur] = q as u32;
+            } else {
+                // `q` also holds strings longer than `p + c`, whose endpos no
+                // longer matches; split the shorter ones off into a clone.
+                let clone = self.new_state(self.len[p as usize] + 1, self.link[q], self.next[q], false);
+                while p != NONE && self.next[p as usize][c] == q as u32 {
+                    self.next[p as usize][c] = clone as u32;
+                    p = self.link[p as usize];
+                }
+                self.link[q] = clone as u32;
+                self.link[cur] = clone as u32;
+            }
+        }
+        self.last = cur;
+        let added = (self.len[cur] - self.len[self.link[cur] as usize]) as u64;
+        self.distinct += added;
+        added
+    }
+
+    /// The number of distinct non-empty substrings: each state other than the
+    /// root holds `len[v] - len[link[v]]` of them. A clone only splits that
+    /// count between itself and the state it came from, so the total is
+    /// kept up to date by `extend`.
+    pub fn distinct_substrings(&self) -> u64 {
+        self.distinct
+    }
+
+    /// `|endpos|` of every state, i.e. how often each of its strings occurs.
+    ///
+    /// A state's endpos is the disjoint union of its suffix-link children's,
+    /// plus its own prefix position if it has one. Links point to strictly
+    /// shorter states, so accumulating in decreasing `len` (a counting sort)
+    /// visits children first.
+    pub fn endpos_sizes(&self) -> Vec<u64> {
+        let n = self.state_count();
+        let max_len = self.len[self.last];
+        let mut bucket = vec![0; max_len + 2];
+        for &l in &self.len {
+            bucket[l + 1] += 1;
+        }
+        for i in 1..bucket.len() {
+            bucket[i] += bucket[i - 1];
+        }
+        let mut order = vec![0; n];
+        for v in 0..n {
+            order[bucket[self.len[v]]] = v;
+            bucket[self.len[v]] += 1;
+        }
+
+        let mut cnt: Vec<u64> = self.is_prefix.iter().map(|&p| u64::from(p)).collect();
+        for &v in order.iter().rev() {
+            let link = self.link[v];
+            if link != NONE {
+                cnt[link as usize] += cnt[v];
+            }
+        }
+        cnt
+    }
+
+    /// The state reached by reading `pattern` from the root, if any.
+    pub fn walk(&self, pattern: &[u8]) -> Option<usize> {
+        pattern.iter().try_fold(0, |v, &c| {
+            let u = self.next[v][(c - b'a') as usize];
+            (u != NONE).then_some(u as usize)
+        })
+    }
+
+    /// How many times a non-empty `pattern` occurs, given `endpos_sizes()`.
+    pub fn occurrences(&self, endpos_sizes: &[u64], pattern: &[u8]) -> u64 {
+        self.walk(pattern).map_or(0, |v| endpos_sizes[v])
+    }
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+
+    // --- Output ---
+    // Number of Substrings, answered online instead of with a suffix array.
+    println!("{}", SuffixAutomaton::from_bytes(s.trim().as_bytes()).distinct_substrings());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    fn random_strings() -> impl Iterator<Item = Vec<u8>> {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        (0..1500).map(move |_| {
+            // Tiny alphabets give many repeats and therefore many clones.
+            let sigma = 1 + rng.below(3);
+            (0..rng.below(40)).map(|_| b'a' + rng.below(sigma) as u8).collect()
+        })
+    }
+
+    #[test]
+    fn state_count_and_suffix_links() {
+        for s in random_strings() {
+            let sam = SuffixAutomaton::from_bytes(&s);
+            let n = s.len();
+            assert!(sam.state_count() <= if n >= 2 { 2 * n - 1 } else { n + 1 }, "{:?}", s);
+            assert_eq!(sam.link[0], NONE);
+            for v in 1..sam.state_count() {
+                let link = sam.link[v];
+                assert!(link != NONE && sam.len[link as usize] < sam.len[v], "{:?} state {}", s, v);
+            }
+            let transitions = sam.next.iter().flatten().filter(|&&u| u != NONE).count();
+            assert!(n < 3 || transitions <= 3 * n - 4, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn distinct_substrings_match_brute_force() {
+        for s in random_strings() {
+            let mut sam = SuffixAutomaton::new();
+            let mut seen = HashSet::new();
+            for (i, &c) in s.iter().enumerate() {
+                let before = seen.len() as u64;
+                seen.extend((0..=i).map(|j| &s[j..=i]));
+                assert_eq!(sam.extend(c), seen.len() as u64 - before, "{:?} at {}", s, i);
+                assert_eq!(sam.distinct_substrings(), seen.len() as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn endpos_sizes_match_occurrence_counts() {
+        for s in random_strings() {
+            let sam = SuffixAutomaton::from_bytes(&s);
+            let sizes = sam.endpos_sizes();
+            for i in 0..s.len() {
+                for j in i + 1..=s.len() {
+                    let t = &s[i..j];
+                    let naive = s.windows(t.len()).filter(|w| *w == t).count() as u64;
+                    assert_eq!(sam.occurrences(&sizes, t), naive, "{:?} in {:?}", t, s);
+                    // `t` lands in the state whose length range contains it.
+                    let v = sam.walk(t).unwrap();
+                    assert!(sam.len[sam.link[v] as usize] < t.len() && t.len() <= sam.len[v]);
+                }
+            }
+            assert_eq!(sam.occurrences(&sizes, b"d"), 0);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::{self, BufRead};
+
+/// `sum_{i=0}^{n-1} floor((a i + b) / m)` for `a, b < m`, in `O(log m)`.
+///
+/// After reducing `a` and `b` below `m`, the lattice points under the line
+/// are counted again with the roles of the axes swapped, which shrinks the
+/// problem like the Euclidean algorithm.
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut ans = 0;
+    loop {
+        if a >= m {
+            ans += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            ans += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    ans
+}
+
+/// `sum_{i=0}^{n-1} floor((a i + b) / m)` for any signed `a` and `b`, with
+/// `n >= 0` and `m >= 1`.
+///
+/// Negative (or large) `a` and `b` are first written as `q m + r` with
+/// `0 <= r < m`; the `q` parts contribute `q n (n - 1) / 2` and `q n` exactly.
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i128 {
+    assert!(n >= 0 && m >= 1, "floor_sum needs n >= 0 and m >= 1");
+    let (n, m, a, b) = (n as i128, m as i128, a as i128, b as i128);
+    let (qa, ra) = (a.div_euclid(m), a.rem_euclid(m));
+    let (qb, rb) = (b.div_euclid(m), b.rem_euclid(m));
+    let shifted = qa * (n * (n - 1) / 2) + qb * n;
+    shifted + floor_sum_unsigned(n as u128, m as u128, ra as u128, rb as u128) as i128
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let mut next = || -> i64 { parts.next().unwrap().parse().expect("Failed to parse a query argument") };
+        let (n, m, a, b) = (next(), next(), next(), next());
+        output.push(floor_sum(n, m, a, b).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
@@ -0,0 +1,185 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// Largest `r` with `r * r <= n`.
+fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
+}
+
+/// Iterator over the distinct values of `n / i` (floor division) for
+/// `1 <= i <= n`, as `(q, lo, hi)` with `n / i = q` exactly for
+/// `lo <= i <= hi`. Ranges come in increasing `i`, so `q` decreases.
+#[derive(Clone, Debug)]
+pub struct FloorQuotients {
+    n: u64,
+    i: u64,
+}
+
+/// The `O(sqrt n)` ranges of `i` on which `n / i` is constant.
+pub fn floor_quotients(n: u64) -> FloorQuotients {
+    FloorQuotients { n, i: 1 }
+}
+
+impl Iterator for FloorQuotients {
+    type Item = (u64, u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.n {
+            return None;
+        }
+        let (q, lo) = (self.n / self.i, self.i);
+        let hi = self.n / q;
+        self.i = hi + 1;
+        Some((q, lo, hi))
+    }
+}
+
+/// The `O(sqrt n)` distinct values of `n / i` (floor division) for `1 <= i <= n`.
+///
+/// Sublinear multiplicative-function sums only ever look at these values, so
+/// they keep one table entry per value. Values are stored in decreasing
+/// order and `index` finds a value's slot in `O(1)`.
+#[derive(Debug)]
+pub struct QuotientSet {
+    n: u64,
+    sqrt: u64,
+    values: Vec<u64>,
+}
+
+impl QuotientSet {
+    pub fn new(n: u64) -> Self {
+        let values = floor_quotients(n).map(|(q, _, _)| q).collect();
+        Self { n, sqrt: isqrt(n), values }
+    }
+
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// All the values, in decreasing order.
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    /// Position of `v` in `values()`; `v` must be of the form `n / i`.
+    ///
+    /// Every `v <= sqrt n` occurs and they fill the tail in increasing order,
+    /// while a larger `v` is `n / i` for a unique `i < sqrt n` at position `i - 1`.
+    pub fn index(&self, v: u64) -> usize {
+        if v <= self.sqrt {
+            self.values.len() - v as usize
+        } else {
+            (self.n / v - 1) as usize
+        }
+    }
+}
+
+/// Euler's totient of a single `n` by trial division in `O(sqrt n)`.
+pub fn euler_phi(mut n: u64) -> u64 {
+    let mut result = n;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+            result -= result / p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        result -= result / n;
+    }
+    result
+}
+
+/// Euler's totient of every number below `n` with a linear sieve, using
+/// `phi(i p) = phi(i) p` when `p | i` and `phi(i) (p - 1)` otherwise.
+pub fn phi_table(n: usize) -> Vec<u64> {
+    let mut phi = vec![0; n];
+    let mut primes: Vec<usize> = Vec::new();
+    if n > 1 {
+        phi[1] = 1;
+    }
+    for i in 2..n {
+        if phi[i] == 0 {
+            phi[i] = i as u64 - 1;
+            primes.push(i);
+        }
+        for &p in &primes {
+            if i * p >= n {
+                break;
+            }
+            if i % p == 0 {
+                phi[i * p] = phi[i] * p as u64;
+                break;
+            }
+            phi[i * p] = phi[i] * (p as u64 - 1);
+        }
+    }
+    phi
+}
+
+/// `sum_{i <= v} phi(i)` modulo `MOD` for every value `v` of `qs`, in the
+/// order of `qs.values()`, in `O(n^{2/3})`.
+///
+/// Every pair `1 <= b <= a <= v` has exactly one `gcd`, which gives
+/// `v (v + 1) / 2 = sum_{d >= 1} Phi(v / d)`. Values up to about `n^{2/3}`
+/// come straight from a sieve; larger ones use that identity with the `d`
+/// sharing the same `v / d` grouped together.
+pub fn totient_sum_table(qs: &QuotientSet) -> Vec<u64> {
+    let values = qs.values();
+    let limit = ((qs.n() as f64).powf(2.0 / 3.0) as usize).max(1);
+    let mut prefix = phi_table(limit + 1);
+    for i in 1..prefix.len() {
+        prefix[i] = (prefix[i - 1] + prefix[i]) % MOD;
+    }
+
+    let mut sums = vec![0; values.len()];
+    // Smaller values come later in `values`, so walk it backwards.
+    for i in (0..values.len()).rev() {
+        let v = values[i];
+        if v as usize <= limit {
+            sums[i] = prefix[v as usize];
+            continue;
+        }
+        let mut total = ((v as u128 * (v as u128 + 1) / 2) % MOD as u128) as u64;
+        for (q, lo, hi) in floor_quotients(v).skip(1) {
+            let phi_q = sums[qs.index(q)];
+            total = (total + MOD - (hi - lo + 1) % MOD * phi_q % MOD) % MOD;
+        }
+        sums[i] = total;
+    }
+    sums
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: u64 = first_line.trim().parse().expect("Failed to parse n");
+
+    // --- Output ---
+    let qs = QuotientSet::new(n);
+    let sums = totient_sum_table(&qs);
+    println!("{}", if n == 0 { 0 } else { sums[qs.index(n)] });
+}
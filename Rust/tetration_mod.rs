@@ -0,0 +1,104 @@
+use std::io::{self, BufRead};
+
+/// `x` reduced into `[0, 2m)` so that it stays `>= m` exactly when `x` is:
+/// `x` itself below `m`, otherwise `x mod m + m`. Exponents kept in this
+/// form can be used with the generalised Euler theorem below.
+fn capped(x: u64, m: u64) -> u64 {
+    if x < m { x } else { x % m + m }
+}
+
+/// `base^exp` in the capped representation modulo `m`; `base` and `exp`
+/// are themselves capped values, with `base >= 1`.
+fn pow_capped(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = capped(1, m);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = capped(result * base, m);
+        }
+        base = capped(base * base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Euler's totient with trial division by the precomputed `primes`, which
+/// must reach `sqrt(n)`.
+fn euler_phi(mut n: u64, primes: &[u64]) -> u64 {
+    let mut result = n;
+    for &p in primes {
+        if p * p > n {
+            break;
+        }
+        if n.is_multiple_of(p) {
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+            result -= result / p;
+        }
+    }
+    if n > 1 {
+        result -= result / n;
+    }
+    result
+}
+
+/// The power tower `a^a^...^a` (`b` copies, with `0^0 = 1`) in the capped
+/// representation modulo `m`.
+///
+/// For `e >= log2 m`, `a^e = a^{(e mod phi(m)) + phi(m)} (mod m)` holds
+/// whether or not `a` is coprime to `m`. The capped form of the exponent
+/// is exactly that reduction, and iterating `phi` reaches 1 after
+/// `O(log m)` levels, where every tower of height at least one is capped to 1.
+fn tower(a: u64, b: u64, m: u64, primes: &[u64]) -> u64 {
+    if b == 0 {
+        return capped(1, m);
+    }
+    if a == 0 {
+        // The tower alternates 0, 1, 0, ... starting from height one.
+        return capped(if b % 2 == 1 { 0 } else { 1 }, m);
+    }
+    if a == 1 || m == 1 {
+        return capped(1, m);
+    }
+    let exponent = tower(a, b - 1, euler_phi(m, primes), primes);
+    pow_capped(capped(a, m), exponent, m)
+}
+
+/// `a` tetrated to height `b`, modulo `m`.
+pub fn tetration(a: u64, b: u64, m: u64, primes: &[u64]) -> u64 {
+    tower(a, b, m, primes) % m
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // Primes up to `sqrt(10^9)` cover every totient computation.
+    let limit = 31623;
+    let mut composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for i in 2..=limit {
+        if !composite[i] {
+            primes.push(i as u64);
+            for j in (i * i..=limit).step_by(i) {
+                composite[j] = true;
+            }
+        }
+    }
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let t: usize = first_line.trim().parse().expect("Failed to parse t");
+
+    // --- Query Processing and Output ---
+    let mut output = Vec::with_capacity(t);
+    for _ in 0..t {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let mut next = || -> u64 { parts.next().unwrap().parse().expect("Failed to parse a query argument") };
+        let (a, b, m) = (next(), next(), next());
+        output.push(tetration(a, b, m, &primes).to_string());
+    }
+    println!("{}", output.join("\n"));
+}
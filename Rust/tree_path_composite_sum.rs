@@ -0,0 +1,170 @@
+use std::io::{self, BufRead};
+
+const MOD: u64 = 998244353;
+
+/// An integer modulo the judge's prime `MOD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModInt {
+    val: u64,
+}
+
+impl ModInt {
+    fn new(val: u64) -> Self {
+        ModInt { val: val % MOD }
+    }
+}
+
+impl std::ops::Add for ModInt {
+    type Output = ModInt;
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val + rhs.val)
+    }
+}
+
+impl std::ops::Mul for ModInt {
+    type Output = ModInt;
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.val * rhs.val)
+    }
+}
+
+/// Computes a tree DP for every choice of root at once ("rerooting") in `O(n)`.
+///
+/// The DP is described by three closures:
+/// - `merge(a, b)` combines the contributions of two neighbours and must be
+///   associative with `identity` as its neutral element;
+/// - `add_edge(dp, e)` turns the DP of the subtree behind edge `e` into the
+///   contribution it makes to the other endpoint of `e`;
+/// - `add_root(acc, v)` finishes the merged contributions at vertex `v`.
+///
+/// `adj[v]` lists `(neighbour, edge index)` pairs. Returns the finished DP
+/// value of the whole tree rooted at every vertex.
+fn rerooting<T, M, E, R>(adj: &[Vec<(usize, usize)>], identity: T, merge: M, add_edge: E, add_root: R) -> Vec<T>
+where
+    T: Clone,
+    M: Fn(&T, &T) -> T,
+    E: Fn(&T, usize) -> T,
+    R: Fn(&T, usize) -> T,
+{
+    let n = adj.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // BFS from vertex 0 to get an order where parents come first.
+    let mut parent = vec![usize::MAX; n];
+    let mut order = vec![0];
+    parent[0] = 0;
+    let mut i = 0;
+    while i < order.len() {
+        let u = order[i];
+        i += 1;
+        for &(v, _) in &adj[u] {
+            if parent[v] == usize::MAX {
+                parent[v] = u;
+                order.push(v);
+            }
+        }
+    }
+
+    // Bottom-up: `down[v]` is the DP of the subtree of `v` when rooted at 0.
+    let mut down = vec![identity.clone(); n];
+    for &u in order.iter().rev() {
+        let mut acc = identity.clone();
+        for &(v, e) in &adj[u] {
+            if v != parent[u] {
+                acc = merge(&acc, &add_edge(&down[v], e));
+            }
+        }
+        down[u] = add_root(&acc, u);
+    }
+
+    // Top-down: `up[v]` is the contribution of everything outside the subtree
+    // of `v`, already passed through the edge to `v`'s parent.
+    let mut up = vec![identity.clone(); n];
+    let mut result = vec![identity.clone(); n];
+    for &u in &order {
+        let contributions: Vec<T> = adj[u]
+            .iter()
+            .map(|&(v, e)| if v == parent[u] { up[u].clone() } else { add_edge(&down[v], e) })
+            .collect();
+
+        // suffix[i] merges contributions[i..]; a running prefix covers the rest.
+        let mut suffix = vec![identity.clone(); contributions.len() + 1];
+        for i in (0..contributions.len()).rev() {
+            suffix[i] = merge(&contributions[i], &suffix[i + 1]);
+        }
+        result[u] = add_root(&suffix[0], u);
+
+        let mut prefix = identity.clone();
+        for (i, &(v, e)) in adj[u].iter().enumerate() {
+            if v != parent[u] {
+                let without_v = merge(&prefix, &suffix[i + 1]);
+                up[v] = add_edge(&add_root(&without_v, u), e);
+            }
+            prefix = merge(&prefix, &contributions[i]);
+        }
+    }
+
+    result
+}
+
+/// Sum of the composites over all paths leaving a vertex, and the number of paths.
+#[derive(Clone, Copy, Debug)]
+struct PathSums {
+    sum: ModInt,
+    count: ModInt,
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let n: usize = first_line.trim().parse().expect("Failed to parse n");
+
+    let a: Vec<ModInt> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the vertex values")
+        .split_whitespace()
+        .take(n)
+        .map(|s| ModInt::new(s.parse().expect("Failed to parse a value")))
+        .collect();
+
+    // Edge `i` carries the linear map `x -> b_i * x + c_i`.
+    let mut adj = vec![Vec::new(); n];
+    let mut maps = Vec::with_capacity(n - 1);
+    for i in 0..n - 1 {
+        let line = lines.next().unwrap().expect("Failed to read an edge line");
+        let mut parts = line.split_whitespace();
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        let b: u64 = parts.next().unwrap().parse().expect("Failed to parse b");
+        let c: u64 = parts.next().unwrap().parse().expect("Failed to parse c");
+        adj[u].push((v, i));
+        adj[v].push((u, i));
+        maps.push((ModInt::new(b), ModInt::new(c)));
+    }
+
+    // --- Rerooting ---
+    // Applying an edge map to every path in a subtree scales the sum by `b`
+    // and adds `c` once per path.
+    let identity = PathSums { sum: ModInt::new(0), count: ModInt::new(0) };
+    let answers = rerooting(
+        &adj,
+        identity,
+        |x, y| PathSums { sum: x.sum + y.sum, count: x.count + y.count },
+        |dp, e| {
+            let (b, c) = maps[e];
+            PathSums { sum: b * dp.sum + c * dp.count, count: dp.count }
+        },
+        |acc, v| PathSums { sum: acc.sum + a[v], count: acc.count + ModInt::new(1) },
+    );
+
+    // --- Output ---
+    let output: Vec<String> = answers.iter().map(|p| p.sum.val.to_string()).collect();
+    println!("{}", output.join(" "));
+}
@@ -0,0 +1,354 @@
+use std::io::{self, BufRead};
+
+/// Common interface for lowest common ancestor backends.
+pub trait Lca {
+    /// Returns the lowest common ancestor of `u` and `v`.
+    fn lca(&self, u: usize, v: usize) -> usize;
+}
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: Vec<usize>, root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, parent, root }
+    }
+
+    /// Roots the tree given by `n - 1` undirected edges at `root`.
+    pub fn from_edges(n: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        let mut parent = vec![usize::MAX; n];
+        parent[root] = root;
+        let mut order = vec![root];
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+        Self::new(parent, root)
+    }
+
+    pub fn size(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns the nodes in BFS order together with the depth of every node.
+    /// Iterative on purpose: judge trees can be paths of length 5 * 10^5.
+    pub fn bfs_order(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut order = Vec::with_capacity(self.size());
+        let mut depth = vec![0; self.size()];
+        order.push(self.root);
+
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &c in &self.children[u] {
+                depth[c] = depth[u] + 1;
+                order.push(c);
+            }
+        }
+
+        (order, depth)
+    }
+
+    /// Returns the flattening of the tree where every node is written when
+    /// it is entered and again when it is left, `2n` entries in total, with
+    /// the two positions of every node.
+    pub fn enter_exit_tour(&self) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+        let mut tour = Vec::with_capacity(2 * self.size());
+        let mut enter = vec![0; self.size()];
+        let mut exit = vec![0; self.size()];
+        tour.push(self.root);
+        // Each stack entry is `(node, index of the next child to visit)`.
+        let mut stack = vec![(self.root, 0)];
+
+        while let Some((u, next_child)) = stack.last_mut() {
+            if let Some(&c) = self.children[*u].get(*next_child) {
+                *next_child += 1;
+                enter[c] = tour.len();
+                tour.push(c);
+                stack.push((c, 0));
+            } else {
+                exit[*u] = tour.len();
+                tour.push(*u);
+                stack.pop();
+            }
+        }
+
+        (tour, enter, exit)
+    }
+}
+
+/// LCA by binary lifting: `O(n log n)` preprocessing, `O(log n)` per query.
+#[derive(Debug)]
+pub struct BinaryLifting {
+    /// `up[k][v]` is the `2^k`-th ancestor of `v` (clamped at the root).
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl BinaryLifting {
+    fn new(tree: &Tree) -> Self {
+        let n = tree.size();
+        let (_, depth) = tree.bfs_order();
+
+        let mut levels = 1;
+        while (1 << levels) < n {
+            levels += 1;
+        }
+
+        let mut up = vec![tree.parent.clone()];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+
+        Self { up, depth }
+    }
+}
+
+impl Lca for BinaryLifting {
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] { (v, u) } else { (u, v) };
+
+        // Lift `u` up to the depth of `v`.
+        let diff = self.depth[u] - self.depth[v];
+        for (k, up) in self.up.iter().enumerate() {
+            if diff >> k & 1 == 1 {
+                u = up[u];
+            }
+        }
+        if u == v {
+            return u;
+        }
+
+        // Lift both as long as they stay below the common ancestor.
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+}
+
+/// The window of Mo's algorithm: a multiset of positions that grows and
+/// shrinks one element at a time.
+pub trait MoState {
+    fn add(&mut self, i: usize);
+    fn remove(&mut self, i: usize);
+}
+
+/// Mo's algorithm: answers every half-open range of `ranges` offline by
+/// moving one window across them, calling `answer(state, query index)` once
+/// the window holds exactly that range. Answers come back in query order.
+///
+/// Ranges are sorted by block of their left end, with the right end
+/// alternating direction between blocks, so that with blocks of
+/// `n / sqrt(q)` the window moves `O(n sqrt q)` steps in total.
+pub fn run_mo<S: MoState, T>(
+    state: &mut S,
+    ranges: &[(usize, usize)],
+    mut answer: impl FnMut(&mut S, usize) -> T,
+) -> Vec<T> {
+    let q = ranges.len();
+    let n = ranges.iter().map(|&(_, r)| r).max().unwrap_or(0);
+    let block = (n / (q as f64).sqrt().max(1.0) as usize).max(1);
+    let mut order: Vec<usize> = (0..q).collect();
+    order.sort_unstable_by_key(|&i| {
+        let (l, r) = ranges[i];
+        let b = l / block;
+        (b, if b & 1 == 0 { r } else { n - r })
+    });
+
+    let mut answers: Vec<Option<T>> = (0..q).map(|_| None).collect();
+    let (mut cur_l, mut cur_r) = (0, 0);
+    for i in order {
+        let (l, r) = ranges[i];
+        // Grow before shrinking, so the window is never "negative".
+        while cur_l > l {
+            cur_l -= 1;
+            state.add(cur_l);
+        }
+        while cur_r < r {
+            state.add(cur_r);
+            cur_r += 1;
+        }
+        while cur_l < l {
+            state.remove(cur_l);
+            cur_l += 1;
+        }
+        while cur_r > r {
+            cur_r -= 1;
+            state.remove(cur_r);
+        }
+        answers[i] = Some(answer(state, i));
+    }
+    answers.into_iter().map(Option::unwrap).collect()
+}
+
+/// Turns moves over the enter/exit tour into vertex additions and removals:
+/// a vertex is on the path exactly when the window holds one of its two
+/// occurrences, so every occurrence toggles it.
+struct PathWindow<'a, S> {
+    state: &'a mut S,
+    tour: &'a [usize],
+    inside: Vec<bool>,
+}
+
+impl<S: MoState> PathWindow<'_, S> {
+    fn toggle(&mut self, i: usize) {
+        let v = self.tour[i];
+        if self.inside[v] {
+            self.state.remove(v);
+        } else {
+            self.state.add(v);
+        }
+        self.inside[v] ^= true;
+    }
+}
+
+impl<S: MoState> MoState for PathWindow<'_, S> {
+    fn add(&mut self, i: usize) {
+        self.toggle(i);
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.toggle(i);
+    }
+}
+
+/// Mo's algorithm on tree paths: `answer(state)` is called with exactly the
+/// vertices of the path `u - v` added to `state`, for every `(u, v)` of
+/// `paths`. `O((n + q) sqrt n)` state updates.
+///
+/// On the tour where every vertex is entered and left, with `u` entered
+/// first, the range from `exit[u]` (or `enter[u]` when `u` is an ancestor of
+/// `v`) to `enter[v]` holds the path's vertices once and every other vertex
+/// zero or two times. Only the LCA is missing when it differs from `u`, and
+/// it is added around the answer.
+fn tree_path_mo<L: Lca, S: MoState, T>(
+    tree: &Tree,
+    lca: &L,
+    state: &mut S,
+    paths: &[(usize, usize)],
+    mut answer: impl FnMut(&mut S) -> T,
+) -> Vec<T> {
+    let (tour, enter, exit) = tree.enter_exit_tour();
+    let mut ranges = Vec::with_capacity(paths.len());
+    let mut extra = Vec::with_capacity(paths.len());
+    for &(u, v) in paths {
+        let (u, v) = if enter[u] <= enter[v] { (u, v) } else { (v, u) };
+        let w = lca.lca(u, v);
+        if w == u {
+            ranges.push((enter[u], enter[v] + 1));
+            extra.push(None);
+        } else {
+            ranges.push((exit[u], enter[v] + 1));
+            extra.push(Some(w));
+        }
+    }
+
+    let mut window = PathWindow { state, tour: &tour, inside: vec![false; tree.size()] };
+    run_mo(&mut window, &ranges, |window, i| {
+        if let Some(w) = extra[i] {
+            window.state.add(w);
+        }
+        let result = answer(window.state);
+        if let Some(w) = extra[i] {
+            window.state.remove(w);
+        }
+        result
+    })
+}
+
+/// The number of distinct colors among the vertices added.
+struct DistinctColors {
+    color: Vec<usize>,
+    count: Vec<u32>,
+    distinct: usize,
+}
+
+impl MoState for DistinctColors {
+    fn add(&mut self, v: usize) {
+        let c = self.color[v];
+        self.count[c] += 1;
+        if self.count[c] == 1 {
+            self.distinct += 1;
+        }
+    }
+
+    fn remove(&mut self, v: usize) {
+        let c = self.color[v];
+        self.count[c] -= 1;
+        if self.count[c] == 0 {
+            self.distinct -= 1;
+        }
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    // `n q`, the color of every vertex, `n - 1` edges, then `q` paths.
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+    let line = lines.next().unwrap().expect("Failed to read the colors");
+    let colors: Vec<i64> = line.split_whitespace().map(|s| s.parse().expect("Failed to parse a color")).collect();
+    let mut read_pair = || {
+        let line = lines.next().unwrap().expect("Failed to read a pair");
+        let mut parts = line.split_whitespace();
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+        let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+        (u, v)
+    };
+    let edges: Vec<(usize, usize)> = (1..n).map(|_| read_pair()).collect();
+    let paths: Vec<(usize, usize)> = (0..q).map(|_| read_pair()).collect();
+
+    // --- Solving and Output ---
+    let mut sorted = colors.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let color = colors.iter().map(|c| sorted.binary_search(c).unwrap()).collect();
+    let mut state = DistinctColors { color, count: vec![0; sorted.len()], distinct: 0 };
+
+    let tree = Tree::from_edges(n, &edges, 0);
+    let lca = BinaryLifting::new(&tree);
+    let answers = tree_path_mo(&tree, &lca, &mut state, &paths, |state| state.distinct);
+
+    let output: Vec<String> = answers.iter().map(|a| a.to_string()).collect();
+    println!("{}", output.join("\n"));
+}
@@ -1,8 +1,9 @@
 use std::io;
 use std::mem::swap;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug)]
-struct UnionFind{
+pub struct UnionFind{
     parent: Vec<usize>,
     height: Vec<usize>
 }
@@ -48,6 +49,27 @@ impl UnionFind {
     }
 }
 
+/// Graphviz rendering for debugging: the forest of parent pointers, with every
+/// element pointing to its parent and roots drawn as double circles. Elements
+/// are listed in index order, so the output is deterministic.
+#[cfg(feature = "debug-viz")]
+impl UnionFind {
+    pub fn to_dot(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::from("digraph UnionFind {\n");
+        for (u, &p) in self.parent.iter().enumerate() {
+            if u == p {
+                writeln!(out, "    {} [shape=doublecircle];", u).unwrap();
+            } else {
+                writeln!(out, "    {} -> {};", u, p).unwrap();
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
 #[derive(Debug)]
 enum Query {
     Set { u: usize, v: usize },
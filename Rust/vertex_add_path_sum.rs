@@ -0,0 +1,328 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+pub trait Monoid {
+    // Required methods
+    fn id() -> Self;
+    fn op(a: &Self, b: &Self) -> Self;
+}
+
+/// Marks a missing child in the node arena.
+const NONE: u32 = u32::MAX;
+
+/// Represents a single node in the segment tree.
+/// Children are indices into the tree's node arena, or `NONE` for a leaf; a node's
+/// [start, end) interval is not stored but recomputed on the way down from the root.
+#[derive(Debug)]
+struct Node<T: Monoid + Clone> {
+    value: T,
+    left: u32,
+    right: u32,
+}
+
+/// A segment tree implementation for sum queries on a range.
+///
+/// Every node lives in one `Vec` and refers to its children by `u32` index instead
+/// of owning them through a `Box`, so building the tree is a single allocation and
+/// the whole structure can be inspected as a flat array.
+#[derive(Debug)]
+pub struct SegmentTree<T>
+where
+    T: Monoid + Clone
+{
+    /// The root, if any, is `nodes[0]`; nodes are stored in preorder.
+    nodes: Vec<Node<T>>,
+    size: usize,
+}
+
+impl<T> SegmentTree<T>
+where
+    T: Monoid + Clone,
+{
+    /// Creates a new SegmentTree for a sequence of `size` elements.
+    pub fn new(size: usize) -> Self {
+        let mut tree = Self {
+            nodes: Vec::with_capacity(2 * size),
+            size,
+        };
+        // An empty range results in no node.
+        if size > 0 {
+            tree.build(0..size);
+        }
+        tree
+    }
+
+    /// Creates the nodes covering the given range and returns the index of the topmost one.
+    fn build(&mut self, range: Range<usize>) -> u32 {
+        let index = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            value: T::id(),
+            left: NONE,
+            right: NONE,
+        });
+
+        // If the range represents more than one element, it's an internal node, so create children.
+        if range.len() > 1 {
+            let mid = range.start + range.len() / 2;
+            let left = self.build(range.start..mid);
+            let right = self.build(mid..range.end);
+            let node = &mut self.nodes[index as usize];
+            node.left = left;
+            node.right = right;
+        }
+
+        index
+    }
+
+    /// Recalculates a node's value based on its children's values.
+    /// This is called after a child's value has been updated.
+    fn update_value(&mut self, index: u32) {
+        let node = &self.nodes[index as usize];
+        let value = T::op(&self.nodes[node.left as usize].value, &self.nodes[node.right as usize].value);
+        self.nodes[index as usize].value = value;
+    }
+
+    /// Sets the value at a specific index.
+    pub fn set(&mut self, index: usize, val: T) {
+        // Ensure the index is within the bounds of the tree.
+        if index >= self.size {
+            return;
+        }
+        self.set_recursive(0, 0..self.size, index, val);
+    }
+
+    /// Helper function to recursively find the correct leaf node and update values up the tree.
+    fn set_recursive(&mut self, node: u32, range: Range<usize>, index: usize, val: T) {
+        // Base case: we have reached the leaf node corresponding to the index.
+        if range.len() == 1 {
+            self.nodes[node as usize].value = val;
+            return;
+        }
+
+        // Recursive step: determine whether to go left or right.
+        // Non-leaf nodes always have both children.
+        let mid = range.start + range.len() / 2;
+        let Node { left, right, .. } = self.nodes[node as usize];
+        if index < mid {
+            self.set_recursive(left, range.start..mid, index, val);
+        } else {
+            self.set_recursive(right, mid..range.end, index, val);
+        }
+
+        // After recursion, update the current node's value based on its children.
+        self.update_value(node);
+    }
+
+    /// Returns the sum of values in the given half-open range `[start, end)`.
+    pub fn get(&self, query_range: Range<usize>) -> T {
+        if self.size == 0 {
+            return T::id();
+        }
+        self.get_recursive(0, 0..self.size, &query_range)
+    }
+
+    /// Helper function to recursively calculate the sum over a given query range.
+    fn get_recursive(&self, node: u32, range: Range<usize>, query_range: &Range<usize>) -> T {
+        // Case 1: The node's range has no overlap with the query range.
+        if query_range.end <= range.start || query_range.start >= range.end {
+            return T::id();
+        }
+
+        let node = &self.nodes[node as usize];
+        // Case 2: The node's range is completely contained within the query range.
+        if query_range.start <= range.start && query_range.end >= range.end {
+            return node.value.clone();
+        }
+
+        // Case 3: Partial overlap. Recurse into children and sum their results.
+        let mid = range.start + range.len() / 2;
+        let left_sum = self.get_recursive(node.left, range.start..mid, query_range);
+        let right_sum = self.get_recursive(node.right, mid..range.end, query_range);
+
+        T::op(&left_sum, &right_sum)
+    }
+}
+
+
+/// Heavy-light decomposition of a rooted tree.
+///
+/// Nodes are laid out so that every heavy path occupies a contiguous block of
+/// positions, which lets any `u`-`v` path be split into `O(log n)` ranges
+/// over a sequence data structure indexed by `pos`.
+#[derive(Debug)]
+pub struct HeavyLightDecomposition {
+    /// `parent[i]` is the parent of node `i`; the root is its own parent.
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    /// `head[i]` is the topmost node of the heavy path containing `i`.
+    head: Vec<usize>,
+    /// `pos[i]` is the base position of node `i` in the linear layout.
+    pos: Vec<usize>,
+}
+
+impl HeavyLightDecomposition {
+    /// Creates the decomposition from a given size and a list of undirected edges.
+    /// Everything is computed iteratively so path-shaped trees don't overflow the stack.
+    pub fn new(size: usize, edges: &[(usize, usize)], root: usize) -> Self {
+        let mut adj = vec![Vec::new(); size];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+
+        // BFS to find parents, depths and an order where parents come first.
+        let mut parent = vec![usize::MAX; size];
+        let mut depth = vec![0; size];
+        let mut order = vec![root];
+        parent[root] = root;
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    order.push(v);
+                }
+            }
+        }
+
+        // Subtree sizes in reverse BFS order, then the heaviest child of each node.
+        let mut subtree_size = vec![1; size];
+        let mut heavy = vec![usize::MAX; size];
+        for &u in order.iter().rev() {
+            if u != root {
+                subtree_size[parent[u]] += subtree_size[u];
+            }
+        }
+        for &u in &order {
+            if u != root {
+                let p = parent[u];
+                if heavy[p] == usize::MAX || subtree_size[u] > subtree_size[heavy[p]] {
+                    heavy[p] = u;
+                }
+            }
+        }
+
+        // DFS that always visits the heavy child right after its parent.
+        let mut head = vec![root; size];
+        let mut pos = vec![0; size];
+        let mut stack = vec![root];
+        let mut next_pos = 0;
+        while let Some(u) = stack.pop() {
+            pos[u] = next_pos;
+            next_pos += 1;
+            for &v in &adj[u] {
+                if v != parent[u] && v != heavy[u] {
+                    head[v] = v;
+                    stack.push(v);
+                }
+            }
+            // Pushed last so it is popped next, continuing the heavy path.
+            if heavy[u] != usize::MAX {
+                head[heavy[u]] = head[u];
+                stack.push(heavy[u]);
+            }
+        }
+
+        Self { parent, depth, head, pos }
+    }
+
+    /// Returns the base position of node `v`.
+    pub fn pos(&self, v: usize) -> usize {
+        self.pos[v]
+    }
+
+    /// Splits the path between `u` and `v` (both inclusive) into
+    /// `O(log n)` half-open ranges of base positions.
+    pub fn path_ranges(&self, mut u: usize, mut v: usize) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        while self.head[u] != self.head[v] {
+            // Always climb from the node whose heavy path starts deeper.
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            ranges.push(self.pos[self.head[u]]..self.pos[u] + 1);
+            u = self.parent[self.head[u]];
+        }
+
+        // `u` and `v` are now on the same heavy path.
+        let (l, r) = if self.pos[u] < self.pos[v] { (self.pos[u], self.pos[v]) } else { (self.pos[v], self.pos[u]) };
+        ranges.push(l..r + 1);
+        ranges
+    }
+}
+
+
+#[derive(Clone)]
+struct S {
+    val: i64
+}
+impl Monoid for S {
+    fn id() -> Self { S {val: 0 } }
+    fn op(a: &Self, b: &Self) -> Self { S {val: a.val + b.val } }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let initial_values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the initial values")
+        .split_whitespace()
+        .map(|s| s.parse().expect("Failed to parse initial value"))
+        .collect();
+
+    let edges: Vec<(usize, usize)> = (0..n - 1)
+        .map(|_| {
+            let line = lines.next().unwrap().expect("Failed to read an edge line");
+            let mut parts = line.split_whitespace();
+            let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+            let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+            (u, v)
+        })
+        .collect();
+
+    // Lay the values out in HLD order so paths map onto segment tree ranges.
+    let hld = HeavyLightDecomposition::new(n, &edges, 0);
+    let mut st = SegmentTree::<S>::new(n);
+    for (v, &a) in initial_values.iter().enumerate() {
+        st.set(hld.pos(v), S { val: a });
+    }
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+
+        match t {
+            0 => {
+                let p: usize = parts.next().unwrap().parse().expect("Failed to parse p");
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                let i = hld.pos(p);
+                st.set(i, S::op(&S { val: x }, &st.get(i..i + 1)));
+            }
+            1 => {
+                let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+                let v: usize = parts.next().unwrap().parse().expect("Failed to parse v");
+                let sum = hld
+                    .path_ranges(u, v)
+                    .into_iter()
+                    .fold(S::id(), |acc, range| S::op(&acc, &st.get(range)));
+                println!("{}", sum.val);
+            }
+            _ => unreachable!()
+        }
+    }
+}
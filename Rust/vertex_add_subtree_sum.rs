@@ -0,0 +1,147 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+/// A rooted tree stored as children lists.
+#[derive(Debug)]
+struct Tree {
+    /// `children[i]` contains the children of node `i`.
+    children: Vec<Vec<usize>>,
+    root: usize,
+}
+
+impl Tree {
+    /// Creates a new Tree from a parent array, where `parent[root] == root`.
+    pub fn new(parent: &[usize], root: usize) -> Self {
+        let mut children = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                children[p].push(v);
+            }
+        }
+
+        Self { children, root }
+    }
+
+    /// Returns the entry and exit times of every node on a DFS of the tree.
+    ///
+    /// `tin[v]` is the position of `v` in preorder and `tout[v]` is one past the
+    /// position of its last descendant, so the subtree of `v` is exactly the
+    /// half-open range `tin[v]..tout[v]` of preorder positions.
+    pub fn euler_times(&self) -> (Vec<usize>, Vec<usize>) {
+        let n = self.children.len();
+        let mut tin = vec![0; n];
+        let mut tout = vec![0; n];
+        let mut timer = 0;
+        // Each stack entry is `(node, index of the next child to visit)`.
+        let mut stack = vec![(self.root, 0)];
+        tin[self.root] = timer;
+        timer += 1;
+
+        while let Some((u, next_child)) = stack.last_mut() {
+            if let Some(&c) = self.children[*u].get(*next_child) {
+                *next_child += 1;
+                tin[c] = timer;
+                timer += 1;
+                stack.push((c, 0));
+            } else {
+                tout[*u] = timer;
+                stack.pop();
+            }
+        }
+
+        (tin, tout)
+    }
+}
+
+/// A Fenwick tree (binary indexed tree) for prefix sums with point additions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug)]
+pub struct FenwickTree {
+    /// 1-indexed internally: `data[i]` covers the range `(i - lowbit(i), i]`.
+    data: Vec<i64>,
+}
+
+impl FenwickTree {
+    /// Creates a new FenwickTree for a sequence of `size` zeros.
+    pub fn new(size: usize) -> Self {
+        Self { data: vec![0; size + 1] }
+    }
+
+    /// Adds `val` to the element at `index`.
+    pub fn add(&mut self, index: usize, val: i64) {
+        let mut i = index + 1;
+        while i < self.data.len() {
+            self.data[i] += val;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the first `end` elements.
+    fn prefix_sum(&self, end: usize) -> i64 {
+        let mut i = end;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.data[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Returns the sum of values in the given half-open range `[start, end)`.
+    pub fn sum(&self, range: Range<usize>) -> i64 {
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let first_line = lines.next().unwrap().expect("Failed to read the first line");
+    let mut parts = first_line.split_whitespace();
+    let n: usize = parts.next().unwrap().parse().expect("Failed to parse n");
+    let q: usize = parts.next().unwrap().parse().expect("Failed to parse q");
+
+    let initial_values: Vec<i64> = lines
+        .next()
+        .unwrap()
+        .expect("Failed to read the initial values")
+        .split_whitespace()
+        .map(|s| s.parse().expect("Failed to parse initial value"))
+        .collect();
+
+    // Node 0 is the root; `p_i` is given for i = 1..n.
+    let mut parent = vec![0; n];
+    if n > 1 {
+        let line = lines.next().unwrap().expect("Failed to read the parents");
+        for (i, p) in line.split_whitespace().enumerate() {
+            parent[i + 1] = p.parse().expect("Failed to parse p");
+        }
+    }
+
+    // Store each value at its preorder position so subtrees become ranges.
+    let (tin, tout) = Tree::new(&parent, 0).euler_times();
+    let mut ft = FenwickTree::new(n);
+    for (v, &a) in initial_values.iter().enumerate() {
+        ft.add(tin[v], a);
+    }
+
+    // --- Query Processing and Output ---
+    for _ in 0..q {
+        let line = lines.next().unwrap().expect("Failed to read a query line");
+        let mut parts = line.split_whitespace();
+        let t: usize = parts.next().unwrap().parse().expect("Failed to parse t");
+        let u: usize = parts.next().unwrap().parse().expect("Failed to parse u");
+
+        match t {
+            0 => {
+                let x: i64 = parts.next().unwrap().parse().expect("Failed to parse x");
+                ft.add(tin[u], x);
+            }
+            1 => println!("{}", ft.sum(tin[u]..tout[u])),
+            _ => unreachable!()
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::io::{self, BufRead};
+
+/// `z[i]` is the length of the longest common prefix of `s` and `s[i..]`,
+/// with `z[0] = |s|`, in `O(n)`.
+///
+/// `[l, r)` is the rightmost window known to match a prefix of `s`; inside
+/// it `z[i]` starts from the mirrored `z[i - l]` and only extends past `r`.
+pub fn z_algorithm<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            (l, r) = (i, i + z[i]);
+        }
+    }
+    z
+}
+
+/// `pi[i]` is the length of the longest proper border of `s[..=i]`, i.e. the
+/// longest proper prefix that is also a suffix, in `O(n)`.
+///
+/// A border of `s[..=i]` is a border of `s[..i]` extended by one character,
+/// so the candidates are `pi[i - 1]`, `pi[pi[i - 1] - 1]`, ... in turn.
+pub fn prefix_function<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Starting positions of every occurrence of a non-empty `pattern` in
+/// `text`, by Knuth-Morris-Pratt in `O(|text| + |pattern|)`.
+pub fn kmp_search<T: PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    assert!(!pattern.is_empty(), "pattern must be non-empty");
+    let pi = prefix_function(pattern);
+    let mut matches = Vec::new();
+    // `k` is the length of the longest prefix of `pattern` ending here.
+    let mut k = 0;
+    for (i, c) in text.iter().enumerate() {
+        while k > 0 && *c != pattern[k] {
+            k = pi[k - 1];
+        }
+        if *c == pattern[k] {
+            k += 1;
+        }
+        if k == pattern.len() {
+            matches.push(i + 1 - k);
+            k = pi[k - 1];
+        }
+    }
+    matches
+}
+
+fn main() {
+    let stdin = io::stdin();
+    // Lock stdin for faster I/O and get an iterator over lines.
+    let mut lines = stdin.lock().lines();
+
+    // --- Input Processing ---
+    let s = lines.next().unwrap().expect("Failed to read the string");
+    let s = s.trim().as_bytes();
+
+    // --- Output ---
+    let output: Vec<String> = z_algorithm(s).iter().map(|x| x.to_string()).collect();
+    println!("{}", output.join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Xorshift64.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    /// Occurrences of `pattern` in `text` from the Z-array of
+    /// `pattern + sep + text`, with `None` as a separator matching nothing.
+    fn z_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        let joined: Vec<Option<u8>> =
+            pattern.iter().map(|&c| Some(c)).chain([None]).chain(text.iter().map(|&c| Some(c))).collect();
+        let z = z_algorithm(&joined);
+        (0..text.len()).filter(|&i| z[pattern.len() + 1 + i] >= pattern.len()).collect()
+    }
+
+    #[test]
+    fn kmp_matches_z_algorithm() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..3000 {
+            // Tiny alphabets give many overlapping occurrences.
+            let sigma = 1 + rng.below(3) as u8;
+            let text: Vec<u8> = (0..rng.below(60)).map(|_| b'a' + rng.below(sigma as usize) as u8).collect();
+            let pattern: Vec<u8> = (0..1 + rng.below(6)).map(|_| b'a' + rng.below(sigma as usize) as u8).collect();
+            assert_eq!(kmp_search(&text, &pattern), z_search(&text, &pattern), "{:?} in {:?}", pattern, text);
+        }
+    }
+
+    #[test]
+    fn prefix_function_is_the_longest_proper_border() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let s: Vec<u8> = (0..rng.below(30)).map(|_| rng.below(2) as u8).collect();
+            let pi = prefix_function(&s);
+            for (i, &p) in pi.iter().enumerate() {
+                let border = (0..=i).rev().find(|&k| s[..k] == s[i + 1 - k..=i]).unwrap();
+                assert_eq!(p, border, "{:?} at {}", s, i);
+            }
+        }
+    }
+}